@@ -6,7 +6,8 @@ use std::path::{Path, PathBuf};
 use std::process::Command;
 use std::{collections::HashSet, env};
 use syn::{
-    Attribute, File, Item, ItemEnum, ItemImpl, ItemStruct, ItemTrait, ItemUse, Type, TypePath,
+    Attribute, File, Item, ItemEnum, ItemFn, ItemImpl, ItemStruct, ItemTrait, ItemUse, Type,
+    TypePath,
 };
 
 #[derive(Debug)]
@@ -20,9 +21,14 @@ struct CodeExample {
 struct ExtractedCode {
     use_statements: Vec<ItemUse>,
     trait_definitions: Vec<ItemTrait>,
+    fn_definitions: Vec<ItemFn>,
     struct_definitions: Vec<ItemStruct>,
     enum_definitions: Vec<ItemEnum>,
     fieldwork_impls: Vec<ItemImpl>,
+    /// Structs and enums generated by fieldwork rather than written in the example.
+    generated_items: Vec<Item>,
+    /// Trait impls for generated items, which the example needs to compile but readers don't.
+    generated_trait_impls: Vec<ItemImpl>,
 }
 
 fn main() -> Result<(), Box<dyn Error>> {
@@ -178,7 +184,10 @@ fn find_expandable_examples(content: &str) -> Result<Vec<CodeExample>, Box<dyn E
     for (i, block_match) in blocks.iter().enumerate() {
         let block_content = block_match.get(1).unwrap().as_str();
 
-        if block_content.contains("#[derive(") && !block_content.contains("// docgen-skip") {
+        if block_content.contains("#[derive(")
+            && !block_content.starts_with("// GENERATED")
+            && !block_content.contains("// docgen-skip")
+        {
             let input_code = block_content
                 .lines()
                 .map(|line| {
@@ -244,9 +253,25 @@ fn extract_fieldwork_code(
 
     let mut use_statements = vec![];
     let mut trait_definitions = vec![];
+    let mut fn_definitions = vec![];
     let mut struct_definitions = vec![];
     let mut enum_definitions = vec![];
     let mut fieldwork_impls = vec![];
+    let mut generated_items = vec![];
+    let mut generated_trait_impls = vec![];
+    let mut derives: Vec<(String, syn::Path)> = vec![];
+    let mut generated_names = HashSet::new();
+
+    for item in &parsed.items {
+        match item {
+            Item::Struct(ItemStruct { ident, .. }) | Item::Enum(ItemEnum { ident, .. })
+                if !target_items.contains(&ident.to_string()) =>
+            {
+                generated_names.insert(ident.to_string());
+            }
+            _ => {}
+        }
+    }
 
     for item in parsed.items {
         match item {
@@ -256,31 +281,64 @@ fn extract_fieldwork_code(
             Item::Trait(item_trait) => {
                 trait_definitions.push(item_trait);
             }
+            Item::Fn(item_fn) => {
+                fn_definitions.push(item_fn);
+            }
             Item::Struct(item_struct) => {
                 if target_items.contains(&item_struct.ident.to_string()) {
                     struct_definitions.push(item_struct);
+                } else {
+                    generated_items.push(Item::Struct(item_struct));
                 }
             }
             Item::Enum(item_enum) => {
                 if target_items.contains(&item_enum.ident.to_string()) {
                     enum_definitions.push(item_enum);
+                } else {
+                    generated_items.push(Item::Enum(item_enum));
                 }
             }
             Item::Impl(item_impl) => {
-                if is_fieldwork_impl(&item_impl, target_items) {
+                if is_fieldwork_impl(&item_impl, target_items)
+                    || is_fieldwork_impl(&item_impl, &generated_names)
+                {
                     fieldwork_impls.push(item_impl);
+                } else if is_generated_trait_impl(&item_impl, &generated_names) {
+                    generated_trait_impls.push(item_impl);
+                } else if let Some(derive) = generated_derive(&item_impl, &generated_names) {
+                    derives.push(derive);
                 }
             }
             _ => {}
         }
     }
 
+    // Put derives back on generated items, since their expansions aren't shown
+    for item in &mut generated_items {
+        let (Item::Struct(ItemStruct { ident, attrs, .. })
+        | Item::Enum(ItemEnum { ident, attrs, .. })) = item
+        else {
+            continue;
+        };
+        let traits: Vec<_> = derives
+            .iter()
+            .filter(|(name, _)| *ident == name)
+            .map(|(_, path)| path)
+            .collect();
+        if !traits.is_empty() {
+            attrs.push(syn::parse_quote!(#[derive(#(#traits),*)]));
+        }
+    }
+
     Ok(ExtractedCode {
         trait_definitions,
+        fn_definitions,
         struct_definitions,
         enum_definitions,
         fieldwork_impls,
         use_statements,
+        generated_items,
+        generated_trait_impls,
     })
 }
 
@@ -299,6 +357,45 @@ fn is_fieldwork_impl(item_impl: &ItemImpl, target_items: &HashSet<String>) -> bo
     false
 }
 
+/// A handwritten trait impl for a generated item. Derived impls are left out, since they can use
+/// unstable internals that don't compile in a doctest.
+fn is_generated_trait_impl(item_impl: &ItemImpl, generated_names: &HashSet<String>) -> bool {
+    if item_impl.trait_.is_none()
+        || item_impl
+            .attrs
+            .iter()
+            .any(|attr| attr.path().is_ident("automatically_derived"))
+    {
+        return false;
+    }
+
+    matches!(
+        &*item_impl.self_ty,
+        Type::Path(TypePath { path, .. })
+            if path.segments.last().is_some_and(|segment| generated_names.contains(&segment.ident.to_string()))
+    )
+}
+
+/// The type name and derived trait for a derived impl on a generated item.
+fn generated_derive(
+    item_impl: &ItemImpl,
+    generated_names: &HashSet<String>,
+) -> Option<(String, syn::Path)> {
+    let (trait_path, _) = item_impl.trait_.as_ref()?;
+    let trait_ident = &trait_path.segments.last()?.ident;
+    // Marker impls that accompany `PartialEq` and `Clone` rather than coming from a derive
+    if trait_ident == "StructuralPartialEq" || trait_ident == "TrivialClone" {
+        return None;
+    }
+    let Type::Path(TypePath { path, .. }) = &*item_impl.self_ty else {
+        return None;
+    };
+    let name = path.segments.last()?.ident.to_string();
+    generated_names
+        .contains(&name)
+        .then(|| (name, syn::parse_quote!(#trait_ident)))
+}
+
 fn format_extracted_code(extracted: &ExtractedCode) -> Result<String, Box<dyn Error>> {
     let mut result = vec!["// GENERATED".to_string()];
 
@@ -323,6 +420,15 @@ fn format_extracted_code(extracted: &ExtractedCode) -> Result<String, Box<dyn Er
         }
     }
 
+    for fn_def in &extracted.fn_definitions {
+        let formatted_fn = prettyplease::unparse(&syn::parse_quote! { #fn_def });
+        for line in formatted_fn.lines() {
+            if !line.trim().is_empty() {
+                result.push(format!("# {line}"));
+            }
+        }
+    }
+
     for struct_def in &extracted.struct_definitions {
         let mut cleaned_struct = struct_def.clone();
         cleaned_struct
@@ -369,6 +475,20 @@ fn format_extracted_code(extracted: &ExtractedCode) -> Result<String, Box<dyn Er
         result.push(formatted_impl);
     }
 
+    for item in &extracted.generated_items {
+        let formatted_item = prettyplease::unparse(&syn::parse_quote! { #item });
+        result.push(formatted_item);
+    }
+
+    for impl_block in &extracted.generated_trait_impls {
+        let formatted_impl = prettyplease::unparse(&syn::parse_quote! { #impl_block });
+        for line in formatted_impl.lines() {
+            if !line.trim().is_empty() {
+                result.push(format!("# {line}"));
+            }
+        }
+    }
+
     Ok(result.join("\n"))
 }
 
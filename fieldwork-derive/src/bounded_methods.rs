@@ -0,0 +1,62 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Generics, Ident, WherePredicate};
+
+/// Generated methods, grouped by the extra `where` bounds they were configured with. Methods
+/// without any go in the item's own impl block, and each distinct set of bounds gets an impl block
+/// of its own, in the order they first appear.
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Default)]
+pub(crate) struct BoundedMethods {
+    unbounded: TokenStream,
+    bounded: Vec<(Vec<WherePredicate>, TokenStream)>,
+}
+
+impl BoundedMethods {
+    pub(crate) fn push(&mut self, predicates: Vec<WherePredicate>, method: TokenStream) {
+        if predicates.is_empty() {
+            self.unbounded.extend(method);
+        } else if let Some((_, methods)) = self
+            .bounded
+            .iter_mut()
+            .find(|(existing, _)| *existing == predicates)
+        {
+            methods.extend(method);
+        } else {
+            self.bounded.push((predicates, method));
+        }
+    }
+
+    /// Adds `other`'s methods after these, merging groups with the same bounds.
+    pub(crate) fn append(&mut self, other: Self) {
+        self.unbounded.extend(other.unbounded);
+        for (predicates, methods) in other.bounded {
+            self.push(predicates, methods);
+        }
+    }
+
+    /// The methods for the item's own impl block.
+    pub(crate) fn unbounded(&self) -> &TokenStream {
+        &self.unbounded
+    }
+
+    /// An impl block for each set of bounds, adding them to the item's own where clause.
+    pub(crate) fn bounded_impls(&self, ident: &Ident, generics: &Generics) -> TokenStream {
+        self.bounded
+            .iter()
+            .map(|(predicates, methods)| {
+                let mut generics = generics.clone();
+                generics
+                    .make_where_clause()
+                    .predicates
+                    .extend(predicates.iter().cloned());
+                let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+                quote! {
+                    impl #impl_generics #ident #type_generics #where_clause {
+                        #methods
+                    }
+                }
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,739 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote, quote_spanned};
+use std::borrow::Cow;
+use syn::{
+    Attribute, Expr, GenericParam, Generics, Ident, Member, Type, TypeParam, Visibility,
+    WhereClause, WherePredicate, parse_quote,
+};
+
+use crate::{
+    Field, ItemAttributes, Method, Query,
+    common_settings::{Vis, must_use_attr, passthrough_attrs, where_predicates},
+    option_handling::extract_option_type,
+};
+
+/// The item-level `builder`: a `{Struct}Builder` with an owned chainable setter per field, and a
+/// `build` method that returns a `{Struct}BuilderError` naming any required fields that weren't
+/// set. Fields are required unless they are `Option`s or have a `default`.
+///
+/// With `builder(typestate)`, each required field is instead stored in its own type parameter,
+/// which is `()` until the field is set, and `build` only exists once all of them are.
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub(crate) struct Builder<'a> {
+    ident: &'a Ident,
+    generated_ident: Ident,
+    error_ident: Ident,
+    generics: &'a Generics,
+    vis: Cow<'a, Visibility>,
+    /// The `attr(...)` attributes for the `builder` function.
+    attrs: Vec<&'a Attribute>,
+    /// The `builder(where = ...)` bounds, for `builder` and every builder impl.
+    where_predicates: Vec<WherePredicate>,
+    typestate: bool,
+    fields: Vec<BuilderField<'a>>,
+    named: bool,
+}
+
+#[cfg_attr(feature = "debug", derive(Debug))]
+struct BuilderField<'a> {
+    member: &'a Member,
+    ty: &'a Type,
+    span: Span,
+    /// The builder's storage for this field, named for the field.
+    binding: Ident,
+    /// Whether the field is an `Option`, and so stored as-is.
+    optional: bool,
+    default: Option<Expr>,
+    /// The setter and any deprecated alternate, or empty for a field that's skipped and always
+    /// gets its default.
+    setters: Vec<BuilderSetter<'a>>,
+    /// The type parameter tracking this field with `typestate`, if it's required.
+    state: Option<Ident>,
+}
+
+#[cfg_attr(feature = "debug", derive(Debug))]
+struct BuilderSetter<'a> {
+    fn_ident: Cow<'a, Ident>,
+    argument_ident: Cow<'a, Ident>,
+    argument_ty: Cow<'a, Type>,
+    assigned_value: Expr,
+    doc: Option<Cow<'a, str>>,
+    vis: Cow<'a, Visibility>,
+    attrs: Vec<Attribute>,
+    /// Validation statements and error type, for `try_with_*` setters.
+    validation: Option<(TokenStream, &'a Type)>,
+    /// Bounds for this setter beyond the builder's own.
+    where_predicates: Vec<WherePredicate>,
+}
+
+impl<'a> Builder<'a> {
+    pub(crate) fn new(
+        ident: &'a Ident,
+        fields: &'a [Field],
+        attributes: &'a ItemAttributes,
+        generics: &'a Generics,
+    ) -> syn::Result<Option<Self>> {
+        if !attributes.include.contains(Method::Builder) {
+            return Ok(None);
+        }
+
+        let method_attributes = attributes.methods.retrieve(Method::Builder);
+        let vis = method_attributes
+            .and_then(|x| x.common_settings.vis.as_ref())
+            .or(attributes.common_settings.vis.as_ref())
+            .unwrap_or(&Vis::Default)
+            .as_visibility();
+        let attrs = passthrough_attrs(
+            Some(&attributes.common_settings)
+                .into_iter()
+                .chain(method_attributes.map(|x| &x.common_settings)),
+        );
+        let where_predicates = where_predicates(method_attributes.map(|x| &x.common_settings));
+        let typestate = method_attributes.is_some_and(|x| x.typestate);
+
+        let named = fields
+            .first()
+            .is_none_or(|field| matches!(field.member, Member::Named(_)));
+        let fields = fields
+            .iter()
+            .map(|field| {
+                BuilderField::new(field, attributes, generics, typestate, &where_predicates)
+            })
+            .collect::<syn::Result<_>>()?;
+
+        Ok(Some(Self {
+            ident,
+            generated_ident: format_ident!("{ident}Builder"),
+            error_ident: format_ident!("{ident}BuilderError"),
+            generics,
+            vis,
+            attrs,
+            where_predicates,
+            typestate,
+            fields,
+            named,
+        }))
+    }
+
+    pub(crate) fn where_predicates(&self) -> Vec<WherePredicate> {
+        self.where_predicates.clone()
+    }
+
+    /// The where clause for the builder's impls: the struct's own, plus `builder(where = ...)`.
+    fn impl_where_clause(&self) -> Option<WhereClause> {
+        let mut generics = self.generics.clone();
+        if !self.where_predicates.is_empty() {
+            generics
+                .make_where_clause()
+                .predicates
+                .extend(self.where_predicates.iter().cloned());
+        }
+        generics.where_clause
+    }
+
+    /// The `builder` associated function, for the struct's own impl block.
+    pub(crate) fn methods(&self) -> TokenStream {
+        let Self {
+            generated_ident,
+            generics,
+            vis,
+            attrs,
+            ..
+        } = self;
+        let (_, type_generics, _) = generics.split_for_impl();
+        let doc = format!("Starts building a new `{}`", self.ident);
+        quote! {
+            #[doc = #doc]
+            #(#attrs)*
+            #vis fn builder() -> #generated_ident #type_generics {
+                Default::default()
+            }
+        }
+    }
+
+    /// The builder type and its impls, and the error type if there is one.
+    pub(crate) fn items(&self) -> TokenStream {
+        if self.typestate {
+            self.typestate_items()
+        } else {
+            self.runtime_items()
+        }
+    }
+
+    fn included(&self) -> impl Iterator<Item = &BuilderField<'a>> {
+        self.fields.iter().filter(|field| !field.setters.is_empty())
+    }
+
+    fn doc(&self) -> String {
+        let ident = self.ident;
+        format!("A builder for [`{ident}`], started with `{ident}::builder()`")
+    }
+
+    /// The struct literal that `build` returns.
+    fn construct(&self) -> TokenStream {
+        let Self {
+            ident,
+            typestate,
+            fields,
+            named,
+            ..
+        } = self;
+        let values = fields.iter().map(|field| field.value(*typestate));
+        if *named {
+            let values = fields
+                .iter()
+                .zip(values)
+                .map(|(field, value)| match field.member {
+                    Member::Named(member) if !typestate && field.binds(member) => quote!(#member),
+                    member => quote!(#member: #value),
+                });
+            quote!(#ident { #(#values),* })
+        } else {
+            quote!(#ident(#(#values),*))
+        }
+    }
+
+    fn runtime_items(&self) -> TokenStream {
+        let Self {
+            ident,
+            generated_ident,
+            error_ident,
+            generics,
+            vis,
+            ..
+        } = self;
+        let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+        let impl_where_clause = self.impl_where_clause();
+        let params = &generics.params;
+
+        let builder_doc = self.doc();
+        // The struct's generics might only appear in fields that the builder leaves out
+        let marker = (!generics.params.is_empty())
+            .then(|| quote!(_marker: std::marker::PhantomData<fn() -> #ident #type_generics>));
+        let storage = self.included().map(|field| {
+            let BuilderField { binding, ty, .. } = field;
+            if field.optional {
+                quote!(#binding: #ty)
+            } else {
+                quote!(#binding: Option<#ty>)
+            }
+        });
+        let empty = self
+            .included()
+            .map(|field| {
+                let binding = &field.binding;
+                quote!(#binding: None)
+            })
+            .chain(
+                marker
+                    .is_some()
+                    .then(|| quote!(_marker: std::marker::PhantomData)),
+            );
+        let setter_fns = self.included().flat_map(|field| {
+            field.setters.iter().map(|setter| {
+                let binding = &field.binding;
+                let value = &setter.assigned_value;
+                let value = if field.optional {
+                    quote!(#value)
+                } else {
+                    quote_spanned!(field.span => Some(#value))
+                };
+                setter.build(
+                    &quote!(mut self),
+                    &quote!(Self),
+                    &quote!(self.#binding = #value;),
+                    &quote!(self),
+                )
+            })
+        });
+
+        let required: Vec<_> = self
+            .included()
+            .filter(|field| field.is_required())
+            .map(|field| &field.binding)
+            .collect();
+        let required_names = required.iter().map(ToString::to_string);
+        let construct = self.construct();
+        let build_body = if required.is_empty() {
+            quote!(Ok(#construct))
+        } else {
+            quote! {
+                let mut missing = Vec::new();
+                #(if self.#required.is_none() {
+                    missing.push(#required_names);
+                })*
+                if let (#(Some(#required),)*) = (#(self.#required,)*) {
+                    Ok(#construct)
+                } else {
+                    Err(#error_ident { missing })
+                }
+            }
+        };
+        let build_doc = format!(
+            "Builds the `{ident}`, or returns an error naming the required fields that were not set"
+        );
+
+        let error = self.error();
+
+        quote! {
+            #[doc = #builder_doc]
+            #vis struct #generated_ident<#params> #where_clause {
+                #(#storage,)*
+                #marker
+            }
+
+            impl #impl_generics Default for #generated_ident #type_generics #impl_where_clause {
+                fn default() -> Self {
+                    Self { #(#empty,)* }
+                }
+            }
+
+            impl #impl_generics #generated_ident #type_generics #impl_where_clause {
+                #(#setter_fns)*
+
+                #[doc = #build_doc]
+                #vis fn build(self) -> Result<#ident #type_generics, #error_ident> {
+                    #build_body
+                }
+            }
+
+            #error
+        }
+    }
+
+    /// The error type returned by `build`, which names the missing fields.
+    fn error(&self) -> TokenStream {
+        let Self {
+            ident,
+            generated_ident,
+            error_ident,
+            vis,
+            ..
+        } = self;
+        let error_doc = format!("The error returned by [`{generated_ident}::build`]");
+        let error_message = format!("missing required fields for `{ident}`: ");
+        quote! {
+            #[doc = #error_doc]
+            #[derive(Debug, Clone, PartialEq, Eq)]
+            #vis struct #error_ident {
+                missing: Vec<&'static str>,
+            }
+
+            impl #error_ident {
+                /// The names of the required fields that were not set
+                #vis fn missing_fields(&self) -> &[&'static str] {
+                    &self.missing
+                }
+            }
+
+            impl std::fmt::Display for #error_ident {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    f.write_str(#error_message)?;
+                    f.write_str(&self.missing.join(", "))
+                }
+            }
+
+            impl std::error::Error for #error_ident {}
+        }
+    }
+
+    /// The builder type's arguments, with each required field's state given by `state`.
+    fn typestate_ty<'b>(
+        &'b self,
+        state: impl Fn(&'b BuilderField<'a>) -> TokenStream,
+    ) -> TokenStream {
+        let generated_ident = &self.generated_ident;
+        let params = self.generics.params.iter().map(|param| match param {
+            GenericParam::Lifetime(lifetime) => {
+                let lifetime = &lifetime.lifetime;
+                quote!(#lifetime)
+            }
+            GenericParam::Type(ty) => {
+                let ident = &ty.ident;
+                quote!(#ident)
+            }
+            GenericParam::Const(constant) => {
+                let ident = &constant.ident;
+                quote!(#ident)
+            }
+        });
+        let states = self
+            .included()
+            .filter(|field| field.state.is_some())
+            .map(state);
+        quote!(#generated_ident<#(#params,)* #(#states,)*>)
+    }
+
+    /// The setters for a `typestate` builder. Setting a required field changes its state, so those
+    /// setters rebuild the builder.
+    fn typestate_setters(&self) -> Vec<TokenStream> {
+        let generated_ident = &self.generated_ident;
+        self.included()
+            .flat_map(|field| {
+                field.setters.iter().map(|setter| {
+                    let binding = &field.binding;
+                    let value = &setter.assigned_value;
+                    if field.state.is_some() {
+                        let next_ty = self.typestate_ty(|other| {
+                            if other.binding == field.binding {
+                                let ty = field.ty;
+                                quote!(#ty)
+                            } else {
+                                let state = &other.state;
+                                quote!(#state)
+                            }
+                        });
+                        let others = self
+                            .included()
+                            .filter(|other| other.binding != field.binding)
+                            .map(|other| {
+                                let binding = &other.binding;
+                                quote!(#binding: self.#binding)
+                            })
+                            .chain(
+                                (!self.generics.params.is_empty())
+                                    .then(|| quote!(_marker: self._marker)),
+                            );
+                        let value = match value {
+                            Expr::Path(path) if path.path.is_ident(binding) => quote!(#binding),
+                            value => quote!(#binding: #value),
+                        };
+                        setter.build(
+                            &quote!(self),
+                            &next_ty,
+                            &TokenStream::new(),
+                            &quote! {
+                                #generated_ident {
+                                    #value,
+                                    #(#others,)*
+                                }
+                            },
+                        )
+                    } else {
+                        let value = if field.optional {
+                            quote!(#value)
+                        } else {
+                            quote_spanned!(field.span => Some(#value))
+                        };
+                        setter.build(
+                            &quote!(mut self),
+                            &quote!(Self),
+                            &quote!(self.#binding = #value;),
+                            &quote!(self),
+                        )
+                    }
+                })
+            })
+            .collect()
+    }
+
+    fn typestate_items(&self) -> TokenStream {
+        let Self {
+            ident,
+            generated_ident,
+            generics,
+            vis,
+            ..
+        } = self;
+        let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+        let impl_where_clause = self.impl_where_clause();
+
+        // The struct's own generics, plus one parameter per required field, unset by default.
+        let mut builder_generics = (*generics).clone();
+        builder_generics
+            .params
+            .extend(self.included().filter_map(|field| {
+                let state = field.state.as_ref()?;
+                let mut param = TypeParam::from(state.clone());
+                param.default = Some((syn::token::Eq::default(), parse_quote!(())));
+                Some(GenericParam::Type(param))
+            }));
+        let params = &builder_generics.params;
+        let (builder_impl_generics, builder_type_generics, _) = builder_generics.split_for_impl();
+
+        let builder_doc = if self.included().any(|field| field.state.is_some()) {
+            format!(
+                "{}\n\nEach `State` type parameter tracks whether a required field has been set, and \
+                 is `()` until it is. `build` is only available once all of them have been.",
+                self.doc()
+            )
+        } else {
+            self.doc()
+        };
+        // The struct's generics might only appear in required fields' types
+        let marker = (!generics.params.is_empty())
+            .then(|| quote!(_marker: std::marker::PhantomData<fn() -> #ident #type_generics>));
+        let storage = self.included().map(|field| {
+            let BuilderField {
+                binding, ty, state, ..
+            } = field;
+            match state {
+                Some(state) => quote!(#binding: #state),
+                None if field.optional => quote!(#binding: #ty),
+                None => quote!(#binding: Option<#ty>),
+            }
+        });
+        let empty = self
+            .included()
+            .map(|field| {
+                let binding = &field.binding;
+                if field.state.is_some() {
+                    quote!(#binding: ())
+                } else {
+                    quote!(#binding: None)
+                }
+            })
+            .chain(
+                marker
+                    .is_some()
+                    .then(|| quote!(_marker: std::marker::PhantomData)),
+            );
+        let unset_ty = self.typestate_ty(|_| quote!(()));
+        let set_ty = self.typestate_ty(|field| {
+            let ty = field.ty;
+            quote!(#ty)
+        });
+
+        let setter_fns = self.typestate_setters();
+
+        let construct = self.construct();
+        let build_doc = format!("Builds the `{ident}`");
+
+        quote! {
+            #[doc = #builder_doc]
+            #vis struct #generated_ident<#params> #where_clause {
+                #(#storage,)*
+                #marker
+            }
+
+            impl #impl_generics Default for #unset_ty #impl_where_clause {
+                fn default() -> Self {
+                    Self { #(#empty,)* }
+                }
+            }
+
+            impl #builder_impl_generics #generated_ident #builder_type_generics #impl_where_clause {
+                #(#setter_fns)*
+            }
+
+            impl #impl_generics #set_ty #impl_where_clause {
+                #[doc = #build_doc]
+                #[must_use]
+                #vis fn build(self) -> #ident #type_generics {
+                    #construct
+                }
+            }
+        }
+    }
+}
+
+impl<'a> BuilderField<'a> {
+    fn new(
+        field: &'a Field,
+        attributes: &'a ItemAttributes,
+        generics: &'a Generics,
+        typestate: bool,
+        builder_predicates: &[WherePredicate],
+    ) -> syn::Result<Self> {
+        let query = Query::new(
+            &Method::Builder,
+            std::slice::from_ref(field),
+            attributes,
+            generics,
+            1,
+        );
+        let mut binding = match &field.member {
+            Member::Named(ident) => Some(ident.clone()),
+            Member::Unnamed(_) => None,
+        };
+
+        let mut setters = Vec::new();
+        if query.enabled() {
+            let setter = BuilderSetter::new(field, &query, builder_predicates)?;
+            binding.get_or_insert_with(|| setter.argument_ident.clone().into_owned());
+            setters.push(setter);
+            if let Some(alternate) = query.as_alternate() {
+                setters.push(BuilderSetter::new(field, &alternate, builder_predicates)?);
+            }
+        }
+        let binding = binding.unwrap_or_else(|| match &field.member {
+            Member::Named(ident) => ident.clone(),
+            Member::Unnamed(index) => format_ident!("field_{}", index.index),
+        });
+        let optional = extract_option_type(&field.ty).is_some();
+        let default = field.attributes.default.clone();
+        let state = (typestate && !setters.is_empty() && !optional && default.is_none())
+            .then(|| format_ident!("{}State", upper_camel_case(&binding.to_string())));
+
+        Ok(Self {
+            member: &field.member,
+            ty: &field.ty,
+            span: field.span,
+            binding,
+            optional,
+            default,
+            setters,
+            state,
+        })
+    }
+
+    /// Whether `build` can't succeed without this field being set.
+    fn is_required(&self) -> bool {
+        !self.setters.is_empty() && !self.optional && self.default.is_none()
+    }
+
+    /// Whether `build` binds this field's value to a local of the same name.
+    fn binds(&self, ident: &Ident) -> bool {
+        self.is_required() && &self.binding == ident
+    }
+
+    /// The field's value in `build`, once every required field is known to be set.
+    fn value(&self, typestate: bool) -> TokenStream {
+        let Self {
+            span,
+            binding,
+            optional,
+            ..
+        } = self;
+        let span = *span;
+        let default = self.default.as_ref().map_or_else(
+            || quote_spanned!(span => Default::default()),
+            |default| quote!(#default),
+        );
+        if self.setters.is_empty() {
+            default
+        } else if self.is_required() && !typestate {
+            quote!(#binding)
+        } else if self.default.is_none() {
+            quote!(self.#binding)
+        } else {
+            // `|| f()` is just `f`, which keeps clippy quiet about the closure
+            let default = match &self.default {
+                Some(Expr::Call(call)) if call.args.is_empty() && !*optional => {
+                    if is_default_call(&call.func) {
+                        return quote_spanned!(span => self.#binding.unwrap_or_default());
+                    }
+                    let func = &call.func;
+                    quote!(#func)
+                }
+                Some(Expr::Call(call)) if call.args.is_empty() => {
+                    let func = &call.func;
+                    quote!(#func)
+                }
+                _ => quote!(|| #default),
+            };
+            if *optional {
+                quote_spanned!(span => self.#binding.or_else(#default))
+            } else {
+                quote_spanned!(span => self.#binding.unwrap_or_else(#default))
+            }
+        }
+    }
+}
+
+impl<'a> BuilderSetter<'a> {
+    fn new(
+        field: &'a Field,
+        query: &Query<'a>,
+        builder_predicates: &[WherePredicate],
+    ) -> syn::Result<Self> {
+        let (Some(fn_ident), Some(argument_ident)) = (query.fn_ident(), query.argument_ident())
+        else {
+            return Err(syn::Error::new(
+                field.span,
+                "builder fields need a name; add `#[field = \"name\"]` or skip this field",
+            ));
+        };
+
+        let (argument_ty, assigned_value) = query
+            .determine_argument_ty_and_assigned_value(&argument_ident)
+            .and_then(|(ty, value)| Some((ty?, value)))
+            .ok_or_else(|| syn::Error::new(field.span, "unsupported builder field"))?;
+        let (validation, assigned_value) =
+            query.validate_assigned_value(&argument_ident, assigned_value);
+        let validation = validation.zip(query.validation().map(|(_, error_ty)| error_ty));
+        let where_predicates = query
+            .where_predicates()
+            .into_iter()
+            .filter(|predicate| !builder_predicates.contains(predicate))
+            .collect();
+
+        Ok(Self {
+            fn_ident,
+            argument_ident,
+            argument_ty,
+            assigned_value,
+            doc: query.docs(false),
+            vis: query.vis(),
+            attrs: query.attrs(),
+            validation,
+            where_predicates,
+        })
+    }
+
+    /// The setter, taking `receiver`, running `statements`, and returning `result` of
+    /// `return_ty`.
+    fn build(
+        &self,
+        receiver: &TokenStream,
+        return_ty: &TokenStream,
+        statements: &TokenStream,
+        result: &TokenStream,
+    ) -> TokenStream {
+        let Self {
+            fn_ident,
+            argument_ident,
+            argument_ty,
+            doc,
+            vis,
+            attrs,
+            validation,
+            where_predicates,
+            ..
+        } = self;
+        let doc = doc.as_deref().map(|d| quote!(#[doc = #d]));
+        let where_clause =
+            (!where_predicates.is_empty()).then(|| quote!(where #(#where_predicates),*));
+        if let Some((validation, error_ty)) = validation {
+            quote! {
+                #doc
+                #(#attrs)*
+                #vis fn #fn_ident(#receiver, #argument_ident: #argument_ty) -> Result<#return_ty, #error_ty> #where_clause {
+                    #validation
+                    #statements
+                    Ok(#result)
+                }
+            }
+        } else {
+            let must_use = must_use_attr(attrs);
+            quote! {
+                #doc
+                #(#attrs)*
+                #must_use
+                #vis fn #fn_ident(#receiver, #argument_ident: #argument_ty) -> #return_ty #where_clause {
+                    #statements
+                    #result
+                }
+            }
+        }
+    }
+}
+
+fn is_default_call(func: &Expr) -> bool {
+    matches!(func, Expr::Path(path) if path.path.segments.len() == 2
+        && path.path.segments[0].ident == "Default"
+        && path.path.segments[1].ident == "default")
+}
+
+fn upper_camel_case(name: &str) -> String {
+    name.split('_')
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default()
+        })
+        .collect()
+}
@@ -0,0 +1,105 @@
+use syn::{GenericArgument, Ident, Path, PathArguments, Type, TypePath, ext::IdentExt};
+
+/// A standard library collection recognized by its last path segment.
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Collection {
+    Vec,
+    VecDeque,
+    HashSet,
+    BTreeSet,
+    HashMap,
+    BTreeMap,
+}
+
+impl Collection {
+    /// The method that adds a single element to this collection.
+    pub(crate) fn add_method(self) -> &'static str {
+        match self {
+            Collection::Vec => "push",
+            Collection::VecDeque => "push_back",
+            Collection::HashSet
+            | Collection::BTreeSet
+            | Collection::HashMap
+            | Collection::BTreeMap => "insert",
+        }
+    }
+}
+
+/// Returns the collection kind, its element type (the key type for maps), and for maps the value
+/// type.
+pub(crate) fn extract_collection_type(ty: &Type) -> Option<(Collection, &Type, Option<&Type>)> {
+    let Type::Path(TypePath {
+        path: Path { segments, .. },
+        ..
+    }) = ty
+    else {
+        return None;
+    };
+
+    let last_segment = segments.last()?;
+    let collection = if last_segment.ident == "Vec" {
+        Collection::Vec
+    } else if last_segment.ident == "VecDeque" {
+        Collection::VecDeque
+    } else if last_segment.ident == "HashSet" {
+        Collection::HashSet
+    } else if last_segment.ident == "BTreeSet" {
+        Collection::BTreeSet
+    } else if last_segment.ident == "HashMap" {
+        Collection::HashMap
+    } else if last_segment.ident == "BTreeMap" {
+        Collection::BTreeMap
+    } else {
+        return None;
+    };
+
+    let PathArguments::AngleBracketed(ref bracketed_args) = last_segment.arguments else {
+        return None;
+    };
+
+    let mut type_args = bracketed_args.args.iter().filter_map(|arg| match arg {
+        GenericArgument::Type(ty) => Some(ty),
+        _ => None,
+    });
+    let element_type = type_args.next()?;
+
+    if matches!(collection, Collection::HashMap | Collection::BTreeMap) {
+        Some((collection, element_type, Some(type_args.next()?)))
+    } else {
+        Some((collection, element_type, None))
+    }
+}
+
+/// Derive a singular name from a plural field name: `-ies` becomes `-y` (`entries` → `entry`),
+/// `-es` after `ss`, `x`, `zz`, `ch`, or `sh` is stripped (`addresses` → `address`), and otherwise
+/// a trailing `s` is stripped (`tags` → `tag`). Names that end in `ss`, `us`, or `is`, that
+/// don't end in `s`, or whose singular is a keyword have no derivable singular.
+pub(crate) fn singular_ident(ident: &Ident) -> Option<Ident> {
+    let name = ident.unraw().to_string();
+    let stem = if let Some(stem) = name
+        .strip_suffix("ies")
+        .filter(|stem| !stem.is_empty() && !stem.ends_with('_'))
+    {
+        format!("{stem}y")
+    } else if let Some(stem) = name.strip_suffix("es").filter(|stem| {
+        ["ss", "x", "zz", "ch", "sh"]
+            .iter()
+            .any(|end| stem.ends_with(end))
+    }) {
+        stem.to_string()
+    } else {
+        let stem = name.strip_suffix('s')?;
+        if ["s", "u", "i"].iter().any(|end| stem.ends_with(end)) {
+            return None;
+        }
+        stem.to_string()
+    };
+    if stem.is_empty() || stem.ends_with('_') {
+        return None;
+    }
+    // A keyword like `box` (from `boxes`) can't be used as an argument name
+    let mut singular: Ident = syn::parse_str(&stem).ok()?;
+    singular.set_span(ident.span());
+    Some(singular)
+}
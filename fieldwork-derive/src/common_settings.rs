@@ -1,6 +1,11 @@
 use std::borrow::Cow;
 
-use syn::{Error, LitStr, Visibility, token::Pub};
+use quote::ToTokens;
+use syn::{
+    Attribute, Error, Expr, LitStr, Meta, Visibility, WherePredicate, parse_quote,
+    punctuated::Punctuated,
+    token::{Comma, Pub},
+};
 
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[derive(Clone, Default)]
@@ -8,13 +13,21 @@ pub(crate) struct CommonSettings {
     pub(crate) auto_deref: Option<bool>,
     pub(crate) chainable_set: Option<bool>,
     pub(crate) get_copy: Option<bool>,
+    pub(crate) get_clone: Option<bool>,
+    pub(crate) get_const: Option<bool>,
+    pub(crate) fallible: Option<bool>,
     pub(crate) option_borrow_inner: Option<bool>,
     pub(crate) option_set_some: Option<bool>,
+    pub(crate) option_predicates: Option<bool>,
     pub(crate) rename_predicates: Option<bool>,
     pub(crate) opt_in: bool,
     pub(crate) skip: bool,
     pub(crate) vis: Option<Vis>,
     pub(crate) into: Option<bool>,
+    /// Attributes passed through to generated methods with `attr(inline, doc(hidden))`.
+    pub(crate) attrs: Vec<Attribute>,
+    /// Extra bounds for generated methods, from `where = "T: Clone"`.
+    pub(crate) where_predicates: Vec<WherePredicate>,
 }
 
 #[cfg_attr(feature = "debug", derive(Debug))]
@@ -33,22 +46,29 @@ impl Vis {
 }
 
 macro_rules! with_common_settings {
-    ($($key:literal,)+) => {
+    ($($key:literal,)*) => {
         &[
+            "attr",
             "chain",
+            "clone",
+            "const",
             "copy",
             "debug",
             "deref",
+            "fallible",
             "inspect",
             "into",
             "opt_in",
             "option_borrow_inner",
+            "option_predicate",
+            "option_predicates",
             "option_set_some",
             "rename_predicate",
             "rename_predicates",
             "skip",
             "vis",
-            $($key,)+
+            "where",
+            $($key,)*
         ]
     }
 }
@@ -60,17 +80,26 @@ impl CommonSettings {
         option_borrow_inner: Some(true),
         auto_deref: Some(true),
         get_copy: Some(true),
+        get_clone: Some(false),
+        get_const: Some(false),
+        fallible: Some(false),
         rename_predicates: Some(false),
+        option_predicates: Some(false),
         option_set_some: Some(false),
         opt_in: false,
         skip: false,
         vis: Some(Vis::Default),
         into: Some(false),
+        attrs: Vec::new(),
+        where_predicates: Vec::new(),
     };
 
     pub(crate) fn handle_assign_str_lit(&mut self, lhs: &str, rhs: &LitStr) -> Result<bool, Error> {
         match lhs {
             "vis" => self.vis = Some(Vis::Visibility(rhs.parse()?)),
+            "where" => self
+                .where_predicates
+                .extend(rhs.parse_with(Punctuated::<WherePredicate, Comma>::parse_terminated)?),
             _ => return Ok(false),
         }
         Ok(true)
@@ -82,24 +111,43 @@ impl CommonSettings {
         self.auto_deref.is_some()
             || self.chainable_set.is_some()
             || self.get_copy.is_some()
+            || self.get_clone.is_some()
+            || self.get_const.is_some()
+            || self.fallible.is_some()
             || self.option_borrow_inner.is_some()
             || self.option_set_some.is_some()
+            || self.option_predicates.is_some()
             || self.rename_predicates.is_some()
             || self.opt_in
             || self.skip
             || self.vis.is_some()
             || self.into.is_some()
+            || !self.attrs.is_empty()
+            || !self.where_predicates.is_empty()
+    }
+
+    /// `attr(inline, doc(hidden))`
+    pub(crate) fn handle_attr(&mut self, args: &Punctuated<Expr, Comma>) -> Result<(), Error> {
+        for arg in args {
+            let meta: Meta = syn::parse2(arg.to_token_stream())?;
+            self.attrs.push(parse_quote!(#[#meta]));
+        }
+        Ok(())
     }
 
     pub(crate) fn handle_assign_bool_lit(&mut self, lhs: &str, value: bool) -> bool {
         match lhs {
             "chain" => self.chainable_set = Some(value),
+            "clone" => self.get_clone = Some(value),
+            "const" => self.get_const = Some(value),
             "copy" => self.get_copy = Some(value),
             "deref" => self.auto_deref = Some(value),
+            "fallible" => self.fallible = Some(value),
             "into" => self.into = Some(value),
             "opt_in" => self.opt_in = value,
             "option" | "option_borrow_inner" => self.option_borrow_inner = Some(value),
             "option_set_some" => self.option_set_some = Some(value),
+            "option_predicate" | "option_predicates" => self.option_predicates = Some(value),
             "rename_predicate" | "rename_predicates" => self.rename_predicates = Some(value),
             "skip" => self.skip = value,
             _ => return false,
@@ -107,3 +155,44 @@ impl CommonSettings {
         true
     }
 }
+
+/// The `attr(...)` attributes from each of `levels`, broadest first. An attribute given at more
+/// than one level is only included once.
+pub(crate) fn passthrough_attrs<'a>(
+    levels: impl IntoIterator<Item = &'a CommonSettings>,
+) -> Vec<&'a Attribute> {
+    let mut attrs: Vec<&Attribute> = Vec::new();
+    for attr in levels.into_iter().flat_map(|settings| &settings.attrs) {
+        if !attrs.contains(&attr) {
+            attrs.push(attr);
+        }
+    }
+    attrs
+}
+
+/// The built-in `#[must_use]` for an owned setter, unless `attrs` already has its own.
+pub(crate) fn must_use_attr<'a>(
+    attrs: impl IntoIterator<Item = &'a Attribute>,
+) -> Option<Attribute> {
+    let provided = attrs
+        .into_iter()
+        .any(|attr| attr.path().is_ident("must_use"));
+    (!provided).then(|| parse_quote!(#[must_use]))
+}
+
+/// The `where` bounds from each of `levels`, broadest first. A bound given at more than one level
+/// is only included once.
+pub(crate) fn where_predicates<'a>(
+    levels: impl IntoIterator<Item = &'a CommonSettings>,
+) -> Vec<WherePredicate> {
+    let mut predicates: Vec<WherePredicate> = Vec::new();
+    for predicate in levels
+        .into_iter()
+        .flat_map(|settings| &settings.where_predicates)
+    {
+        if !predicates.contains(predicate) {
+            predicates.push(predicate.clone());
+        }
+    }
+    predicates
+}
@@ -0,0 +1,270 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use std::borrow::Cow;
+use syn::{
+    Attribute, Expr, Fields, Generics, Ident, Member, Type, Visibility, WherePredicate,
+    parse_quote_spanned,
+};
+
+use crate::{
+    Enum, Field, ItemAttributes, Method, Query,
+    common_settings::{Vis, passthrough_attrs, where_predicates},
+    r#enum::EnumVariant,
+    variants::snake_case,
+};
+
+/// The item-level `new` constructor. Fields with a `default` (or that skip `new`) are filled in,
+/// and every other field becomes an argument, named and typed as it would be for a setter.
+///
+/// On enums, each variant gets its own constructor, named `new_{variant}` unless the `new`
+/// method's template says otherwise.
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub(crate) struct Constructor<'a> {
+    fn_ident: Cow<'a, Ident>,
+    /// What's constructed: `Self`, or `Self::Variant` for enums.
+    path: TokenStream,
+    doc: String,
+    vis: Cow<'a, Visibility>,
+    attrs: Vec<&'a Attribute>,
+    /// Extra bounds from `new(where = ...)`, which put the constructor in its own impl block.
+    where_predicates: Vec<WherePredicate>,
+    arguments: Vec<Argument<'a>>,
+    /// Every field in declaration order, with its value.
+    values: Vec<(&'a Member, Expr)>,
+    shape: Shape,
+}
+
+type Argument<'a> = (Cow<'a, Ident>, Cow<'a, Type>);
+
+#[derive(Clone, Copy)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+enum Shape {
+    Named,
+    Unnamed,
+    Unit,
+}
+
+impl<'a> Constructor<'a> {
+    pub(crate) fn new(
+        ident: &'a Ident,
+        fields: &'a [Field],
+        attributes: &'a ItemAttributes,
+        generics: &'a Generics,
+    ) -> Option<Self> {
+        if !attributes.include.contains(Method::New) {
+            return None;
+        }
+
+        let vis = attributes
+            .methods
+            .retrieve(Method::New)
+            .and_then(|x| x.common_settings.vis.as_ref())
+            .or(attributes.common_settings.vis.as_ref())
+            .unwrap_or(&Vis::Default)
+            .as_visibility();
+
+        let method_settings = attributes
+            .methods
+            .retrieve(Method::New)
+            .map(|x| &x.common_settings);
+        let attrs = passthrough_attrs(
+            Some(&attributes.common_settings)
+                .into_iter()
+                .chain(method_settings),
+        );
+        let where_predicates = where_predicates(method_settings);
+        let (arguments, values) = arguments_and_values(fields, attributes, generics);
+
+        Some(Self {
+            fn_ident: Cow::Owned(format_ident!("new")),
+            path: quote!(Self),
+            doc: format!("Constructs a new `{ident}`"),
+            vis,
+            attrs,
+            where_predicates,
+            arguments,
+            values,
+            shape: if fields
+                .first()
+                .is_none_or(|field| matches!(field.member, Member::Named(_)))
+            {
+                Shape::Named
+            } else {
+                Shape::Unnamed
+            },
+        })
+    }
+
+    /// One constructor for each variant of an enum that doesn't skip `new`.
+    pub(crate) fn for_variants(enum_item: &'a Enum) -> Vec<Self> {
+        let attributes = &enum_item.attributes;
+        if !attributes.include.contains(Method::New) {
+            return Vec::new();
+        }
+
+        enum_item
+            .variants
+            .iter()
+            .filter(|variant| !variant.attributes.skips(Method::New))
+            .map(|variant| {
+                Self::for_variant(&enum_item.ident, variant, attributes, &enum_item.generics)
+            })
+            .collect()
+    }
+
+    fn for_variant(
+        ident: &'a Ident,
+        variant: &'a EnumVariant,
+        attributes: &'a ItemAttributes,
+        generics: &'a Generics,
+    ) -> Self {
+        let variant_ident = &variant.ident;
+        let item_method_attributes = attributes.methods.retrieve(Method::New);
+        let variant_method_attributes = variant
+            .attributes
+            .method_attributes
+            .retrieve(Method::New)
+            .map(|(_, vma)| vma);
+
+        let name = variant.attributes.rename.clone().unwrap_or_else(|| {
+            Ident::new(
+                &snake_case(&variant_ident.to_string()),
+                variant_ident.span(),
+            )
+        });
+        let template = item_method_attributes
+            .and_then(|x| x.template.as_deref())
+            .unwrap_or("new_{}");
+        let fn_ident = Ident::new(
+            &template.replacen("{}", &name.to_string(), 1),
+            variant_ident.span(),
+        );
+
+        let vis = variant_method_attributes
+            .and_then(|x| x.common_settings.vis.as_ref())
+            .or(variant.attributes.common_settings.vis.as_ref())
+            .or(item_method_attributes.and_then(|x| x.common_settings.vis.as_ref()))
+            .or(attributes.common_settings.vis.as_ref())
+            .unwrap_or(&Vis::Default)
+            .as_visibility();
+
+        let levels = [
+            Some(&attributes.common_settings),
+            item_method_attributes.map(|x| &x.common_settings),
+            Some(&variant.attributes.common_settings),
+            variant_method_attributes.map(|x| &x.common_settings),
+        ];
+        let attrs = passthrough_attrs(levels.into_iter().flatten());
+        let where_predicates = where_predicates(levels.into_iter().flatten());
+        let (arguments, values) = arguments_and_values(&variant.all_fields, attributes, generics);
+
+        Self {
+            fn_ident: Cow::Owned(fn_ident),
+            path: quote!(Self::#variant_ident),
+            doc: format!("Constructs a new `{ident}::{variant_ident}`"),
+            vis,
+            attrs,
+            where_predicates,
+            arguments,
+            values,
+            shape: match variant.shape {
+                Fields::Named(_) => Shape::Named,
+                Fields::Unnamed(_) => Shape::Unnamed,
+                Fields::Unit => Shape::Unit,
+            },
+        }
+    }
+
+    pub(crate) fn fn_ident(&self) -> &Ident {
+        &self.fn_ident
+    }
+
+    pub(crate) fn where_predicates(&self) -> Vec<WherePredicate> {
+        self.where_predicates.clone()
+    }
+
+    pub(crate) fn build(&self) -> TokenStream {
+        let Self {
+            fn_ident,
+            path,
+            doc,
+            vis,
+            attrs,
+            arguments,
+            values,
+            shape,
+            ..
+        } = self;
+        let arguments = arguments.iter().map(|(ident, ty)| quote!(#ident: #ty));
+        let construct = match shape {
+            Shape::Named => {
+                let values = values.iter().map(|(member, value)| match (member, value) {
+                    (Member::Named(ident), Expr::Path(path)) if path.path.is_ident(ident) => {
+                        quote!(#ident)
+                    }
+                    _ => quote!(#member: #value),
+                });
+                quote!(#path { #(#values),* })
+            }
+            Shape::Unnamed => {
+                let values = values.iter().map(|(_, value)| value);
+                quote!(#path(#(#values),*))
+            }
+            Shape::Unit => quote!(#path),
+        };
+
+        quote! {
+            #[doc = #doc]
+            #(#attrs)*
+            #vis fn #fn_ident(#(#arguments),*) -> Self {
+                #construct
+            }
+        }
+    }
+}
+
+/// The constructor arguments for `fields`, and the value of every field in declaration order.
+fn arguments_and_values<'a>(
+    fields: &'a [Field],
+    attributes: &'a ItemAttributes,
+    generics: &'a Generics,
+) -> (Vec<Argument<'a>>, Vec<(&'a Member, Expr)>) {
+    let mut arguments = Vec::new();
+    let mut values = Vec::new();
+    for field in fields {
+        let span = field.span;
+        let query = Query::new(
+            &Method::New,
+            std::slice::from_ref(field),
+            attributes,
+            generics,
+            1,
+        );
+        let argument = if query.enabled() && field.attributes.default.is_none() {
+            let argument_ident = query
+                .argument_ident()
+                .unwrap_or_else(|| match &field.member {
+                    Member::Named(ident) => Cow::Borrowed(ident),
+                    Member::Unnamed(index) => Cow::Owned(format_ident!("field_{}", index.index)),
+                });
+            query
+                .determine_argument_ty_and_assigned_value(&argument_ident)
+                .and_then(|(argument_ty, value)| Some((argument_ident, argument_ty?, value)))
+        } else {
+            None
+        };
+
+        let value = if let Some((argument_ident, argument_ty, value)) = argument {
+            arguments.push((argument_ident, argument_ty));
+            value
+        } else {
+            field
+                .attributes
+                .default
+                .clone()
+                .unwrap_or_else(|| parse_quote_spanned!(span => Default::default()))
+        };
+        values.push((&field.member, value));
+    }
+    (arguments, values)
+}
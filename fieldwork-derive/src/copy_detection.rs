@@ -45,6 +45,12 @@ pub(crate) fn enable_copy_for_type(ty: &Type, method: Method) -> bool {
     }
 }
 
+/// Shared-ownership pointers, which are cheap to clone and usually more useful to callers as an
+/// owned handle than as a borrow of the pointee.
+pub(crate) fn enable_clone_for_type(ty: &Type) -> bool {
+    is_type(ty, "Arc") || is_type(ty, "Rc")
+}
+
 pub(crate) fn is_type(ty: &Type, type_ident: &'static str) -> bool {
     if let Type::Path(TypePath {
         path: Path { segments, .. },
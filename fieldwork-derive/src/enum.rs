@@ -3,12 +3,15 @@ use std::collections::BTreeMap;
 use proc_macro2::TokenStream;
 use quote::quote;
 use syn::{
-    Data, DeriveInput, Generics, Ident, Member, Variant,
+    Data, DeriveInput, Fields, Generics, Ident, Member, Variant,
     parse::{Parse, ParseStream},
     spanned::Spanned,
 };
 
-use crate::{Field, ItemAttributes, Method, Query};
+use crate::{
+    BoundedMethods, Field, ItemAttributes, Method, Query, VariantAttributes,
+    option_handling::extract_option_type,
+};
 
 /// A parsed `#[derive(Fieldwork)]` enum.
 #[cfg_attr(feature = "debug", derive(Debug))]
@@ -21,30 +24,57 @@ pub(crate) struct Enum {
 
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub(crate) struct EnumVariant {
+    pub(crate) ident: Ident,
+    /// Every field of the variant, for methods that cover the whole variant.
+    pub(crate) shape: Fields,
+    pub(crate) attributes: VariantAttributes,
     /// Only fields that have a usable name (natural or via `#[field = name]`).
     pub(crate) fields: Vec<Field>,
+    /// Every field of the variant, including unnamed ones, for variant constructors.
+    pub(crate) all_fields: Vec<Field>,
 }
 
 impl EnumVariant {
-    fn build(variant: &Variant) -> syn::Result<Self> {
+    fn build(variant: &Variant, item_attributes: &ItemAttributes) -> syn::Result<Self> {
         let ident = variant.ident.clone();
-        // Only keep fields that have a usable name: either a natural ident or
-        // an explicit `#[field = name]` override. Set variant_ident on each.
-        let fields = variant
+        let attributes = VariantAttributes::build(&variant.attrs)?;
+        let mut fields = variant
             .fields
             .iter()
             .enumerate()
             .map(|(i, f)| Field::build(f, i))
-            .collect::<syn::Result<Vec<_>>>()?
+            .collect::<syn::Result<Vec<_>>>()?;
+        // Item-level `positional(0 = name)` names unnamed fields that aren't named on their own.
+        for (index, field) in fields.iter_mut().enumerate() {
+            if let (Member::Unnamed(_), None) = (&field.member, &field.attributes.fn_ident) {
+                field.attributes.fn_ident = item_attributes.positional.get(&index).cloned();
+            }
+        }
+        let members: Vec<Member> = fields.iter().map(|f| f.member.clone()).collect();
+        let all_fields: Vec<Field> = fields
             .into_iter()
-            .filter(|f| f.attributes.fn_ident.is_some() || matches!(f.member, Member::Named(_)))
             .map(|mut f| {
                 f.variant_ident = Some(ident.clone());
+                f.variant_members.clone_from(&members);
+                f.variant_attributes = Some(attributes.clone());
                 f
             })
             .collect();
+        // Only keep fields that have a usable name: either a natural ident or
+        // an explicit `#[field = name]` override.
+        let fields = all_fields
+            .iter()
+            .filter(|f| f.attributes.fn_ident.is_some() || matches!(f.member, Member::Named(_)))
+            .cloned()
+            .collect();
 
-        Ok(Self { fields })
+        Ok(Self {
+            shape: variant.fields.clone(),
+            ident,
+            attributes,
+            fields,
+            all_fields,
+        })
     }
 }
 
@@ -57,10 +87,22 @@ impl Parse for Enum {
 
         let ident = input.ident.clone();
         let mut attributes = ItemAttributes::build(&input.attrs)?;
+        if attributes.into_parts || attributes.from_parts {
+            return Err(syn::Error::new(
+                ident.span(),
+                "`into_parts` and `from_parts` are only supported on structs",
+            ));
+        }
+        if attributes.include.contains(Method::Builder) {
+            return Err(syn::Error::new(
+                ident.span(),
+                "`builder` is only supported on structs",
+            ));
+        }
         let variants = de
             .variants
             .iter()
-            .map(EnumVariant::build)
+            .map(|variant| EnumVariant::build(variant, &attributes))
             .collect::<syn::Result<Vec<_>>>()?;
 
         let mut generics = input.generics.clone();
@@ -86,7 +128,8 @@ impl Enum {
     ///
     /// Returns an error if:
     /// - More than one occurrence in a group has a `#[field]` annotation (`decorated = true`).
-    /// - Any occurrence is annotated and the group has inconsistent field types.
+    /// - Any occurrence is annotated and the group has inconsistent field types that a
+    ///   `get(deref = ...)` or `get(as = ...)` doesn't unify.
     ///
     /// Silently drops groups where types differ but no occurrence is annotated.
     pub(crate) fn named_fields(&self) -> syn::Result<Vec<Vec<Field>>> {
@@ -127,9 +170,15 @@ impl Enum {
                 return Err(err);
             }
 
-            // All occurrences must share the same type.
+            // All occurrences must share the same type, unless the getter unifies them.
             let reference = &fields[0];
             if let Some(mismatched) = fields.iter().skip(1).find(|f| f.ty != reference.ty) {
+                if let Some(unifying) = fields.iter().find(|f| f.attributes.unifies_types()) {
+                    check_unified_options(&name, unifying, &fields)?;
+                    check_unified_methods(&name, &fields)?;
+                    result.push(fields);
+                    continue;
+                }
                 if fields.iter().any(|f| f.attributes.decorated) {
                     use quote::ToTokens;
                     let ref_ty = reference.ty.to_token_stream().to_string();
@@ -149,7 +198,8 @@ impl Enum {
                         format!(
                             "field `{name}` has type `{mis_ty}` in variant `{mis_variant}`, \
                              but type `{ref_ty}` in variant `{ref_variant}`; \
-                             fieldwork cannot generate a single accessor method"
+                             fieldwork cannot generate a single accessor method \
+                             without `get(deref = ...)` or `get(as = ...)`"
                         ),
                     );
                     err.combine(syn::Error::new(
@@ -168,23 +218,102 @@ impl Enum {
     }
 
     /// Generate all field accessor methods for this enum.
-    pub(crate) fn generate_methods(&self) -> syn::Result<TokenStream> {
+    pub(crate) fn generate_methods(&self) -> syn::Result<BoundedMethods> {
         let total_variants = self.variants.len();
-        let named_fields = self.named_fields()?;
-        let methods = named_fields
-            .iter()
-            .flat_map(|fields| {
-                Method::all().iter().flat_map(|method| {
-                    let query = Query::new(method, fields, &self.attributes, total_variants);
-                    let canonical = query.resolve();
-                    let alternate = query.as_alternate().and_then(|q| q.resolve());
-                    canonical.into_iter().chain(alternate)
-                })
-            })
-            .map(|resolved| resolved.build())
-            .collect();
+        let mut methods = BoundedMethods::default();
+        for fields in &self.named_fields()? {
+            for method in Method::all() {
+                let query = Query::new(
+                    method,
+                    fields,
+                    &self.attributes,
+                    &self.generics,
+                    total_variants,
+                );
+                let canonical = query.resolve();
+                let alternate = query.as_alternate().and_then(|q| q.resolve());
+                for resolved in canonical.into_iter().chain(alternate) {
+                    methods.push(query.where_predicates(), resolved.build());
+                }
+            }
+        }
         Ok(methods)
     }
+
+    /// The names of every field method generated for this enum, so item-level methods can avoid
+    /// them.
+    pub(crate) fn method_idents(&self) -> Vec<Ident> {
+        let total_variants = self.variants.len();
+        let mut idents = Vec::new();
+        for fields in &self.named_fields().unwrap_or_default() {
+            for method in Method::all() {
+                let query = Query::new(
+                    method,
+                    fields,
+                    &self.attributes,
+                    &self.generics,
+                    total_variants,
+                );
+                let alternate = query.as_alternate();
+                for query in std::iter::once(&query).chain(&alternate) {
+                    if query.resolve().is_some()
+                        && let Some(fn_ident) = query.fn_ident()
+                    {
+                        idents.push(fn_ident.into_owned());
+                    }
+                }
+            }
+        }
+        idents
+    }
+}
+
+/// A `deref` getter for a field whose types differ across variants borrows every occurrence the
+/// same way, so either all of them are `Option`s or none are. An `as` getter has no such
+/// restriction, since each occurrence is coerced to the given type.
+fn check_unified_options(name: &str, unifying: &Field, fields: &[Field]) -> syn::Result<()> {
+    let has_as_type = unifying
+        .attributes
+        .method_attributes
+        .retrieve(Method::Get)
+        .is_some_and(|(_, fma)| fma.as_type.is_some());
+    let options = fields
+        .iter()
+        .filter(|f| extract_option_type(&f.ty).is_some())
+        .count();
+    if has_as_type || options == 0 || options == fields.len() {
+        Ok(())
+    } else {
+        Err(syn::Error::new(
+            unifying.span,
+            format!(
+                "field `{name}` is an `Option` in some variants but not others; \
+                 use `get(as = ...)` to unify it"
+            ),
+        ))
+    }
+}
+
+/// Only `get` unifies differing types, so configuring any other method on such a field is an error
+/// rather than a method that silently isn't generated.
+fn check_unified_methods(name: &str, fields: &[Field]) -> syn::Result<()> {
+    for field in fields {
+        for method in Method::all() {
+            if let Some((span, fma)) = field.attributes.method_attributes.retrieve(*method)
+                && *method != Method::Get
+                && !fma.common_settings.skip
+            {
+                return Err(syn::Error::new(
+                    *span,
+                    format!(
+                        "field `{name}` has different types across variants, so only `get` can \
+                         be generated for it"
+                    ),
+                ));
+            }
+        }
+    }
+    Ok(())
 }
 
 /// Generate the match pattern for one arm, binding the field as its binding ident.
@@ -213,3 +342,80 @@ pub(crate) fn arm_pattern(field: &Field, override_binding: Option<&Ident>) -> To
         }
     }
 }
+
+/// Generate a pattern that matches the field's variant without binding anything.
+pub(crate) fn variant_pattern(field: &Field) -> TokenStream {
+    let variant_ident = field
+        .variant_ident
+        .as_ref()
+        .expect("variant_pattern called on non-enum field");
+    match &field.member {
+        Member::Named(_) => quote! { Self::#variant_ident { .. } },
+        Member::Unnamed(_) => quote! { Self::#variant_ident(..) },
+    }
+}
+
+/// Generate a by-value match arm that destructures the field's entire variant and rebuilds it,
+/// substituting `value(binding)` for the field. Any binding that would shadow `reserved` (e.g. a
+/// closure argument) is suffixed with `_binding`; unnamed sibling members bind as `field_{index}`.
+pub(crate) fn rebuild_arm(
+    field: &Field,
+    reserved: &Ident,
+    value: impl FnOnce(&Ident) -> TokenStream,
+) -> TokenStream {
+    let variant_ident = field
+        .variant_ident
+        .as_ref()
+        .expect("rebuild_arm called on non-enum field");
+    let binding_for = |member: &Member| {
+        let ident = match member {
+            _ if member == &field.member => field.binding().clone(),
+            Member::Named(ident) => ident.clone(),
+            Member::Unnamed(idx) => Ident::new(&format!("field_{}", idx.index), idx.span),
+        };
+        if &ident == reserved {
+            Ident::new(&format!("{ident}_binding"), ident.span())
+        } else {
+            ident
+        }
+    };
+    let mut value = Some(value);
+    let (patterns, values): (Vec<_>, Vec<_>) = field
+        .variant_members
+        .iter()
+        .map(|member| {
+            let binding = binding_for(member);
+            let is_target = member == &field.member;
+            let rebuilt = if is_target {
+                (value.take().expect("field appears once in its variant"))(&binding)
+            } else {
+                quote! { #binding }
+            };
+            match member {
+                Member::Named(ident) => {
+                    let pattern = if ident == &binding {
+                        quote! { #ident }
+                    } else {
+                        quote! { #ident: #binding }
+                    };
+                    let rebuilt = if is_target || ident != &binding {
+                        quote! { #ident: #rebuilt }
+                    } else {
+                        rebuilt
+                    };
+                    (pattern, rebuilt)
+                }
+                Member::Unnamed(_) => (quote! { #binding }, rebuilt),
+            }
+        })
+        .collect();
+
+    match &field.member {
+        Member::Named(_) => quote! {
+            Self::#variant_ident { #(#patterns),* } => Self::#variant_ident { #(#values),* }
+        },
+        Member::Unnamed(_) => quote! {
+            Self::#variant_ident(#(#patterns),*) => Self::#variant_ident(#(#values),*)
+        },
+    }
+}
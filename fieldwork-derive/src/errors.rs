@@ -1,7 +1,10 @@
-use std::fmt::{self, Display, Formatter};
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display, Formatter},
+};
 
 use proc_macro2::Span;
-use syn::Error;
+use syn::{Error, Expr, ExprAssign, ExprCall, ExprPath, ext::IdentExt, spanned::Spanned};
 
 fn damerau_levenshtein(a: &str, b: &str) -> usize {
     let a = a.chars().collect::<Vec<_>>();
@@ -69,6 +72,64 @@ pub(crate) fn invalid_key(span: Span, key: &str, valid_keys: &[&str]) -> Error {
     }
 }
 
+/// The key of a setting like `get`, `vis = "pub"`, or `get(copy)`, and its span.
+pub(crate) fn expr_key(expr: &Expr) -> Option<(String, Span)> {
+    match expr {
+        Expr::Assign(ExprAssign { left: key, .. }) | Expr::Call(ExprCall { func: key, .. }) => {
+            expr_key(key)
+        }
+        Expr::Path(ExprPath { path, .. }) => path
+            .get_ident()
+            .map(|ident| (ident.unraw().to_string(), expr.span())),
+        _ => None,
+    }
+}
+
+/// Settings can be spread across several attributes, but each key may only be given in one of
+/// them. The error points at both occurrences. Within a single attribute, the last of a repeated
+/// key wins. Keys in `repeatable` may be given any number of times.
+pub(crate) fn check_duplicate_keys(
+    attributes: impl IntoIterator<Item = Vec<(String, Span)>>,
+    repeatable: &[&str],
+) -> syn::Result<()> {
+    let mut seen = BTreeMap::new();
+    for (index, keys) in attributes.into_iter().enumerate() {
+        for (key, span) in keys {
+            if repeatable.contains(&key.as_str()) {
+                continue;
+            }
+            match seen.get(canonical_key(&key)) {
+                Some((first_index, first_key, first_span)) if *first_index != index => {
+                    let mut error = Error::new(span, format!("duplicate configuration `{key}`"));
+                    error.combine(Error::new(
+                        *first_span,
+                        format!("`{first_key}` first configured here"),
+                    ));
+                    return Err(error);
+                }
+                Some(_) => {}
+                None => {
+                    seen.insert(canonical_key(&key).to_owned(), (index, key, span));
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// The spelling of a key that has aliases, so that e.g. `name` and `rename` count as the same
+/// setting.
+fn canonical_key(key: &str) -> &str {
+    match key {
+        "name" => "rename",
+        "option_borrow_inner" => "option",
+        "option_predicates" => "option_predicate",
+        "rename_predicates" => "rename_predicate",
+        "bounds" => "where_clause",
+        key => key,
+    }
+}
+
 struct Keys<'a>(&'a [&'a str]);
 
 impl Display for Keys<'_> {
@@ -1,7 +1,7 @@
 use proc_macro2::Span;
 use syn::{Expr, ExprLit, Field as SynField, Ident, Index, Lit, Member, Type, spanned::Spanned};
 
-use crate::{FieldAttributes, is_fieldwork_attr};
+use crate::{FieldAttributes, VariantAttributes};
 
 // this represents a field within a struct that Access has been derived for
 #[cfg_attr(feature = "debug", derive(Debug))]
@@ -14,6 +14,11 @@ pub(crate) struct Field {
     pub(crate) doc: Vec<String>,
     /// The variant this field belongs to, for enum fields. `None` for struct fields.
     pub(crate) variant_ident: Option<Ident>,
+    /// Every member of the variant this field belongs to, in declaration order, including
+    /// unnamed fields that are not otherwise accessible. Empty for struct fields.
+    pub(crate) variant_members: Vec<Member>,
+    /// The `#[variant(...)]` configuration of the variant this field belongs to, for enum fields.
+    pub(crate) variant_attributes: Option<VariantAttributes>,
 }
 
 impl Field {
@@ -55,8 +60,7 @@ impl Field {
             })
             .collect();
 
-        let attributes =
-            FieldAttributes::build(field.attrs.iter().find(|attr| is_fieldwork_attr(attr)))?;
+        let attributes = FieldAttributes::build(&field.attrs)?;
 
         Ok(Field {
             member,
@@ -65,6 +69,8 @@ impl Field {
             attributes,
             doc,
             variant_ident: None,
+            variant_members: Vec::new(),
+            variant_attributes: None,
         })
     }
 }
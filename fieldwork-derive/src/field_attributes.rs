@@ -1,13 +1,14 @@
 use crate::{
     CommonSettings, Deprecation, FieldMethodAttributes, Method, MethodSettings, deprecation,
-    errors::invalid_key, with_common_settings, with_methods,
+    errors::{check_duplicate_keys, expr_key, invalid_key},
+    is_fieldwork_attr, with_common_settings, with_methods,
 };
-use proc_macro2::Span;
+use proc_macro2::{Group, Span, TokenStream, TokenTree};
 use quote::ToTokens;
 use syn::{
     Attribute, Error, Expr, ExprAssign, ExprCall, ExprLit, ExprPath, Ident, Lit, LitBool, LitStr,
-    Meta, MetaList, MetaNameValue, Path, Type, TypePath, punctuated::Punctuated, spanned::Spanned,
-    token::Comma,
+    Meta, MetaNameValue, Path, Type, TypePath, ext::IdentExt, parse::Parser, parse_quote_spanned,
+    punctuated::Punctuated, spanned::Spanned, token::Comma,
 };
 
 // this represents the configuration for the field
@@ -20,6 +21,11 @@ pub(crate) struct FieldAttributes {
     pub(crate) method_attributes: MethodSettings<(Span, FieldMethodAttributes)>,
     pub(crate) deref: Option<Type>,
     pub(crate) deprecate: Option<Deprecation>,
+    pub(crate) validate: Option<Path>,
+    pub(crate) error: Option<Type>,
+    /// The value for this field when it isn't otherwise provided, such as a skipped field in
+    /// `from_parts`.
+    pub(crate) default: Option<Expr>,
 
     pub(crate) common_settings: CommonSettings,
 }
@@ -41,23 +47,38 @@ impl FieldAttributes {
             && self.method_attributes.is_empty()
             && self.deref.is_none()
             && self.deprecate.is_none()
+            && self.validate.is_none()
+            && self.default.is_none()
             && !self.common_settings.any_active())
     }
 
+    /// Returns `true` if this annotation configures a getter that can return a single type for
+    /// fields whose types differ across variants, with `get(deref = ...)` or `get(as = ...)`.
+    pub(crate) fn unifies_types(&self) -> bool {
+        self.deref.is_some()
+            || self
+                .method_attributes
+                .retrieve(Method::Get)
+                .is_some_and(|(_, fma)| fma.deref.is_some() || fma.as_type.is_some())
+    }
+
     const VALID_KEYS: &[&str] = with_methods!(with_common_settings!(
         "argument",
+        "default",
         "deprecate",
+        "error",
         "name",
         "option_set_some",
         "rename",
         "skip",
+        "validate",
     ));
 
     fn handle_assign(&mut self, assign: &ExprAssign) -> syn::Result<()> {
         let ExprAssign { left, right, .. } = assign;
         let span = assign.span();
         let lhs = if let Expr::Path(ExprPath { path: lhs, .. }) = &**left {
-            lhs.get_ident().map(ToString::to_string)
+            lhs.get_ident().map(|ident| ident.unraw().to_string())
         } else {
             None
         }
@@ -68,6 +89,10 @@ impl FieldAttributes {
                 Self::VALID_KEYS,
             )
         })?;
+        if lhs == "default" {
+            self.default = Some((**right).clone());
+            return Ok(());
+        }
         match &**right {
             Expr::Lit(ExprLit {
                 lit: Lit::Str(rhs), ..
@@ -83,19 +108,22 @@ impl FieldAttributes {
         }
     }
 
-    fn handle_list(&mut self, list: &MetaList) -> syn::Result<()> {
-        self.decorated = true;
-        for expr in list.parse_args_with(Punctuated::<Expr, Comma>::parse_terminated)? {
-            match &expr {
+    fn handle_exprs(&mut self, exprs: &Punctuated<Expr, Comma>) -> syn::Result<()> {
+        for expr in exprs {
+            match expr {
                 Expr::Assign(assign) => self.handle_assign(assign)?,
 
                 Expr::Path(ExprPath { path, .. }) => self.handle_assign_bool_lit(
                     path.span(),
-                    &path.require_ident()?.to_string(),
+                    &path.require_ident()?.unraw().to_string(),
                     true,
                 )?,
 
                 Expr::Call(ExprCall { func, args, .. }) => match &**func {
+                    Expr::Path(ExprPath { path, .. }) if path.is_ident("attr") => {
+                        self.common_settings.handle_attr(args)?;
+                    }
+
                     Expr::Path(ExprPath { path, .. }) if path.is_ident("deprecate") => {
                         self.deprecate = Some(Deprecation::parse_list(args)?);
                     }
@@ -128,46 +156,61 @@ impl FieldAttributes {
         Ok(())
     }
 
-    pub(crate) fn build(attribute: Option<&Attribute>) -> syn::Result<Self> {
+    pub(crate) fn build(attributes: &[Attribute]) -> syn::Result<Self> {
         let mut field_attributes = Self::default();
-        match attribute {
-            Some(Attribute {
-                meta: Meta::Path(_),
-                ..
-            }) => field_attributes.decorated = true,
-
-            Some(Attribute {
-                meta: Meta::List(list),
-                ..
-            }) => field_attributes.handle_list(list)?,
+        let mut exprs = Punctuated::<Expr, Comma>::new();
+        let mut keys = Vec::new();
+        let mut list_span = None;
+        for attribute in attributes.iter().filter(|attr| is_fieldwork_attr(attr)) {
+            match &attribute.meta {
+                Meta::Path(_) => field_attributes.decorated = true,
 
-            Some(Attribute {
-                meta: Meta::NameValue(MetaNameValue { value, .. }),
-                ..
-            }) => match value {
-                Expr::Lit(ExprLit {
-                    lit: Lit::Str(value),
-                    ..
-                }) => {
-                    field_attributes.fn_ident = Some(value.parse()?);
+                Meta::List(list) => {
+                    field_attributes.decorated = true;
+                    list_span.get_or_insert_with(|| list.span());
+                    let list = Punctuated::<Expr, Comma>::parse_terminated
+                        .parse2(raw_keyword_keys(list.tokens.clone()))?;
+                    keys.push(list.iter().filter_map(expr_key).collect());
+                    exprs.extend(list);
                 }
 
-                Expr::Lit(ExprLit {
-                    lit: Lit::Bool(value),
-                    ..
-                }) => {
-                    if value.value {
-                        field_attributes.decorated = true;
-                    } else {
-                        field_attributes.common_settings.skip = true;
+                Meta::NameValue(MetaNameValue { value, .. }) => match value {
+                    Expr::Lit(ExprLit {
+                        lit: Lit::Str(value),
+                        ..
+                    }) => {
+                        keys.push(vec![(String::from("rename"), value.span())]);
+                        field_attributes.fn_ident = Some(value.parse()?);
                     }
-                }
-                _ => {
-                    return Err(Error::new(value.span(), "unexpected attribute format"));
-                }
-            },
 
-            _ => {}
+                    Expr::Lit(ExprLit {
+                        lit: Lit::Bool(value),
+                        ..
+                    }) => {
+                        if value.value {
+                            field_attributes.decorated = true;
+                        } else {
+                            keys.push(vec![(String::from("skip"), value.span())]);
+                            field_attributes.common_settings.skip = true;
+                        }
+                    }
+                    _ => {
+                        return Err(Error::new(value.span(), "unexpected attribute format"));
+                    }
+                },
+            }
+        }
+
+        check_duplicate_keys(keys, &["attr"])?;
+        field_attributes.handle_exprs(&exprs)?;
+
+        if let Some(span) = list_span
+            && field_attributes.validate.is_some() != field_attributes.error.is_some()
+        {
+            return Err(Error::new(
+                span,
+                "`validate` and `error` must be specified together",
+            ));
         }
 
         Ok(field_attributes)
@@ -180,6 +223,8 @@ impl FieldAttributes {
                 "deref" => self.deref = Some(rhs.parse()?),
                 "argument" => self.argument_ident = Some(rhs.parse()?),
                 "deprecate" => self.deprecate = Some(deprecation::from_str_lit(rhs)?),
+                "validate" => self.validate = Some(rhs.parse()?),
+                "error" => self.error = Some(rhs.parse()?),
                 _ => {
                     let method = Method::from_str_with_span(lhs, span)
                         .map_err(|_| invalid_key(span, lhs, Self::VALID_KEYS))?;
@@ -208,6 +253,14 @@ impl FieldAttributes {
                 }));
             }
             "deprecate" => self.deprecate = Some(deprecation::from_path(rhs)?),
+            "validate" => self.validate = Some(rhs.clone()),
+            "error" => {
+                self.error = Some(Type::Path(TypePath {
+                    attrs: Vec::new(),
+                    qself: None,
+                    path: rhs.clone(),
+                }));
+            }
 
             _ => {
                 let method = Method::from_str_with_span(lhs, span)
@@ -229,6 +282,9 @@ impl FieldAttributes {
         } else if lhs == "deprecate" {
             self.deprecate = value.then(deprecation::from_bare);
             Ok(())
+        } else if lhs == "default" {
+            self.default = value.then(|| parse_quote_spanned!(span => Default::default()));
+            Ok(())
         } else {
             let method = Method::from_str_with_span(lhs, span)
                 .map_err(|_| invalid_key(span, lhs, Self::VALID_KEYS))?;
@@ -245,3 +301,40 @@ impl FieldAttributes {
         }
     }
 }
+
+/// `as`, `const`, and `where` are keywords, so `get(as = "&dyn Display")`, `get(const)`, and
+/// `get(where = "T: Clone")` don't parse as expressions. This rewrites each `as =` and `where =`,
+/// and each `const` that's followed by `=`, `,`, or nothing, to a raw identifier such as `r#as`,
+/// at any depth, so they parse like any other key.
+pub(crate) fn raw_keyword_keys(tokens: TokenStream) -> TokenStream {
+    let mut tokens = tokens.into_iter().peekable();
+    let mut rewritten = TokenStream::new();
+    while let Some(token) = tokens.next() {
+        let token = match token {
+            TokenTree::Ident(ident) if is_keyword_key(&ident, tokens.peek()) => {
+                TokenTree::Ident(Ident::new_raw(&ident.to_string(), ident.span()))
+            }
+            TokenTree::Group(group) => {
+                let mut raw = Group::new(group.delimiter(), raw_keyword_keys(group.stream()));
+                raw.set_span(group.span());
+                TokenTree::Group(raw)
+            }
+            token => token,
+        };
+        rewritten.extend([token]);
+    }
+    rewritten
+}
+
+fn is_keyword_key(ident: &Ident, next: Option<&TokenTree>) -> bool {
+    let punct = match next {
+        Some(TokenTree::Punct(punct)) => Some(punct.as_char()),
+        Some(_) => return false,
+        None => None,
+    };
+    match punct {
+        Some('=') => ident == "as" || ident == "const" || ident == "where",
+        Some(',') | None => ident == "const",
+        Some(_) => false,
+    }
+}
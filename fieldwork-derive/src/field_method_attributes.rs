@@ -4,7 +4,7 @@ use quote::ToTokens;
 use std::string::ToString;
 use syn::{
     Error, Expr, ExprAssign, ExprCall, ExprLit, ExprPath, Ident, Lit, LitBool, LitStr, Path, Type,
-    TypePath, punctuated::Punctuated, spanned::Spanned, token::Comma,
+    TypePath, ext::IdentExt, punctuated::Punctuated, spanned::Spanned, token::Comma,
 };
 
 // this represents the configuration for the field, for a particular method
@@ -15,18 +15,36 @@ pub(crate) struct FieldMethodAttributes {
     pub(crate) argument_ident: Option<Ident>,
     pub(crate) doc: Option<String>,
     pub(crate) deref: Option<Type>,
+    /// The return type of a getter, which the field is coerced to (`get(as = "&dyn Display")`).
+    pub(crate) as_type: Option<Type>,
     pub(crate) deprecate: Option<Deprecation>,
+    pub(crate) validate: Option<Path>,
+    pub(crate) error: Option<Type>,
 
     pub(crate) common_settings: CommonSettings,
 }
 
 impl FieldMethodAttributes {
-    pub(crate) const VALID_KEYS: &[&str] =
-        with_common_settings!("argument", "deprecate", "doc", "name", "rename",);
+    pub(crate) const VALID_KEYS: &[&str] = with_common_settings!(
+        "argument",
+        "as",
+        "deprecate",
+        "doc",
+        "error",
+        "name",
+        "rename",
+        "validate",
+    );
 
     pub(crate) fn build(exprs: &Punctuated<Expr, Comma>) -> syn::Result<FieldMethodAttributes> {
         let mut field_method_attributes = Self::default();
         field_method_attributes.handle_exprs(exprs)?;
+        if field_method_attributes.validate.is_some() != field_method_attributes.error.is_some() {
+            return Err(Error::new(
+                exprs.span(),
+                "`validate` and `error` must be specified together",
+            ));
+        }
         Ok(field_method_attributes)
     }
 
@@ -36,10 +54,14 @@ impl FieldMethodAttributes {
                 Expr::Assign(assign) => self.handle_assign(assign)?,
                 Expr::Path(ExprPath { path, .. }) => self.handle_assign_bool_lit(
                     path.span(),
-                    &path.require_ident()?.to_string(),
+                    &path.require_ident()?.unraw().to_string(),
                     true,
                 )?,
                 Expr::Call(ExprCall { func, args, .. }) => match &**func {
+                    Expr::Path(ExprPath { path, .. }) if path.is_ident("attr") => {
+                        self.common_settings.handle_attr(args)?;
+                    }
+
                     Expr::Path(ExprPath { path, .. }) if path.is_ident("deprecate") => {
                         self.deprecate = Some(Deprecation::parse_list(args)?);
                     }
@@ -61,8 +83,9 @@ impl FieldMethodAttributes {
     fn handle_assign(&mut self, assign: &ExprAssign) -> syn::Result<()> {
         let ExprAssign { left, right, .. } = assign;
         let span = assign.span();
+        // `as` arrives as the raw identifier `r#as`, since it's a keyword; see `raw_keyword_keys`.
         let lhs = if let Expr::Path(ExprPath { path: lhs, .. }) = &**left {
-            lhs.get_ident().map(ToString::to_string)
+            lhs.get_ident().map(|ident| ident.unraw().to_string())
         } else {
             None
         }
@@ -90,8 +113,11 @@ impl FieldMethodAttributes {
                 "name" | "rename" => self.fn_ident = Some(rhs.parse()?),
                 "argument" => self.argument_ident = Some(rhs.parse()?),
                 "deref" => self.deref = Some(rhs.parse()?),
+                "as" => self.as_type = Some(rhs.parse()?),
                 "doc" => self.doc = Some(rhs.value()),
                 "deprecate" => self.deprecate = Some(deprecation::from_str_lit(rhs)?),
+                "validate" => self.validate = Some(rhs.parse()?),
+                "error" => self.error = Some(rhs.parse()?),
                 _ => return Err(invalid_key(span, lhs, Self::VALID_KEYS)),
             }
         }
@@ -120,7 +146,22 @@ impl FieldMethodAttributes {
                     path: rhs.clone(),
                 }));
             }
+            "as" => {
+                self.as_type = Some(Type::Path(TypePath {
+                    attrs: Vec::new(),
+                    qself: None,
+                    path: rhs.clone(),
+                }));
+            }
             "deprecate" => self.deprecate = Some(deprecation::from_path(rhs)?),
+            "validate" => self.validate = Some(rhs.clone()),
+            "error" => {
+                self.error = Some(Type::Path(TypePath {
+                    attrs: Vec::new(),
+                    qself: None,
+                    path: rhs.clone(),
+                }));
+            }
             _ => return Err(invalid_key(span, lhs, Self::VALID_KEYS)),
         }
 
@@ -1,12 +1,17 @@
 use crate::{
-    CommonSettings, ItemMethodAttributes, Method, MethodSettings, errors::invalid_key,
+    CommonSettings, ItemMethodAttributes, Method, MethodSettings,
+    errors::{check_duplicate_keys, expr_key, invalid_key},
+    field_attributes::raw_keyword_keys,
     is_fieldwork_attr, with_common_settings, with_methods,
 };
 use proc_macro2::Span;
 use quote::ToTokens;
+use std::collections::BTreeMap;
 use syn::{
-    Attribute, Error, Expr, ExprAssign, ExprCall, ExprLit, ExprPath, Lit, LitBool, LitStr, Meta,
-    WhereClause, WherePredicate,
+    Attribute, Error, Expr, ExprAssign, ExprCall, ExprLit, ExprPath, Ident, Lit, LitBool, LitStr,
+    Meta, Path, WhereClause, WherePredicate,
+    ext::IdentExt,
+    parse::Parser,
     punctuated::Punctuated,
     spanned::Spanned,
     token::{Comma, Where},
@@ -19,24 +24,70 @@ pub(crate) struct ItemAttributes {
     pub(crate) methods: MethodSettings<ItemMethodAttributes>,
     pub(crate) include: MethodSettings<bool>,
     pub(crate) where_clause: Option<WhereClause>,
+    pub(crate) into_parts: bool,
+    pub(crate) from_parts: bool,
+    /// Use a generated struct rather than a tuple for `into_parts` and `from_parts`, optionally
+    /// with an explicit name.
+    pub(crate) parts_struct: bool,
+    pub(crate) parts_ident: Option<Ident>,
+    /// Enum-only: `is_`, `as_`, `as_*_mut`, and `into_` methods for each variant. The span is
+    /// kept for the error on structs.
+    pub(crate) variants: Option<Span>,
+    /// Enum-only: a fieldless kind enum with `kind()` and `variant_name()` methods, optionally
+    /// with an explicit name and derives. The span is kept for the error on structs.
+    pub(crate) kind: Option<Span>,
+    pub(crate) kind_ident: Option<Ident>,
+    pub(crate) kind_derives: Option<Vec<Path>>,
+    /// Enum-only: methods that move a value from one variant to another.
+    pub(crate) transitions: Vec<TransitionSettings>,
+    /// Enum-only: names for unnamed tuple-variant fields by position, as if each were annotated
+    /// with `#[field = name]`.
+    pub(crate) positional: BTreeMap<usize, Ident>,
 
     pub(crate) common_settings: CommonSettings,
 }
 
+/// One `transition(from = Pending, to = Active)`, generating `into_active`.
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub(crate) struct TransitionSettings {
+    pub(crate) span: Span,
+    pub(crate) from: Ident,
+    pub(crate) to: Ident,
+    pub(crate) fn_ident: Option<Ident>,
+}
+
 impl ItemAttributes {
-    const VALID_KEYS: &[&str] = with_methods!(with_common_settings!("where_clause", "bounds",));
+    const VALID_KEYS: &[&str] = with_methods!(with_common_settings!(
+        "bounds",
+        "from_parts",
+        "into_parts",
+        "kind",
+        "parts",
+        "positional",
+        "transition",
+        "variants",
+        "where_clause",
+    ));
+
+    const KIND_VALID_KEYS: &[&str] = &["derive", "name"];
+    const TRANSITION_VALID_KEYS: &[&str] = &["from", "name", "to"];
 
     pub(crate) fn build(attributes: &[Attribute]) -> syn::Result<Self> {
         let mut item_attributes = Self::default();
-        let Some(attr) = attributes.iter().find(|x| is_fieldwork_attr(x)) else {
-            return Ok(item_attributes);
-        };
-        let Meta::List(list) = &attr.meta else {
-            return Err(Error::new(attr.span(), "unexpected attribute format"));
-        };
+        let mut list = Punctuated::<Expr, Comma>::new();
+        let mut keys = Vec::new();
+        for attr in attributes.iter().filter(|x| is_fieldwork_attr(x)) {
+            let Meta::List(meta_list) = &attr.meta else {
+                return Err(Error::new(attr.span(), "unexpected attribute format"));
+            };
+            let exprs = Punctuated::<Expr, Comma>::parse_terminated
+                .parse2(raw_keyword_keys(meta_list.tokens.clone()))?;
+            keys.push(exprs.iter().filter_map(expr_key).collect());
+            list.extend(exprs);
+        }
 
-        item_attributes
-            .handle_list(&list.parse_args_with(Punctuated::<Expr, Comma>::parse_terminated)?)?;
+        check_duplicate_keys(keys, &["attr", "transition"])?;
+        item_attributes.handle_list(&list)?;
 
         Ok(item_attributes)
     }
@@ -48,16 +99,32 @@ impl ItemAttributes {
 
                 Expr::Path(ExprPath { path, .. }) => self.handle_assign_bool_lit(
                     path.span(),
-                    &path.require_ident()?.to_string(),
+                    &path.require_ident()?.unraw().to_string(),
                     true,
                 )?,
 
                 Expr::Call(ExprCall { func, args, .. }) => match &**func {
+                    Expr::Path(ExprPath { path, .. }) if path.is_ident("attr") => {
+                        self.common_settings.handle_attr(args)?;
+                    }
+
+                    Expr::Path(ExprPath { path, .. }) if path.is_ident("kind") => {
+                        self.handle_kind(path.span(), args)?;
+                    }
+
+                    Expr::Path(ExprPath { path, .. }) if path.is_ident("transition") => {
+                        self.handle_transition(expr.span(), args)?;
+                    }
+
+                    Expr::Path(ExprPath { path, .. }) if path.is_ident("positional") => {
+                        self.handle_positional(args)?;
+                    }
+
                     Expr::Path(ExprPath { path: method, .. }) => match Method::try_from(method) {
                         Ok(method) => {
                             self.include.insert(method, true);
                             self.methods
-                                .insert(method, ItemMethodAttributes::build(args)?);
+                                .insert(method, ItemMethodAttributes::build(method, args)?);
                         }
 
                         Err(_) => {
@@ -85,11 +152,97 @@ impl ItemAttributes {
         Ok(())
     }
 
+    /// `kind(name = StatusKind, derive(Clone, Copy, Debug))`
+    fn handle_kind(&mut self, span: Span, args: &Punctuated<Expr, Comma>) -> syn::Result<()> {
+        self.kind = Some(span);
+        for arg in args {
+            match (nested_key(arg).as_deref(), arg) {
+                (Some("name"), Expr::Assign(ExprAssign { right, .. })) => {
+                    self.kind_ident = Some(ident_value(right)?);
+                }
+
+                (Some("derive"), Expr::Call(ExprCall { args, .. })) => {
+                    self.kind_derives = Some(
+                        args.iter()
+                            .map(|derive| match derive {
+                                Expr::Path(ExprPath { path, .. }) => Ok(path.clone()),
+                                derive => Err(Error::new(derive.span(), "expected a derive path")),
+                            })
+                            .collect::<syn::Result<_>>()?,
+                    );
+                }
+
+                _ => return Err(invalid_nested_key(arg, Self::KIND_VALID_KEYS)),
+            }
+        }
+        Ok(())
+    }
+
+    /// `transition(from = Pending, to = Active, name = activate)`
+    fn handle_transition(&mut self, span: Span, args: &Punctuated<Expr, Comma>) -> syn::Result<()> {
+        let (mut from, mut to, mut fn_ident) = (None, None, None);
+        for arg in args {
+            let invalid = || invalid_nested_key(arg, Self::TRANSITION_VALID_KEYS);
+            let Expr::Assign(ExprAssign { right, .. }) = arg else {
+                return Err(invalid());
+            };
+            let value = match nested_key(arg).as_deref() {
+                Some("from") => &mut from,
+                Some("to") => &mut to,
+                Some("name") => &mut fn_ident,
+                _ => return Err(invalid()),
+            };
+            *value = Some(ident_value(right)?);
+        }
+
+        let (Some(from), Some(to)) = (from, to) else {
+            return Err(Error::new(
+                span,
+                "`transition` needs both `from` and `to` variants",
+            ));
+        };
+        self.transitions.push(TransitionSettings {
+            span,
+            from,
+            to,
+            fn_ident,
+        });
+        Ok(())
+    }
+
+    /// `positional(0 = id, 1 = payload)`
+    fn handle_positional(&mut self, args: &Punctuated<Expr, Comma>) -> syn::Result<()> {
+        for arg in args {
+            let Expr::Assign(ExprAssign { left, right, .. }) = arg else {
+                return Err(Error::new(arg.span(), "expected `index = name`"));
+            };
+            let Expr::Lit(ExprLit {
+                lit: Lit::Int(index),
+                ..
+            }) = &**left
+            else {
+                return Err(Error::new(left.span(), "expected a field index"));
+            };
+            let name = ident_value(right)?;
+            if self
+                .positional
+                .insert(index.base10_parse()?, name)
+                .is_some()
+            {
+                return Err(Error::new(
+                    index.span(),
+                    format!("field {index} is already named"),
+                ));
+            }
+        }
+        Ok(())
+    }
+
     fn handle_assign(&mut self, assign: &ExprAssign) -> syn::Result<()> {
         let ExprAssign { left, right, .. } = assign;
         let span = assign.span();
         let lhs = if let Expr::Path(ExprPath { path: lhs, .. }) = &**left {
-            lhs.get_ident().map(ToString::to_string)
+            lhs.get_ident().map(|ident| ident.unraw().to_string())
         } else {
             None
         }
@@ -115,6 +268,12 @@ impl ItemAttributes {
     }
 
     fn handle_assign_str_lit(&mut self, span: Span, lhs: &str, rhs: &LitStr) -> Result<(), Error> {
+        if lhs == "where" {
+            return Err(Error::new(
+                span,
+                "`where` bounds individual methods; use `where_clause` to bound the whole impl",
+            ));
+        }
         if !self.common_settings.handle_assign_str_lit(lhs, rhs)? {
             match lhs {
                 "where_clause" | "bounds" => {
@@ -124,6 +283,14 @@ impl ItemAttributes {
                         where_token: Where::default(),
                     });
                 }
+                "parts" => {
+                    self.parts_struct = true;
+                    self.parts_ident = Some(rhs.parse()?);
+                }
+                "kind" => {
+                    self.kind = Some(span);
+                    self.kind_ident = Some(rhs.parse()?);
+                }
                 _ => return Err(invalid_key(span, lhs, Self::VALID_KEYS)),
             }
         }
@@ -133,6 +300,20 @@ impl ItemAttributes {
     fn handle_assign_bool_lit(&mut self, span: Span, lhs: &str, value: bool) -> Result<(), Error> {
         if self.common_settings.handle_assign_bool_lit(lhs, value) {
             Ok(())
+        } else if lhs == "variants" {
+            self.variants = value.then_some(span);
+            Ok(())
+        } else if lhs == "kind" {
+            self.kind = value.then_some(span);
+            Ok(())
+        } else if let Some(setting) = match lhs {
+            "into_parts" => Some(&mut self.into_parts),
+            "from_parts" => Some(&mut self.from_parts),
+            "parts" => Some(&mut self.parts_struct),
+            _ => None,
+        } {
+            *setting = value;
+            Ok(())
         } else {
             let method = Method::from_str_with_span(lhs, span)
                 .map_err(|_| invalid_key(span, lhs, Self::VALID_KEYS))?;
@@ -146,3 +327,28 @@ impl ItemAttributes {
         }
     }
 }
+
+/// The key of a nested setting like `name = Foo` or `derive(Clone)`.
+fn nested_key(expr: &Expr) -> Option<String> {
+    expr_key(expr).map(|(key, _)| key)
+}
+
+fn invalid_nested_key(expr: &Expr, valid_keys: &[&str]) -> Error {
+    let key = match expr {
+        Expr::Assign(ExprAssign { left: key, .. }) | Expr::Call(ExprCall { func: key, .. }) => key,
+        expr => expr,
+    };
+    invalid_key(key.span(), &key.to_token_stream().to_string(), valid_keys)
+}
+
+/// An ident given either bare (`name = Foo`) or as a string (`name = "Foo"`).
+fn ident_value(expr: &Expr) -> syn::Result<Ident> {
+    match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Str(value),
+            ..
+        }) => value.parse(),
+        Expr::Path(ExprPath { path, .. }) => path.require_ident().cloned(),
+        expr => Err(Error::new(expr.span(), "expected an ident")),
+    }
+}
@@ -1,8 +1,9 @@
-use crate::{CommonSettings, errors::invalid_key, with_common_settings};
+use crate::{CommonSettings, Method, errors::invalid_key, with_common_settings};
 use proc_macro2::Span;
+use quote::ToTokens;
 use syn::{
-    Error, Expr, ExprAssign, ExprLit, ExprPath, Lit, LitBool, punctuated::Punctuated,
-    spanned::Spanned, token::Comma,
+    Error, Expr, ExprAssign, ExprCall, ExprLit, ExprPath, Lit, LitBool, ext::IdentExt,
+    punctuated::Punctuated, spanned::Spanned, token::Comma,
 };
 
 // this represents the configuration passed to #[fieldwork] for a particular method
@@ -12,28 +13,53 @@ use syn::{
 pub(crate) struct ItemMethodAttributes {
     pub(crate) template: Option<String>,
     pub(crate) doc_template: Option<String>,
+    /// `builder(typestate)`: track required fields in the builder's type rather than at runtime.
+    pub(crate) typestate: bool,
 
     pub(crate) common_settings: CommonSettings,
 }
 
 impl ItemMethodAttributes {
     const VALID_KEYS: &[&str] = with_common_settings!("doc_template", "template",);
+    const BUILDER_VALID_KEYS: &[&str] =
+        with_common_settings!("doc_template", "template", "typestate",);
 
-    pub(crate) fn build(exprs: &Punctuated<Expr, Comma>) -> syn::Result<Self> {
+    pub(crate) fn build(method: Method, exprs: &Punctuated<Expr, Comma>) -> syn::Result<Self> {
         let mut item_method_attributes = Self::default();
-        item_method_attributes.handle_exprs(exprs)?;
+        item_method_attributes.handle_exprs(method, exprs)?;
         Ok(item_method_attributes)
     }
 
-    fn handle_exprs(&mut self, exprs: &Punctuated<Expr, Comma>) -> syn::Result<()> {
+    fn valid_keys(method: Method) -> &'static [&'static str] {
+        if method == Method::Builder {
+            Self::BUILDER_VALID_KEYS
+        } else {
+            Self::VALID_KEYS
+        }
+    }
+
+    fn handle_exprs(&mut self, method: Method, exprs: &Punctuated<Expr, Comma>) -> syn::Result<()> {
         for expr in exprs {
             match expr {
-                Expr::Assign(assign) => self.handle_assign(assign)?,
+                Expr::Assign(assign) => self.handle_assign(method, assign)?,
                 Expr::Path(ExprPath { path, .. }) => self.handle_assign_bool_lit(
+                    method,
                     path.span(),
-                    &path.require_ident()?.to_string(),
+                    &path.require_ident()?.unraw().to_string(),
                     true,
                 )?,
+                Expr::Call(ExprCall { func, args, .. }) => match &**func {
+                    Expr::Path(ExprPath { path, .. }) if path.is_ident("attr") => {
+                        self.common_settings.handle_attr(args)?;
+                    }
+                    func => {
+                        return Err(invalid_key(
+                            func.span(),
+                            &func.to_token_stream().to_string(),
+                            Self::valid_keys(method),
+                        ));
+                    }
+                },
                 expr => return Err(Error::new(expr.span(), "not recognized")),
             }
         }
@@ -41,11 +67,11 @@ impl ItemMethodAttributes {
         Ok(())
     }
 
-    fn handle_assign(&mut self, assign: &ExprAssign) -> syn::Result<()> {
+    fn handle_assign(&mut self, method: Method, assign: &ExprAssign) -> syn::Result<()> {
         let ExprAssign { left, right, .. } = assign;
         let span = assign.span();
         let lhs = if let Expr::Path(ExprPath { path: lhs, .. }) = &**left {
-            lhs.get_ident().map(ToString::to_string)
+            lhs.get_ident().map(|ident| ident.unraw().to_string())
         } else {
             None
         }
@@ -54,27 +80,37 @@ impl ItemMethodAttributes {
         match &**right {
             Expr::Lit(ExprLit {
                 lit: Lit::Str(rhs), ..
-            }) => self.handle_assign_str_lit(span, &lhs, rhs),
+            }) => self.handle_assign_str_lit(method, span, &lhs, rhs),
 
             Expr::Lit(ExprLit {
                 lit: Lit::Bool(LitBool { value, .. }),
                 ..
-            }) => self.handle_assign_bool_lit(span, &lhs, *value),
+            }) => self.handle_assign_bool_lit(method, span, &lhs, *value),
 
             _ => Err(Error::new(span, "not recognized")),
         }
     }
 
-    fn handle_assign_bool_lit(&mut self, span: Span, lhs: &str, value: bool) -> syn::Result<()> {
+    fn handle_assign_bool_lit(
+        &mut self,
+        method: Method,
+        span: Span,
+        lhs: &str,
+        value: bool,
+    ) -> syn::Result<()> {
         if self.common_settings.handle_assign_bool_lit(lhs, value) {
             Ok(())
+        } else if lhs == "typestate" && method == Method::Builder {
+            self.typestate = value;
+            Ok(())
         } else {
-            Err(invalid_key(span, lhs, Self::VALID_KEYS))
+            Err(invalid_key(span, lhs, Self::valid_keys(method)))
         }
     }
 
     fn handle_assign_str_lit(
         &mut self,
+        method: Method,
         span: Span,
         lhs: &str,
         rhs: &syn::LitStr,
@@ -83,7 +119,7 @@ impl ItemMethodAttributes {
             match lhs {
                 "template" => self.template = Some(rhs.value()),
                 "doc_template" => self.doc_template = Some(rhs.value()),
-                _ => return Err(invalid_key(span, lhs, Self::VALID_KEYS)),
+                _ => return Err(invalid_key(span, lhs, Self::valid_keys(method))),
             }
         }
         Ok(())
@@ -0,0 +1,162 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote, quote_spanned};
+use std::borrow::Cow;
+use syn::{Attribute, Error, Fields, Ident, Path, Visibility};
+
+use crate::{
+    Constructor, Enum, Transitions, Variants,
+    common_settings::{Vis, passthrough_attrs},
+    r#enum::EnumVariant,
+};
+
+const DEFAULT_DERIVES: &[&str] = &["Clone", "Copy", "PartialEq", "Eq", "Hash", "Debug"];
+
+/// The item-level `kind` feature for enums: a fieldless `{Enum}Kind` enum with one variant per
+/// variant of the enum, a `kind()` method returning it, and `variant_name()`.
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub(crate) struct Kind<'a> {
+    ident: &'a Ident,
+    variants: &'a [EnumVariant],
+    generated_ident: Ident,
+    derives: Cow<'a, [Path]>,
+    vis: Cow<'a, Visibility>,
+    attrs: Vec<&'a Attribute>,
+}
+
+impl<'a> Kind<'a> {
+    pub(crate) fn new(enum_item: &'a Enum) -> syn::Result<Option<Self>> {
+        let attributes = &enum_item.attributes;
+        let Some(span) = attributes.kind else {
+            return Ok(None);
+        };
+        let ident = &enum_item.ident;
+        check_method_names(enum_item, span)?;
+
+        Ok(Some(Self {
+            ident,
+            variants: &enum_item.variants,
+            generated_ident: attributes
+                .kind_ident
+                .clone()
+                .unwrap_or_else(|| format_ident!("{ident}Kind", span = span)),
+            derives: attributes.kind_derives.as_deref().map_or_else(
+                || {
+                    Cow::Owned(
+                        DEFAULT_DERIVES
+                            .iter()
+                            .map(|derive| Ident::new(derive, span).into())
+                            .collect(),
+                    )
+                },
+                Cow::Borrowed,
+            ),
+            vis: attributes
+                .common_settings
+                .vis
+                .as_ref()
+                .unwrap_or(&Vis::Default)
+                .as_visibility(),
+            attrs: passthrough_attrs([&attributes.common_settings]),
+        }))
+    }
+
+    pub(crate) fn methods(&self) -> TokenStream {
+        let Self {
+            ident,
+            generated_ident,
+            vis,
+            attrs,
+            ..
+        } = self;
+        let kind_doc = format!("Returns the [`{generated_ident}`] of this `{ident}`");
+        let name_doc = format!("Returns the name of this `{ident}`'s variant");
+        let (kind_arms, name_arms): (Vec<_>, Vec<_>) = self
+            .variants
+            .iter()
+            .map(|variant| {
+                let variant_ident = &variant.ident;
+                let span = variant_ident.span();
+                let name = variant_ident.to_string();
+                let pattern = match &variant.shape {
+                    Fields::Named(_) => quote_spanned!(span=> Self::#variant_ident { .. }),
+                    Fields::Unnamed(_) => quote_spanned!(span=> Self::#variant_ident(..)),
+                    Fields::Unit => quote_spanned!(span=> Self::#variant_ident),
+                };
+                (
+                    quote_spanned!(span=> #pattern => #generated_ident::#variant_ident),
+                    quote_spanned!(span=> #pattern => #name),
+                )
+            })
+            .unzip();
+
+        quote! {
+            #[doc = #kind_doc]
+            #(#attrs)*
+            #vis fn kind(&self) -> #generated_ident {
+                match *self {
+                    #(#kind_arms,)*
+                }
+            }
+
+            #[doc = #name_doc]
+            #(#attrs)*
+            #vis fn variant_name(&self) -> &'static str {
+                match *self {
+                    #(#name_arms,)*
+                }
+            }
+        }
+    }
+
+    /// The generated kind enum.
+    pub(crate) fn kind_enum(&self) -> TokenStream {
+        let Self {
+            ident,
+            generated_ident,
+            derives,
+            vis,
+            ..
+        } = self;
+        let doc = format!("The variants of [`{ident}`], without their fields");
+        let variants = self.variants.iter().map(|variant| {
+            let variant_ident = &variant.ident;
+            let doc = format!("[`{ident}::{variant_ident}`]");
+            quote!(#[doc = #doc] #variant_ident)
+        });
+        let derives = derives.iter();
+
+        quote! {
+            #[doc = #doc]
+            #[derive(#(#derives),*)]
+            #vis enum #generated_ident {
+                #(#variants,)*
+            }
+        }
+    }
+}
+
+/// `kind()` and `variant_name()` can't share an impl block with another generated method of the
+/// same name, such as a getter for a field called `kind`.
+fn check_method_names(enum_item: &Enum, span: Span) -> syn::Result<()> {
+    let transitions = Transitions::new(enum_item).ok().flatten();
+    let variants = Variants::new(enum_item);
+    let constructors = Constructor::for_variants(enum_item);
+    let taken = enum_item
+        .method_idents()
+        .into_iter()
+        .chain(constructors.iter().map(|c| c.fn_ident().clone()))
+        .chain(transitions.iter().flat_map(|t| t.method_idents().cloned()))
+        .chain(variants.iter().flat_map(Variants::method_idents));
+
+    for ident in taken {
+        if ident == "kind" || ident == "variant_name" {
+            let mut err = Error::new(
+                ident.span(),
+                format!("`{ident}` is also generated by `kind`; rename or skip this method"),
+            );
+            err.combine(Error::new(span, "`kind` enabled here"));
+            return Err(err);
+        }
+    }
+    Ok(())
+}
@@ -17,7 +17,11 @@ use proc_macro2::TokenStream as TokenStream2;
 use quote::quote;
 use syn::{Attribute, Data, DeriveInput};
 
+mod bounded_methods;
+mod builder;
+mod collection_handling;
 mod common_settings;
+mod constructor;
 mod copy_detection;
 mod deprecation;
 mod deref_handling;
@@ -28,31 +32,44 @@ mod field_attributes;
 mod field_method_attributes;
 mod item_attributes;
 mod item_method_attributes;
+mod kind;
 mod method;
 mod option_handling;
+mod parts;
 mod query;
 mod resolved;
 mod r#struct;
+mod transitions;
+mod variant_attributes;
+mod variants;
 
 #[cfg(test)]
 mod coverage_tests;
 
+pub(crate) use bounded_methods::BoundedMethods;
+pub(crate) use builder::Builder;
 pub(crate) use common_settings::{CommonSettings, with_common_settings};
+pub(crate) use constructor::Constructor;
 pub(crate) use deprecation::Deprecation;
-pub(crate) use r#enum::{Enum, arm_pattern};
+pub(crate) use r#enum::{Enum, arm_pattern, rebuild_arm, variant_pattern};
 pub(crate) use field::Field;
 pub(crate) use field_attributes::FieldAttributes;
 pub(crate) use field_method_attributes::FieldMethodAttributes;
 pub(crate) use item_attributes::ItemAttributes;
 pub(crate) use item_method_attributes::ItemMethodAttributes;
+pub(crate) use kind::Kind;
 pub(crate) use method::{Method, MethodSettings, with_methods};
+pub(crate) use parts::Parts;
 pub(crate) use query::Query;
 pub(crate) use resolved::Resolved;
 pub(crate) use r#struct::Struct;
+pub(crate) use transitions::Transitions;
+pub(crate) use variant_attributes::VariantAttributes;
+pub(crate) use variants::Variants;
 
 /// Derive field accessor methods for a struct or enum. See
 /// [`fieldwork`](https://docs.rs/fieldwork) for full documentation.
-#[proc_macro_derive(Fieldwork, attributes(fieldwork, field))]
+#[proc_macro_derive(Fieldwork, attributes(fieldwork, field, variant))]
 pub fn derive_fieldwork(input: TokenStream) -> TokenStream {
     derive_fieldwork_internal(input.into()).into()
 }
@@ -83,24 +100,50 @@ fn derive_struct(input: TokenStream2) -> TokenStream2 {
         Err(e) => return e.to_compile_error(),
     };
 
-    let impls = fields
-        .iter()
-        .flat_map(|field| {
-            Method::all().iter().flat_map(|method| {
-                let query = Query::new(method, std::slice::from_ref(field), &attributes, 1);
-                let canonical = query.resolve();
-                let alternate = query.as_alternate().and_then(|q| q.resolve());
-                canonical.into_iter().chain(alternate)
-            })
-        })
-        .map(|resolved| resolved.build())
-        .collect::<TokenStream2>();
+    let builder = match Builder::new(&ident, &fields, &attributes, &generics) {
+        Ok(builder) => builder,
+        Err(e) => return e.to_compile_error(),
+    };
+    let builder_items = builder.as_ref().map(Builder::items);
+
+    let mut methods = BoundedMethods::default();
+    if let Some(constructor) = Constructor::new(&ident, &fields, &attributes, &generics) {
+        methods.push(constructor.where_predicates(), constructor.build());
+    }
+    if let Some(builder) = &builder {
+        methods.push(builder.where_predicates(), builder.methods());
+    }
+    for field in &fields {
+        for method in Method::all() {
+            let query = Query::new(
+                method,
+                std::slice::from_ref(field),
+                &attributes,
+                &generics,
+                1,
+            );
+            let canonical = query.resolve();
+            let alternate = query.as_alternate().and_then(|q| q.resolve());
+            for resolved in canonical.into_iter().chain(alternate) {
+                methods.push(query.where_predicates(), resolved.build());
+            }
+        }
+    }
+    let parts = Parts::new(&ident, &fields, &attributes, &generics);
+    let parts_methods = parts.as_ref().map(Parts::methods);
+    let parts_struct = parts.as_ref().and_then(Parts::parts_struct);
 
+    let bounded_impls = methods.bounded_impls(&ident, &generics);
+    let methods = methods.unbounded();
     let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
     quote! {
         impl #impl_generics #ident #type_generics #where_clause {
-            #impls
+            #methods
+            #parts_methods
         }
+        #bounded_impls
+        #parts_struct
+        #builder_items
     }
 }
 
@@ -110,17 +153,54 @@ fn derive_enum(input: TokenStream2) -> TokenStream2 {
         Err(e) => return e.to_compile_error(),
     };
 
-    let methods = match enum_item.generate_methods() {
+    let field_methods = match enum_item.generate_methods() {
         Ok(methods) => methods,
         Err(e) => return e.to_compile_error(),
     };
+    let transitions = match Transitions::new(&enum_item) {
+        Ok(transitions) => transitions,
+        Err(e) => return e.to_compile_error(),
+    };
+    let kind = match Kind::new(&enum_item) {
+        Ok(kind) => kind,
+        Err(e) => return e.to_compile_error(),
+    };
+    let kind_enum = kind.as_ref().map(Kind::kind_enum);
+
+    let mut methods = BoundedMethods::default();
+    for constructor in Constructor::for_variants(&enum_item) {
+        methods.push(constructor.where_predicates(), constructor.build());
+    }
+    methods.push(
+        Vec::new(),
+        kind.as_ref().map(Kind::methods).unwrap_or_default(),
+    );
+    methods.push(
+        Vec::new(),
+        Variants::new(&enum_item)
+            .map(|v| v.methods())
+            .unwrap_or_default(),
+    );
+    methods.push(
+        Vec::new(),
+        transitions
+            .as_ref()
+            .map(Transitions::methods)
+            .unwrap_or_default(),
+    );
+    methods.append(field_methods);
+
     let ident = &enum_item.ident;
+    let bounded_impls = methods.bounded_impls(ident, &enum_item.generics);
+    let methods = methods.unbounded();
     let (impl_generics, type_generics, where_clause) = enum_item.generics.split_for_impl();
 
     quote! {
         impl #impl_generics #ident #type_generics #where_clause {
             #methods
         }
+        #bounded_impls
+        #kind_enum
     }
 }
 
@@ -11,11 +11,31 @@ pub(crate) enum Method {
     Without,
     Take,
     IntoField,
+    Replace,
+    Update,
+    Map,
+    Push,
+    Extend,
+    Clear,
+    WithItem,
+    GetItem,
+    GetItemMut,
+    InsertItem,
+    RemoveItem,
+    Enable,
+    Disable,
+    Toggle,
+    Enabled,
+    Disabled,
+    Toggled,
+    Cloned,
+    New,
+    Builder,
 }
 
 macro_rules! with_methods {
     ($macro:ident!($($inner:literal,)*)) => {
-        $macro!($($inner,)* "get", "set", "with", "get_mut", "without", "take", "into_field", )
+        $macro!($($inner,)* "get", "set", "with", "get_mut", "without", "take", "into_field", "replace", "update", "map", "push", "extend", "clear", "with_item", "get_item", "get_item_mut", "insert_item", "remove_item", "enable", "disable", "toggle", "enabled", "disabled", "toggled", "cloned", "new", "builder", )
     };
 }
 pub(crate) use with_methods;
@@ -30,6 +50,26 @@ impl Method {
             Self::Without,
             Self::Take,
             Self::IntoField,
+            Self::Replace,
+            Self::Update,
+            Self::Map,
+            Self::Push,
+            Self::Extend,
+            Self::Clear,
+            Self::WithItem,
+            Self::GetItem,
+            Self::GetItemMut,
+            Self::InsertItem,
+            Self::RemoveItem,
+            Self::Enable,
+            Self::Disable,
+            Self::Toggle,
+            Self::Enabled,
+            Self::Disabled,
+            Self::Toggled,
+            Self::Cloned,
+            Self::New,
+            Self::Builder,
         ]
     }
 
@@ -42,6 +82,26 @@ impl Method {
             "without" => Ok(Self::Without),
             "take" => Ok(Self::Take),
             "into_field" => Ok(Self::IntoField),
+            "replace" => Ok(Self::Replace),
+            "update" => Ok(Self::Update),
+            "map" => Ok(Self::Map),
+            "push" => Ok(Self::Push),
+            "extend" => Ok(Self::Extend),
+            "clear" => Ok(Self::Clear),
+            "with_item" => Ok(Self::WithItem),
+            "get_item" => Ok(Self::GetItem),
+            "get_item_mut" => Ok(Self::GetItemMut),
+            "insert_item" => Ok(Self::InsertItem),
+            "remove_item" => Ok(Self::RemoveItem),
+            "enable" => Ok(Self::Enable),
+            "disable" => Ok(Self::Disable),
+            "toggle" => Ok(Self::Toggle),
+            "enabled" => Ok(Self::Enabled),
+            "disabled" => Ok(Self::Disabled),
+            "toggled" => Ok(Self::Toggled),
+            "cloned" => Ok(Self::Cloned),
+            "new" => Ok(Self::New),
+            "builder" => Ok(Self::Builder),
             _ => Err(Error::new(span, "unrecognized method")),
         }
     }
@@ -65,6 +125,46 @@ impl TryFrom<&Path> for Method {
             Ok(Self::Take)
         } else if path.is_ident("into_field") {
             Ok(Self::IntoField)
+        } else if path.is_ident("replace") {
+            Ok(Self::Replace)
+        } else if path.is_ident("update") {
+            Ok(Self::Update)
+        } else if path.is_ident("map") {
+            Ok(Self::Map)
+        } else if path.is_ident("push") {
+            Ok(Self::Push)
+        } else if path.is_ident("extend") {
+            Ok(Self::Extend)
+        } else if path.is_ident("clear") {
+            Ok(Self::Clear)
+        } else if path.is_ident("with_item") {
+            Ok(Self::WithItem)
+        } else if path.is_ident("get_item") {
+            Ok(Self::GetItem)
+        } else if path.is_ident("get_item_mut") {
+            Ok(Self::GetItemMut)
+        } else if path.is_ident("insert_item") {
+            Ok(Self::InsertItem)
+        } else if path.is_ident("remove_item") {
+            Ok(Self::RemoveItem)
+        } else if path.is_ident("enable") {
+            Ok(Self::Enable)
+        } else if path.is_ident("disable") {
+            Ok(Self::Disable)
+        } else if path.is_ident("toggle") {
+            Ok(Self::Toggle)
+        } else if path.is_ident("enabled") {
+            Ok(Self::Enabled)
+        } else if path.is_ident("disabled") {
+            Ok(Self::Disabled)
+        } else if path.is_ident("toggled") {
+            Ok(Self::Toggled)
+        } else if path.is_ident("cloned") {
+            Ok(Self::Cloned)
+        } else if path.is_ident("new") {
+            Ok(Self::New)
+        } else if path.is_ident("builder") {
+            Ok(Self::Builder)
         } else {
             Err(Error::new(path.span(), "unrecognized method"))
         }
@@ -72,7 +172,7 @@ impl TryFrom<&Path> for Method {
 }
 
 #[derive(Debug, Clone)]
-pub(crate) struct MethodSettings<T>([Option<T>; 7]);
+pub(crate) struct MethodSettings<T>([Option<T>; 27]);
 
 impl<T> Default for MethodSettings<T> {
     fn default() -> Self {
@@ -1,4 +1,6 @@
-use syn::{GenericArgument, Path, PathArguments, Type, TypePath, TypeReference};
+use syn::{
+    Expr, ExprCall, ExprPath, GenericArgument, Path, PathArguments, Type, TypePath, TypeReference,
+};
 
 pub(crate) fn extract_option_type(ty: &Type) -> Option<&Type> {
     let Type::Path(TypePath {
@@ -69,3 +71,17 @@ pub(crate) fn ref_inner_mut(ty: &mut Type) -> Option<(&mut Type, bool)> {
         _ => None,
     }
 }
+
+/// If `expr` is a `Some(inner)` call, as produced for `option_set_some`, returns `inner`.
+pub(crate) fn some_inner(expr: &Expr) -> Option<&Expr> {
+    let Expr::Call(ExprCall { func, args, .. }) = expr else {
+        return None;
+    };
+
+    match &**func {
+        Expr::Path(ExprPath { path, .. }) if path.is_ident("Some") && args.len() == 1 => {
+            args.first()
+        }
+        _ => None,
+    }
+}
@@ -0,0 +1,325 @@
+use proc_macro2::{Span, TokenStream, TokenTree};
+use quote::{ToTokens, format_ident, quote, quote_spanned};
+use std::borrow::Cow;
+use syn::{
+    Expr, GenericParam, Generics, Ident, Index, Member, Type, Visibility, parse_quote_spanned,
+    spanned::Spanned,
+};
+
+use crate::{
+    Field, ItemAttributes,
+    common_settings::{Vis, must_use_attr, passthrough_attrs},
+};
+
+/// The item-level `into_parts` and `from_parts` methods, which move every non-skipped field out of
+/// or into the struct at once. The parts are a tuple in field order, or a generated struct with
+/// public fields when `parts` is set.
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub(crate) struct Parts<'a> {
+    ident: &'a Ident,
+    generics: &'a Generics,
+    attributes: &'a ItemAttributes,
+    included: Vec<PartsField<'a>>,
+    /// Every field in declaration order, with the value `from_parts` gives it if it's skipped.
+    members: Vec<(&'a Member, Option<Expr>)>,
+    named: bool,
+}
+
+#[cfg_attr(feature = "debug", derive(Debug))]
+struct PartsField<'a> {
+    member: &'a Member,
+    ty: &'a Type,
+    doc: &'a [String],
+    span: Span,
+}
+
+impl<'a> Parts<'a> {
+    pub(crate) fn new(
+        ident: &'a Ident,
+        fields: &'a [Field],
+        attributes: &'a ItemAttributes,
+        generics: &'a Generics,
+    ) -> Option<Self> {
+        if !attributes.into_parts && !attributes.from_parts {
+            return None;
+        }
+
+        let mut included = Vec::new();
+        let mut members = Vec::new();
+        for field in fields {
+            if field.attributes.common_settings.skip {
+                let span = field.span;
+                let default = field
+                    .attributes
+                    .default
+                    .clone()
+                    .unwrap_or_else(|| parse_quote_spanned!(span => Default::default()));
+                members.push((&field.member, Some(default)));
+            } else {
+                included.push(PartsField {
+                    member: &field.member,
+                    ty: &field.ty,
+                    doc: &field.doc,
+                    span: field.span,
+                });
+                members.push((&field.member, None));
+            }
+        }
+
+        Some(Self {
+            ident,
+            generics,
+            attributes,
+            included,
+            members,
+            named: fields
+                .first()
+                .is_none_or(|field| matches!(field.member, Member::Named(_))),
+        })
+    }
+
+    fn parts_ident(&self) -> Option<Ident> {
+        if !self.attributes.parts_struct {
+            return None;
+        }
+        Some(
+            self.attributes
+                .parts_ident
+                .clone()
+                .unwrap_or_else(|| format_ident!("{}Parts", self.ident)),
+        )
+    }
+
+    fn vis(&self) -> Cow<'a, Visibility> {
+        self.attributes
+            .common_settings
+            .vis
+            .as_ref()
+            .unwrap_or(&Vis::Default)
+            .as_visibility()
+    }
+
+    /// The type returned by `into_parts` and accepted by `from_parts`.
+    fn parts_ty(&self) -> TokenStream {
+        if let Some(parts_ident) = self.parts_ident() {
+            let generics = self.parts_generics();
+            let (_, type_generics, _) = generics.split_for_impl();
+            quote!(#parts_ident #type_generics)
+        } else {
+            let tys = self.included.iter().map(|field| field.ty);
+            quote!((#(#tys,)*))
+        }
+    }
+
+    /// The parts struct's generics: the struct's, without the parameters that only skipped fields
+    /// use, since the parts struct would otherwise have unused parameters. Bounds that mention a
+    /// dropped parameter are dropped with it.
+    fn parts_generics(&self) -> Generics {
+        let mut used = Vec::new();
+        for field in &self.included {
+            collect_names(field.ty.to_token_stream(), &mut used);
+        }
+        let unused: Vec<String> = self
+            .generics
+            .params
+            .iter()
+            .map(param_name)
+            .filter(|name| !used.contains(name))
+            .collect();
+
+        let mut generics = self.generics.clone();
+        if unused.is_empty() {
+            return generics;
+        }
+        generics.params = generics
+            .params
+            .into_iter()
+            .filter(|param| !unused.contains(&param_name(param)))
+            .map(|mut param| {
+                match &mut param {
+                    GenericParam::Lifetime(param) => {
+                        param.bounds = std::mem::take(&mut param.bounds)
+                            .into_iter()
+                            .filter(|bound| !mentions(bound, &unused))
+                            .collect();
+                    }
+                    GenericParam::Type(param) => {
+                        param.bounds = std::mem::take(&mut param.bounds)
+                            .into_iter()
+                            .filter(|bound| !mentions(bound, &unused))
+                            .collect();
+                        if param
+                            .default
+                            .as_ref()
+                            .is_some_and(|(_, ty)| mentions(ty, &unused))
+                        {
+                            param.default = None;
+                        }
+                    }
+                    GenericParam::Const(param) => {
+                        if param
+                            .default
+                            .as_ref()
+                            .is_some_and(|(_, expr)| mentions(expr, &unused))
+                        {
+                            param.default = None;
+                        }
+                    }
+                }
+                param
+            })
+            .collect();
+        if let Some(where_clause) = &mut generics.where_clause {
+            where_clause.predicates = std::mem::take(&mut where_clause.predicates)
+                .into_iter()
+                .filter(|predicate| !mentions(predicate, &unused))
+                .collect();
+        }
+        generics
+    }
+
+    /// An expression of the parts type, with one value per included field.
+    fn parts_expr(&self) -> TokenStream {
+        let values = self.included.iter().map(|PartsField { member, span, .. }| {
+            let value = quote_spanned!(*span => self.#member);
+            match member {
+                Member::Named(ident) if self.parts_ident().is_some() => quote!(#ident: #value),
+                _ => value,
+            }
+        });
+        match self.parts_ident() {
+            Some(parts_ident) if self.named => quote!(#parts_ident { #(#values),* }),
+            Some(parts_ident) => quote!(#parts_ident(#(#values),*)),
+            None => quote!((#(#values,)*)),
+        }
+    }
+
+    /// Methods to add to the struct's impl block.
+    pub(crate) fn methods(&self) -> TokenStream {
+        let vis = self.vis();
+        let attrs = passthrough_attrs([&self.attributes.common_settings]);
+        let parts_ty = self.parts_ty();
+
+        let into_parts = self.attributes.into_parts.then(|| {
+            let parts = self.parts_expr();
+            let must_use = must_use_attr(attrs.iter().copied());
+            quote! {
+                /// Consumes self, returning its fields
+                #must_use
+                #(#attrs)*
+                #vis fn into_parts(self) -> #parts_ty {
+                    #parts
+                }
+            }
+        });
+
+        let from_parts = self.attributes.from_parts.then(|| {
+            let mut position = 0..;
+            let values = self.members.iter().map(|(member, default)| {
+                let value = if let Some(default) = default {
+                    quote!(#default)
+                } else {
+                    let index = position.next().unwrap_or_default();
+                    let part = match member {
+                        Member::Named(ident) if self.parts_ident().is_some() => quote!(#ident),
+                        _ => {
+                            let index = Index::from(index);
+                            quote!(#index)
+                        }
+                    };
+                    quote_spanned!(member.span() => parts.#part)
+                };
+                match member {
+                    Member::Named(ident) => quote!(#ident: #value),
+                    Member::Unnamed(_) => value,
+                }
+            });
+            let construct = if self.named {
+                quote!(Self { #(#values),* })
+            } else {
+                quote!(Self(#(#values),*))
+            };
+            quote! {
+                /// Constructs self from its fields
+                #(#attrs)*
+                #vis fn from_parts(parts: #parts_ty) -> Self {
+                    #construct
+                }
+            }
+        });
+
+        quote! {
+            #into_parts
+            #from_parts
+        }
+    }
+
+    /// The generated parts struct, if one was requested.
+    pub(crate) fn parts_struct(&self) -> Option<TokenStream> {
+        let parts_ident = self.parts_ident()?;
+        let ident = self.ident;
+        let vis = self.vis();
+        let generics = self.parts_generics();
+        let (_, _, where_clause) = generics.split_for_impl();
+        let params = &generics.params;
+        let methods = match (self.attributes.into_parts, self.attributes.from_parts) {
+            (true, true) => "`into_parts` and `from_parts`",
+            (true, false) => "`into_parts`",
+            _ => "`from_parts`",
+        };
+        let doc = format!("The fields of `{ident}`, as moved by {methods}");
+        let fields = self.included.iter().map(|field| {
+            let PartsField {
+                member, ty, doc, ..
+            } = field;
+            match member {
+                Member::Named(ident) => quote!(#(#[doc = #doc])* pub #ident: #ty),
+                Member::Unnamed(_) => quote!(#(#[doc = #doc])* pub #ty),
+            }
+        });
+        Some(if self.named {
+            quote! {
+                #[doc = #doc]
+                #vis struct #parts_ident<#params> #where_clause {
+                    #(#fields,)*
+                }
+            }
+        } else {
+            quote! {
+                #[doc = #doc]
+                #vis struct #parts_ident<#params>(#(#fields,)*) #where_clause;
+            }
+        })
+    }
+}
+
+/// Every identifier and lifetime (as `'name`) mentioned in `tokens`.
+fn collect_names(tokens: TokenStream, names: &mut Vec<String>) {
+    let mut lifetime = false;
+    for token in tokens {
+        let apostrophe = matches!(&token, TokenTree::Punct(punct) if punct.as_char() == '\'');
+        match token {
+            TokenTree::Group(group) => collect_names(group.stream(), names),
+            TokenTree::Ident(ident) if lifetime => names.push(format!("'{ident}")),
+            TokenTree::Ident(ident) => names.push(ident.to_string()),
+            TokenTree::Punct(_) | TokenTree::Literal(_) => {}
+        }
+        lifetime = apostrophe;
+    }
+}
+
+/// The name of a generic parameter, as [`collect_names`] would find it.
+fn param_name(param: &GenericParam) -> String {
+    match param {
+        GenericParam::Lifetime(param) => format!("'{}", param.lifetime.ident),
+        GenericParam::Type(param) => param.ident.to_string(),
+        GenericParam::Const(param) => param.ident.to_string(),
+    }
+}
+
+/// Whether `tokens` mention any of `names`.
+fn mentions(tokens: &impl ToTokens, names: &[String]) -> bool {
+    let mut found = Vec::new();
+    collect_names(tokens.to_token_stream(), &mut found);
+    found.iter().any(|name| names.contains(name))
+}
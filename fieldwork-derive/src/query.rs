@@ -2,14 +2,28 @@ use std::borrow::Cow;
 
 use crate::{
     CommonSettings, Deprecation, Field, FieldMethodAttributes, ItemAttributes,
-    ItemMethodAttributes, Method, Resolved,
-    copy_detection::{enable_copy_for_type, is_type},
+    ItemMethodAttributes, Method, Resolved, VariantAttributes,
+    collection_handling::{Collection, extract_collection_type, singular_ident},
+    common_settings::{passthrough_attrs, where_predicates},
+    copy_detection::{enable_clone_for_type, enable_copy_for_type, is_type},
     deref_handling::auto_deref,
-    option_handling::{extract_option_type, option_type_mut, ref_inner, ref_inner_mut, strip_ref},
+    option_handling::{
+        extract_option_type, option_type_mut, ref_inner, ref_inner_mut, some_inner, strip_ref,
+    },
+    variant_attributes::VariantMethodAttributes,
+};
+use Method::{
+    Builder, Clear, Cloned, Disable, Disabled, Enable, Enabled, Extend, Get, GetItem, GetItemMut,
+    GetMut, InsertItem, IntoField, Map, New, Push, RemoveItem, Replace, Set, Take, Toggle, Toggled,
+    Update, With, WithItem, Without,
+};
+use proc_macro2::{Span, TokenStream};
+use quote::{ToTokens, quote_spanned};
+use syn::{
+    Attribute, Error, Expr, ExprField, ExprMethodCall, ExprParen, ExprReference, GenericParam,
+    Generics, Ident, Member, Path, Token, Type, TypeArray, Visibility, WherePredicate,
+    ext::IdentExt, parse_quote_spanned,
 };
-use Method::{Get, GetMut, IntoField, Set, Take, With, Without};
-use proc_macro2::Span;
-use syn::{Attribute, Expr, Ident, Member, Type, TypeArray, Visibility, parse_quote_spanned};
 
 /// Scope of a deprecation rename: field-level applies the method prefix/template to the old
 /// name, while method-level treats the old name as a literal method ident.
@@ -41,6 +55,8 @@ pub(crate) struct Query<'a> {
     span: &'a Span,
     item_attributes: &'a ItemAttributes,
     item_method_attributes: Option<&'a ItemMethodAttributes>,
+    /// The item's generics, which generated method generics must not shadow.
+    generics: &'a Generics,
     /// Total variants in the enum (denominator for coverage). Always 1 for structs.
     total_variants: usize,
     /// When set, this Query represents the deprecated alternate emission, not the canonical.
@@ -60,6 +76,7 @@ impl<'a> Query<'a> {
         method: &'a Method,
         fields: &'a [Field],
         item_attributes: &'a ItemAttributes,
+        generics: &'a Generics,
         total_variants: usize,
     ) -> Self {
         // Prefer the substantively-annotated occurrence as representative so its configuration
@@ -84,6 +101,7 @@ impl<'a> Query<'a> {
             span,
             item_attributes,
             item_method_attributes,
+            generics,
             total_variants,
             alternate: None,
         }
@@ -119,9 +137,28 @@ impl<'a> Query<'a> {
         Some(alt)
     }
 
+    /// The attributes to attach to the method generated from this Query: those passed through
+    /// with `attr(...)` at every level, from the item down to the field method, and then any
+    /// `#[deprecated(...)]`.
+    pub(crate) fn attrs(&self) -> Vec<Attribute> {
+        let levels: Vec<_> = self.common_settings().collect();
+        let mut attrs: Vec<Attribute> = passthrough_attrs(levels.into_iter().rev())
+            .into_iter()
+            .cloned()
+            .collect();
+        attrs.extend(self.deprecation_attr());
+        attrs
+    }
+
+    /// The extra `where` bounds for the method generated from this Query, from every level.
+    pub(crate) fn where_predicates(&self) -> Vec<WherePredicate> {
+        let levels: Vec<_> = self.common_settings().collect();
+        where_predicates(levels.into_iter().rev())
+    }
+
     /// The `#[deprecated(...)]` attribute to attach to the method generated from this Query,
     /// if any. Returns `None` when no deprecation is in effect for this slot.
-    pub(crate) fn deprecation_attr(&self) -> Option<Attribute> {
+    fn deprecation_attr(&self) -> Option<Attribute> {
         if let Some(alt) = &self.alternate {
             // We're the alternate; always #[deprecated]. Default note points to the canonical.
             let mut canonical = self.clone();
@@ -158,9 +195,10 @@ impl<'a> Query<'a> {
         self.item_method_attributes
     }
 
-    /// Returns true when this query is for an enum (more than one total variant).
+    /// Returns true when this query is for an enum field. An enum with a single variant, such as
+    /// one whose other variants are `#[cfg]`'d out, still needs match arms.
     pub(crate) fn is_enum(&self) -> bool {
-        self.total_variants > 1
+        self.field.variant_ident.is_some()
     }
 
     /// Returns true when every variant contributes a field occurrence.
@@ -168,6 +206,64 @@ impl<'a> Query<'a> {
         self.fields.len() == self.total_variants
     }
 
+    /// The `#[variant(...)]` configuration of each variant this field appears in, starting with
+    /// the representative's. Empty for struct fields.
+    fn variant_attributes(&self) -> impl Iterator<Item = &'a VariantAttributes> {
+        std::iter::once(self.field)
+            .chain(self.fields)
+            .filter_map(|field| field.variant_attributes.as_ref())
+    }
+
+    /// The `#[variant(method(...))]` configuration for this method, in the same order as
+    /// [`Self::variant_attributes`].
+    fn variant_method_attributes(&self) -> impl Iterator<Item = &'a VariantMethodAttributes> {
+        let method = *self.method;
+        self.variant_attributes()
+            .filter_map(move |va| va.method_attributes.retrieve(method))
+            .map(|(_, vma)| vma)
+    }
+
+    /// A setting from the variant-method or variant level, which applies to every field of the
+    /// variant as if each were annotated with it.
+    fn variant_setting<T>(&self, fun: impl Fn(&'a CommonSettings) -> Option<T>) -> Option<T> {
+        self.variant_method_attributes()
+            .map(|vma| &vma.common_settings)
+            .chain(self.variant_attributes().map(|va| &va.common_settings))
+            .find_map(fun)
+    }
+
+    /// Returns true when every occurrence of the field has the same type. Enum fields whose types
+    /// differ across variants only get a unified `get`.
+    pub(crate) fn is_uniform_type(&self) -> bool {
+        self.fields.iter().all(|field| field.ty == self.field.ty)
+    }
+
+    /// Returns true when a `set`, `with`, or `into_field` method for a partial-coverage field is
+    /// generated anyway with a fallible signature, returning the value (or `self`) when the
+    /// variant doesn't have the field.
+    pub(crate) fn is_fallible(&self) -> bool {
+        matches!(self.method, Set | With | IntoField)
+            && self.is_enum()
+            && !self.is_full_coverage()
+            && self.common_setting(|x| x.fallible)
+    }
+
+    /// The field type a fallible setter hands back from variants without the field. That rejected
+    /// value is the setter's whole error, so a fallible setter can't also `validate`.
+    pub(crate) fn fallible_ty(&self) -> syn::Result<Option<&'a Type>> {
+        if !self.is_fallible() {
+            return Ok(None);
+        }
+        if let Some((validate, _)) = self.validation() {
+            return Err(Error::new_spanned(
+                validate,
+                "fallible setters return the rejected value as their error, so they can't also \
+                 validate it",
+            ));
+        }
+        Ok(Some(self.ty()))
+    }
+
     /// For enum resolvers: all field occurrences (each carries its `variant_ident`).
     /// Returns `None` for struct fields so enum resolvers can early-exit cleanly.
     pub(crate) fn enum_fields(&self) -> Option<&'a [Field]> {
@@ -179,6 +275,7 @@ impl<'a> Query<'a> {
             .field_method_attributes
             .and_then(|fma| fma.common_settings.get_copy)
             .or(self.field.attributes.common_settings.get_copy)
+            .or_else(|| self.variant_setting(|x| x.get_copy))
         {
             return field_copy;
         }
@@ -190,8 +287,36 @@ impl<'a> Query<'a> {
                     .is_some_and(|ty| enable_copy_for_type(ty, *self.method)))
     }
 
+    /// Whether a `get` or `cloned` method returns an owned clone. Like `copy`, an explicit
+    /// field-level setting applies to any type, while the item-level setting (and the `cloned`
+    /// method itself) applies only to `Arc` and `Rc` fields, or options of them.
+    pub(crate) fn is_get_clone(&self) -> bool {
+        if !matches!(self.method, Get | Cloned) {
+            return false;
+        }
+
+        let explicit_opt_in = self.method == &Cloned && self.field_method_attribute().is_some();
+        if let Some(field_clone) = self
+            .field_method_attributes
+            .and_then(|fma| fma.common_settings.get_clone)
+            .or(self.field.attributes.common_settings.get_clone)
+            .or_else(|| self.variant_setting(|x| x.get_clone))
+            .or(explicit_opt_in.then_some(true))
+        {
+            return field_clone;
+        }
+
+        let ty = self.ty();
+        (self.method == &Cloned || self.common_setting(|x| x.get_clone))
+            && (enable_clone_for_type(ty)
+                || extract_option_type(ty).is_some_and(enable_clone_for_type))
+    }
+
     pub(crate) fn chainable_set(&self) -> bool {
-        self.method == &Set && self.common_setting(|x| x.chainable_set)
+        matches!(
+            self.method,
+            Set | Update | Push | Extend | Clear | Enable | Disable | Toggle
+        ) && self.common_setting(|x| x.chainable_set)
     }
 
     pub(crate) fn vis(&self) -> Cow<'a, Visibility> {
@@ -202,6 +327,89 @@ impl<'a> Query<'a> {
         self.common_setting(|x| x.rename_predicates)
     }
 
+    /// For the flag methods on a `bool` field, the value to assign given an expression for the
+    /// current value, and whether the method consumes and returns `Self`.
+    pub(crate) fn flag_value(&self, current: &Expr) -> Option<(Expr, bool)> {
+        if !is_type(&self.field.ty, "bool") {
+            return None;
+        }
+        let span = self.span();
+        let value = match self.method {
+            Enable | Enabled => parse_quote_spanned!(span => true),
+            Disable | Disabled => parse_quote_spanned!(span => false),
+            Toggle | Toggled => parse_quote_spanned!(span => !#current),
+            _ => return None,
+        };
+        Some((value, matches!(self.method, Enabled | Disabled | Toggled)))
+    }
+
+    /// For `get` with `option_predicates` enabled, the ident and docs of a `has_` predicate that
+    /// reports whether the getter would return `Some`. Only emitted alongside the canonical getter.
+    pub(crate) fn option_predicate(&self) -> Option<(Ident, Option<String>)> {
+        if self.method != &Get
+            || self.alternate.is_some()
+            || !self.common_setting(|x| x.option_predicates)
+        {
+            return None;
+        }
+
+        let returns_option = extract_option_type(self.ty()).is_some()
+            || (self.is_enum() && !self.is_full_coverage());
+        if !returns_option {
+            return None;
+        }
+
+        let name = self.field_name()?.unraw();
+        let ident = Ident::new(&format!("has_{name}"), self.field.span);
+        // A partial-coverage enum field is only present in some variants, so its predicate is also
+        // about the current variant. The field's doc describes the field, so it's its own
+        // paragraph after the summary.
+        let summary = match (
+            self.is_enum() && !self.is_full_coverage(),
+            extract_option_type(self.ty()).is_some(),
+        ) {
+            (false, _) => format!("Returns `true` if `{name}` is `Some`"),
+            (true, false) => format!("Returns `true` if this variant has `{name}`"),
+            (true, true) => format!("Returns `true` if this variant has `{name}` and it is `Some`"),
+        };
+        let doc = (!self.field.doc.is_empty())
+            .then(|| format!("{summary}\n\n{}", self.field.doc.join("\n")));
+        Some((ident, doc))
+    }
+
+    /// For `get` with `const` enabled, `Some(const)` if `body` can be evaluated in a const context,
+    /// or an error at the field saying why it can't. A deprecated alternate that can't be const
+    /// is left as is, since the canonical getter reports the error.
+    pub(crate) fn constness(&self, body: &Expr) -> syn::Result<Option<Token![const]>> {
+        if self.method != &Get || !self.common_setting(|x| x.get_const) {
+            return Ok(None);
+        }
+
+        let reason = if let Some(as_type) = self.as_type() {
+            Some(format!(
+                "it coerces the field to `{}`",
+                as_type.to_token_stream()
+            ))
+        } else if self.is_enum() && !self.is_uniform_type() {
+            Some(String::from("it dereferences the field"))
+        } else {
+            non_const_reason(body)
+        };
+
+        match reason {
+            None => Ok(Some(Token![const](self.span()))),
+            Some(_) if self.alternate.is_some() => Ok(None),
+            Some(reason) => Err(Error::new(
+                self.field.span,
+                format!(
+                    "`{}` can't be a `const fn` because {reason}",
+                    self.fn_ident()
+                        .map_or_else(String::new, |ident| ident.to_string())
+                ),
+            )),
+        }
+    }
+
     pub(crate) fn field_name(&self) -> Option<&'a Ident> {
         self.field_method_attributes
             .and_then(|x| x.fn_ident.as_ref())
@@ -242,28 +450,121 @@ impl<'a> Query<'a> {
                 })?
         };
 
-        if let Some(template) = self
+        // Single-item methods are named for one item of the collection. `push`, `insert_item`,
+        // and `remove_item` fall back to the field name, but the others would collide with
+        // `with`, `get`, and `get_mut`, so they need a singular.
+        let ident = match self.method {
+            Push | InsertItem | RemoveItem => {
+                singular_ident(ident).map_or(Cow::Borrowed(ident), Cow::Owned)
+            }
+            WithItem | GetItem | GetItemMut => Cow::Owned(singular_ident(ident)?),
+            _ => Cow::Borrowed(ident),
+        };
+
+        let fn_ident = if let Some(template) = self
             .struct_method_attribute()
             .and_then(|x| x.template.as_ref())
         {
-            return Some(Cow::Owned(Ident::new(
+            Cow::Owned(Ident::new(
                 &template.as_str().replacen("{}", &ident.to_string(), 1),
                 self.field.span,
-            )));
+            ))
+        } else {
+            self.default_fn_ident(ident)
+        };
+
+        // Validated setters and fallible `into_field` methods carry a `try_` prefix.
+        if self.validation().is_some() || (self.method == &IntoField && self.is_fallible()) {
+            Some(Cow::Owned(Ident::new(
+                &format!("try_{fn_ident}"),
+                self.field.span,
+            )))
+        } else {
+            Some(fn_ident)
         }
+    }
 
-        Some(match self.method {
+    fn default_fn_ident(&self, ident: Cow<'a, Ident>) -> Cow<'a, Ident> {
+        match self.method {
             Get if self.rename_predicates() && is_type(&self.field.ty, "bool") => {
                 Cow::Owned(Ident::new(&format!("is_{ident}"), self.field.span))
             }
-            Get => Cow::Borrowed(ident),
+            Get | GetItem | New => ident,
             Set => Cow::Owned(Ident::new(&format!("set_{ident}"), self.field.span)),
-            With => Cow::Owned(Ident::new(&format!("with_{ident}"), self.field.span)),
-            GetMut => Cow::Owned(Ident::new(&format!("{ident}_mut"), self.field.span)),
+            With | WithItem | Builder => {
+                Cow::Owned(Ident::new(&format!("with_{ident}"), self.field.span))
+            }
+            GetMut | GetItemMut => Cow::Owned(Ident::new(&format!("{ident}_mut"), self.field.span)),
             Without => Cow::Owned(Ident::new(&format!("without_{ident}"), self.field.span)),
             Take => Cow::Owned(Ident::new(&format!("take_{ident}"), self.field.span)),
             IntoField => Cow::Owned(Ident::new(&format!("into_{ident}"), self.field.span)),
-        })
+            Replace => Cow::Owned(Ident::new(&format!("replace_{ident}"), self.field.span)),
+            Update => Cow::Owned(Ident::new(&format!("update_{ident}"), self.field.span)),
+            Map => Cow::Owned(Ident::new(&format!("map_{ident}"), self.field.span)),
+            Push => Cow::Owned(Ident::new(&format!("push_{ident}"), self.field.span)),
+            Extend => Cow::Owned(Ident::new(&format!("extend_{ident}"), self.field.span)),
+            Clear => Cow::Owned(Ident::new(&format!("clear_{ident}"), self.field.span)),
+            InsertItem => Cow::Owned(Ident::new(&format!("insert_{ident}"), self.field.span)),
+            RemoveItem => Cow::Owned(Ident::new(&format!("remove_{ident}"), self.field.span)),
+            Enable => Cow::Owned(Ident::new(&format!("enable_{ident}"), self.field.span)),
+            Disable => Cow::Owned(Ident::new(&format!("disable_{ident}"), self.field.span)),
+            Toggle => Cow::Owned(Ident::new(&format!("toggle_{ident}"), self.field.span)),
+            Enabled => Cow::Owned(Ident::new(&format!("{ident}_enabled"), self.field.span)),
+            Disabled => Cow::Owned(Ident::new(&format!("{ident}_disabled"), self.field.span)),
+            Toggled => Cow::Owned(Ident::new(&format!("{ident}_toggled"), self.field.span)),
+            Cloned => Cow::Owned(Ident::new(&format!("{ident}_cloned"), self.field.span)),
+        }
+    }
+
+    /// The `validate` function and `error` type for a `set`, `with`, or builder setter, if
+    /// configured. Field-method configuration beats field configuration.
+    pub(crate) fn validation(&self) -> Option<(&'a Path, &'a Type)> {
+        if !matches!(self.method, Set | With | Builder) {
+            return None;
+        }
+
+        self.field_method_attributes
+            .and_then(|fma| fma.validate.as_ref().zip(fma.error.as_ref()))
+            .or_else(|| {
+                let attributes = &self.field.attributes;
+                attributes.validate.as_ref().zip(attributes.error.as_ref())
+            })
+    }
+
+    /// For validated setters, bind the assigned value to `argument_ident` and check it before
+    /// assignment. Returns the validation statements and the expression to assign; unvalidated
+    /// setters get no statements and the original assigned value. With `option_set_some`, the
+    /// value is checked before it's wrapped in `Some`.
+    pub(crate) fn validate_assigned_value(
+        &self,
+        argument_ident: &Ident,
+        assigned_value: Expr,
+    ) -> (Option<TokenStream>, Expr) {
+        let Some((validate, _)) = self.validation() else {
+            return (None, assigned_value);
+        };
+        let span = self.span();
+        let (value, wrap_some) = match some_inner(&assigned_value) {
+            Some(inner) => (inner.clone(), true),
+            None => (assigned_value, false),
+        };
+        let binding = if matches!(&value, Expr::Path(path) if path.path.is_ident(argument_ident)) {
+            None
+        } else {
+            Some(quote_spanned!(span => let #argument_ident = #value;))
+        };
+
+        (
+            Some(quote_spanned! {span=>
+                #binding
+                #validate(&#argument_ident)?;
+            }),
+            if wrap_some {
+                parse_quote_spanned!(span => Some(#argument_ident))
+            } else {
+                parse_quote_spanned!(span => #argument_ident)
+            },
+        )
     }
 
     pub(crate) fn member(&self) -> &'a Member {
@@ -282,6 +583,13 @@ impl<'a> Query<'a> {
             return Some(Cow::Borrowed(argument_ident));
         }
 
+        // Single-element collection methods take one item, named in the singular when possible.
+        if matches!(self.method, Push | WithItem)
+            && let Some(singular) = self.argument_base().and_then(singular_ident)
+        {
+            return Some(Cow::Owned(singular));
+        }
+
         // For a field-level deprecated alternate, default the argument name to the old field
         // base so the signature reads naturally (e.g. `set_name(name: String)`).
         if let Some(alt) = &self.alternate
@@ -308,16 +616,170 @@ impl<'a> Query<'a> {
         None
     }
 
+    /// The name that a default argument ident is derived from, ignoring explicit `argument`s.
+    fn argument_base(&self) -> Option<&'a Ident> {
+        if let Some(alt) = &self.alternate
+            && matches!(alt.scope, AlternateScope::Field)
+        {
+            return Some(alt.was);
+        }
+
+        self.field
+            .attributes
+            .fn_ident
+            .as_ref()
+            .or(match &self.field.member {
+                Member::Named(ident) => Some(ident),
+                Member::Unnamed(_) => None,
+            })
+    }
+
+    /// The recognized collection type of this field, its element (or key) type, and for maps its
+    /// value type.
+    pub(crate) fn collection(&self) -> Option<(Collection, &'a Type, Option<&'a Type>)> {
+        extract_collection_type(self.ty())
+    }
+
+    /// For `push`, `extend`, `with_item`, and `insert_item`: the method parameters and the
+    /// arguments handed to the collection, accounting for `into`. Single map entries are passed as
+    /// a `key` and a `value` rather than as one argument.
+    pub(crate) fn collection_parameters_and_arguments(
+        &self,
+        argument_ident: &Ident,
+    ) -> Option<(TokenStream, TokenStream)> {
+        let (_, element_ty, value_ty) = self.collection()?;
+        let span = self.span();
+        let into = self.common_setting(|x| x.into);
+        Some(match (self.method, value_ty, into) {
+            (Extend, None, true) => (
+                quote_spanned!(span => #argument_ident: impl IntoIterator<Item = impl Into<#element_ty>>),
+                quote_spanned!(span => #argument_ident.into_iter().map(Into::into)),
+            ),
+            (Extend, None, false) => (
+                quote_spanned!(span => #argument_ident: impl IntoIterator<Item = #element_ty>),
+                quote_spanned!(span => #argument_ident),
+            ),
+            (Extend, Some(value_ty), true) => (
+                quote_spanned!(span =>
+                    #argument_ident: impl IntoIterator<Item = (impl Into<#element_ty>, impl Into<#value_ty>)>
+                ),
+                quote_spanned!(span =>
+                    #argument_ident.into_iter().map(|(key, value)| (key.into(), value.into()))
+                ),
+            ),
+            (Extend, Some(value_ty), false) => (
+                quote_spanned!(span => #argument_ident: impl IntoIterator<Item = (#element_ty, #value_ty)>),
+                quote_spanned!(span => #argument_ident),
+            ),
+            (_, None, true) => (
+                quote_spanned!(span => #argument_ident: impl Into<#element_ty>),
+                quote_spanned!(span => #argument_ident.into()),
+            ),
+            (_, None, false) => (
+                quote_spanned!(span => #argument_ident: #element_ty),
+                quote_spanned!(span => #argument_ident),
+            ),
+            (_, Some(value_ty), true) => (
+                quote_spanned!(span => key: impl Into<#element_ty>, value: impl Into<#value_ty>),
+                quote_spanned!(span => key.into(), value.into()),
+            ),
+            (_, Some(value_ty), false) => (
+                quote_spanned!(span => key: #element_ty, value: #value_ty),
+                quote_spanned!(span => key, value),
+            ),
+        })
+    }
+
+    /// For keyed map lookups: the map's value type, a method generic `Q` for a borrowed form of
+    /// the key, and the `where` predicates that let it be used, as `HashMap::get` and
+    /// `BTreeMap::get` allow. `Q` gets trailing underscores until it doesn't shadow one of the
+    /// item's own generics.
+    pub(crate) fn map_value_ty_and_key_bounds(&self) -> Option<(&'a Type, Ident, TokenStream)> {
+        let (collection, key_ty, value_ty) = self.collection()?;
+        let span = self.span();
+        let key_bound = if collection == Collection::HashMap {
+            quote_spanned!(span => std::hash::Hash + Eq)
+        } else {
+            quote_spanned!(span => Ord)
+        };
+        let mut name = String::from("Q");
+        while self.generics.params.iter().any(|param| match param {
+            GenericParam::Type(param) => param.ident == name,
+            GenericParam::Const(param) => param.ident == name,
+            GenericParam::Lifetime(_) => false,
+        }) {
+            name.push('_');
+        }
+        let key_generic = Ident::new(&name, span);
+        Some((
+            value_ty?,
+            key_generic.clone(),
+            quote_spanned!(span => #key_ty: std::borrow::Borrow<#key_generic>, #key_generic: #key_bound + ?Sized),
+        ))
+    }
+
     pub(crate) fn doc_template(&self, is_get_copy: bool) -> &str {
         match self.method {
+            Get if self.is_get_clone() => "Returns a clone of {}",
             Get if is_get_copy => "Returns a copy of {}",
             Get => "Borrows {}",
+            Set if self.validation().is_some() && self.chainable_set() => {
+                "Sets {} after validating it, returning `&mut Self` for chaining"
+            }
+            Set if self.validation().is_some() => "Sets {} after validating it",
             Set if self.chainable_set() => "Sets {}, returning `&mut Self` for chaining",
             Set => "Sets {}",
+            With if self.validation().is_some() => {
+                "Owned chainable setter for {} after validating it, returning `Self`"
+            }
             With | Without => "Owned chainable setter for {}, returning `Self`",
             Take => "Takes {}, leaving a None in its place",
             GetMut => "Mutably borrow {}",
             IntoField => "Consumes self, returning {}",
+            Replace => "Replaces {}, returning the previous value",
+            Update if self.chainable_set() => {
+                "Updates {} in place, returning `&mut Self` for chaining"
+            }
+            Update => "Updates {} in place",
+            Map => "Owned chainable transformation of {}, returning `Self`",
+            Push if self.chainable_set() => {
+                "Adds an item to {}, returning `&mut Self` for chaining"
+            }
+            Push => "Adds an item to {}",
+            Extend if self.chainable_set() => {
+                "Extends {} with the contents of an iterator, returning `&mut Self` for chaining"
+            }
+            Extend => "Extends {} with the contents of an iterator",
+            Clear if self.chainable_set() => {
+                "Removes all items from {}, returning `&mut Self` for chaining"
+            }
+            Clear => "Removes all items from {}",
+            WithItem => "Owned chainable setter that adds an item to {}, returning `Self`",
+            GetItem => "Borrows the value for a key in {}",
+            GetItemMut => "Mutably borrows the value for a key in {}",
+            InsertItem => {
+                "Inserts a key and value into {}, returning the previous value for the key"
+            }
+            RemoveItem => "Removes a key from {}, returning its value",
+            Enable if self.chainable_set() => {
+                "Sets {} to `true`, returning `&mut Self` for chaining"
+            }
+            Enable => "Sets {} to `true`",
+            Disable if self.chainable_set() => {
+                "Sets {} to `false`, returning `&mut Self` for chaining"
+            }
+            Disable => "Sets {} to `false`",
+            Toggle if self.chainable_set() => "Toggles {}, returning `&mut Self` for chaining",
+            Toggle => "Toggles {}",
+            Enabled => "Owned chainable setter that sets {} to `true`, returning `Self`",
+            Disabled => "Owned chainable setter that sets {} to `false`, returning `Self`",
+            Toggled => "Owned chainable setter that toggles {}, returning `Self`",
+            Cloned => "Returns a clone of {}",
+            New => "Constructs a new {}",
+            Builder if self.validation().is_some() => {
+                "Sets {} after validating it, returning the builder for chaining"
+            }
+            Builder => "Sets {}, returning the builder for chaining",
         }
     }
 
@@ -353,9 +815,15 @@ impl<'a> Query<'a> {
             ..
         } = self.item_attributes;
 
-        // Veto: any field occurrence is globally skipped or skips this method specifically.
+        // Veto: any field occurrence, or the variant it belongs to, is globally skipped or skips
+        // this method specifically.
         for field in self.fields {
-            if field.attributes.common_settings.skip {
+            if field.attributes.common_settings.skip
+                || field
+                    .variant_attributes
+                    .as_ref()
+                    .is_some_and(|va| va.skips(method))
+            {
                 return false;
             }
             if let Some((_, fma)) = field.attributes.method_attributes.retrieve(method) {
@@ -371,18 +839,32 @@ impl<'a> Query<'a> {
                 .method_attributes
                 .retrieve(method)
                 .is_some_and(|(_, fma)| !fma.common_settings.skip)
+                || f.variant_attributes
+                    .as_ref()
+                    .is_some_and(|va| va.opts_into(method))
         });
 
-        // Whether any occurrence is decorated (has any fieldwork annotation).
-        let any_decorated = self.fields.iter().any(|f| f.attributes.decorated);
+        // Whether any occurrence is decorated (has any fieldwork annotation, or belongs to a
+        // variant with method-specific configuration).
+        let any_decorated = self.fields.iter().any(|f| {
+            f.attributes.decorated
+                || f.variant_attributes
+                    .as_ref()
+                    .is_some_and(|va| !va.method_attributes.is_empty())
+        });
 
-        let field_opt_in = self.field.attributes.common_settings.opt_in;
+        let field_opt_in = self.field.attributes.common_settings.opt_in
+            || self
+                .variant_attributes()
+                .any(|va| va.common_settings.opt_in);
 
         if *opt_in || field_opt_in {
-            let all_method_attrs_empty = self
-                .fields
-                .iter()
-                .all(|f| f.attributes.method_attributes.is_empty());
+            let all_method_attrs_empty = self.fields.iter().all(|f| {
+                f.attributes.method_attributes.is_empty()
+                    && f.variant_attributes
+                        .as_ref()
+                        .is_none_or(|va| va.method_attributes.is_empty())
+            });
             any_decorated
                 && ((all_method_attrs_empty && include.contains(method)) || any_method_opt_in)
         } else if !include.contains(method) {
@@ -393,7 +875,7 @@ impl<'a> Query<'a> {
     }
 
     pub(crate) fn auto_deref(&self, ty: &'a Type) -> Option<(Cow<'a, Type>, usize)> {
-        if self.common_setting(|x| x.auto_deref) {
+        if self.borrow_setting(|x| x.auto_deref) {
             auto_deref(ty, *self.method, self.span()).map(|(ty, count)| (Cow::Owned(ty), count))
         } else {
             None
@@ -404,6 +886,11 @@ impl<'a> Query<'a> {
         self.field_method_attribute()
             .and_then(|x| x.deref.as_ref())
             .or(self.field.attributes.deref.as_ref())
+            .or_else(|| {
+                self.variant_method_attributes()
+                    .find_map(|vma| vma.deref.as_ref())
+                    .or_else(|| self.variant_attributes().find_map(|va| va.deref.as_ref()))
+            })
             .map(|specified| strip_ref(extract_option_type(specified).unwrap_or(specified)))
     }
 
@@ -427,23 +914,34 @@ impl<'a> Query<'a> {
             .unwrap_or_else(|| fun(CommonSettings::DEFAULTS).unwrap())
     }
 
+    /// `deref` and `option_borrow_inner` are off by default for `update`, whose closure gets
+    /// `&mut` the field's own type unless they're configured.
+    fn borrow_setting(&self, fun: impl Fn(&'a CommonSettings) -> Option<bool>) -> bool {
+        self.common_setting_without_default(&fun)
+            .unwrap_or_else(|| *self.method != Update && fun(CommonSettings::DEFAULTS).unwrap())
+    }
+
     pub(crate) fn common_setting_without_default<T: 'a>(
         &self,
         fun: impl Fn(&'a CommonSettings) -> Option<T>,
     ) -> Option<T> {
-        [
-            self.field_method_attributes.map(|x| &x.common_settings),
-            Some(&self.field.attributes.common_settings),
-            self.item_method_attributes.map(|x| &x.common_settings),
-            Some(&self.item_attributes.common_settings),
-        ]
-        .into_iter()
-        .flatten()
-        .find_map(fun)
+        self.common_settings().find_map(fun)
+    }
+
+    /// Every level of configuration for this method, from the field method to the item.
+    fn common_settings(&self) -> impl Iterator<Item = &'a CommonSettings> {
+        self.field_method_attributes
+            .map(|x| &x.common_settings)
+            .into_iter()
+            .chain(Some(&self.field.attributes.common_settings))
+            .chain(self.variant_method_attributes().map(|x| &x.common_settings))
+            .chain(self.variant_attributes().map(|x| &x.common_settings))
+            .chain(self.item_method_attributes.map(|x| &x.common_settings))
+            .chain(Some(&self.item_attributes.common_settings))
     }
 
     pub(crate) fn borrow_inner(&self, ty: &'a Type) -> Option<&'a Type> {
-        if self.common_setting(|x| x.option_borrow_inner) {
+        if self.borrow_setting(|x| x.option_borrow_inner) {
             extract_option_type(ty)
         } else {
             None
@@ -511,14 +1009,64 @@ impl<'a> Query<'a> {
         self.apply_get_transforms(parse_quote_spanned!(span => self.#member))
     }
 
+    /// The explicit return type of a getter, which the field is coerced to.
+    fn as_type(&self) -> Option<&'a Type> {
+        self.field_method_attributes
+            .and_then(|fma| fma.as_type.as_ref())
+            .filter(|_| self.method == &Get)
+    }
+
+    /// The arm expression and return type of a getter for a field whose types differ across
+    /// variants. Each arm relies on coercion to the `as` type, or to a reference to the `deref`
+    /// type (through `as_deref` when every type is an `Option`).
+    pub(crate) fn apply_unified_get_transforms(&self, binding: &Ident) -> Option<(Expr, Type)> {
+        let span = self.span();
+        if let Some(as_type) = self.as_type() {
+            return Some((parse_quote_spanned!(span => #binding), as_type.clone()));
+        }
+
+        let deref_type = self.explicit_deref_type()?;
+        let options = self
+            .fields
+            .iter()
+            .filter(|field| extract_option_type(&field.ty).is_some())
+            .count();
+        if options == 0 {
+            Some((
+                parse_quote_spanned!(span => #binding),
+                parse_quote_spanned!(span => &#deref_type),
+            ))
+        } else if options == self.fields.len() {
+            Some((
+                parse_quote_spanned!(span => #binding.as_deref()),
+                parse_quote_spanned!(span => Option<&#deref_type>),
+            ))
+        } else {
+            None
+        }
+    }
+
     /// Apply immutable borrow/copy transforms (deref, option unwrap, copy) to an arbitrary base
     /// expression. Used by struct code via [`Self::get_access_expr_type_and_copy`] and directly
     /// by enum code.
     pub(crate) fn apply_get_transforms(&self, base_expr: Expr) -> (Expr, Type, bool) {
         let span = self.span();
+        if let Some(as_type) = self.as_type() {
+            return (binding_or_ref(base_expr, span), as_type.clone(), false);
+        }
+
         let mut access_expr: Expr = base_expr.clone();
         let mut current_type: Type = self.ty().clone();
 
+        if self.is_get_clone() {
+            let receiver = as_method_receiver(access_expr);
+            return (
+                parse_quote_spanned!(span => #receiver.clone()),
+                current_type,
+                false,
+            );
+        }
+
         if let Some(result) = self.check_copy(&access_expr, &current_type) {
             return result;
         }
@@ -595,6 +1143,7 @@ impl<'a> Query<'a> {
                 &Without,
                 self.fields,
                 self.item_attributes,
+                self.generics,
                 self.total_variants,
             )
             .enabled()
@@ -647,7 +1196,7 @@ impl<'a> Query<'a> {
         access_expr: &mut Expr,
         current_type: &mut Type,
     ) {
-        if !self.common_setting(|x| x.auto_deref) {
+        if !self.borrow_setting(|x| x.auto_deref) {
             return;
         }
 
@@ -670,6 +1219,36 @@ impl<'a> Query<'a> {
     }
 }
 
+/// Why a getter body can't be evaluated in a const context, if it can't. Field access, borrows,
+/// reborrows of match bindings, and `Option::as_ref` are const; dereferencing anything else goes
+/// through `Deref`, which isn't.
+fn non_const_reason(expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::Path(_) => None,
+        Expr::Field(ExprField { base, .. }) => non_const_reason(base),
+        Expr::Reference(ExprReference { expr, .. }) | Expr::Paren(ExprParen { expr, .. }) => {
+            non_const_reason(expr)
+        }
+        Expr::Unary(syn::ExprUnary {
+            op: syn::UnOp::Deref(_),
+            expr,
+            ..
+        }) if matches!(**expr, Expr::Path(_)) => None,
+        Expr::MethodCall(ExprMethodCall {
+            receiver,
+            method,
+            args,
+            ..
+        }) if method == "as_ref" && args.is_empty() => non_const_reason(receiver),
+        Expr::MethodCall(ExprMethodCall { method, .. }) if method == "clone" => {
+            Some(String::from("it calls `clone`"))
+        }
+        _ => Some(String::from(
+            "it dereferences the field; `deref = false` borrows it as is",
+        )),
+    }
+}
+
 /// Strip one level of deref from `expr` when it will be used as a method-call or index receiver.
 /// If `expr` is `*inner`, return `inner` directly — the method call's auto-ref makes the deref
 /// redundant (e.g. enum binding `token: &Option<String>` → `token.as_deref()` rather than
@@ -0,0 +1,84 @@
+use crate::{Query, arm_pattern};
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, quote_spanned};
+use std::borrow::Cow;
+use syn::{Attribute, Ident, Visibility};
+
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub(crate) struct Clear<'a> {
+    doc: Option<Cow<'a, str>>,
+    fn_ident: Cow<'a, Ident>,
+    span: Span,
+    vis: Cow<'a, Visibility>,
+    chainable: bool,
+    field_binding: &'a Ident,
+    /// One pattern per variant (full coverage only).
+    patterns: Vec<TokenStream>,
+    attrs: Vec<Attribute>,
+}
+
+impl<'a> Clear<'a> {
+    pub(crate) fn build(&self) -> TokenStream {
+        let Self {
+            doc,
+            fn_ident,
+            span,
+            vis,
+            chainable,
+            field_binding,
+            patterns,
+            attrs,
+        } = self;
+        let doc = doc.as_deref().map(|d| quote_spanned!(*span => #[doc = #d]));
+        let match_body = quote! { #(#patterns)|* => #field_binding.clear(), };
+        if *chainable {
+            quote_spanned! {*span=>
+                #doc
+                #(#attrs)*
+                #vis fn #fn_ident(&mut self) -> &mut Self {
+                    match self { #match_body }
+                    self
+                }
+            }
+        } else {
+            quote_spanned! {*span=>
+                #doc
+                #(#attrs)*
+                #vis fn #fn_ident(&mut self) {
+                    match self { #match_body }
+                }
+            }
+        }
+    }
+
+    pub(crate) fn from_query(query: &Query<'a>) -> Option<Self> {
+        let fields = query.enum_fields()?;
+        if !query.is_full_coverage() {
+            return None;
+        }
+        query.collection()?;
+        let span = query.span();
+        let fn_ident = query.fn_ident()?;
+        let vis = query.vis();
+        let doc = query.docs(false);
+        let chainable = query.chainable_set();
+        let attrs = query.attrs();
+
+        let field_binding = fields.first()?.binding();
+        let patterns = fields
+            .iter()
+            .map(|field| arm_pattern(field, None))
+            .collect();
+
+        Some(Self {
+            doc,
+            fn_ident,
+            span,
+            vis,
+            chainable,
+            field_binding,
+            patterns,
+            attrs,
+        })
+    }
+}
@@ -0,0 +1,98 @@
+use crate::{Query, arm_pattern};
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, quote_spanned};
+use std::borrow::Cow;
+use syn::{Attribute, Ident, Visibility};
+
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub(crate) struct Extend<'a> {
+    doc: Option<Cow<'a, str>>,
+    fn_ident: Cow<'a, Ident>,
+    span: Span,
+    vis: Cow<'a, Visibility>,
+    parameters: TokenStream,
+    arguments: TokenStream,
+    chainable: bool,
+    /// Binding ident used in arm patterns and body, suffixed with `_binding` when the field
+    /// name would otherwise shadow the argument ident.
+    field_binding: Ident,
+    /// One pattern per variant (full coverage only).
+    patterns: Vec<TokenStream>,
+    attrs: Vec<Attribute>,
+}
+
+impl<'a> Extend<'a> {
+    pub(crate) fn build(&self) -> TokenStream {
+        let Self {
+            doc,
+            fn_ident,
+            span,
+            vis,
+            parameters,
+            arguments,
+            chainable,
+            field_binding,
+            patterns,
+            attrs,
+        } = self;
+        let doc = doc.as_deref().map(|d| quote_spanned!(*span => #[doc = #d]));
+        let match_body = quote! { #(#patterns)|* => { #field_binding.extend(#arguments); } };
+        if *chainable {
+            quote_spanned! {*span=>
+                #doc
+                #(#attrs)*
+                #vis fn #fn_ident(&mut self, #parameters) -> &mut Self {
+                    match self { #match_body }
+                    self
+                }
+            }
+        } else {
+            quote_spanned! {*span=>
+                #doc
+                #(#attrs)*
+                #vis fn #fn_ident(&mut self, #parameters) {
+                    match self { #match_body }
+                }
+            }
+        }
+    }
+
+    pub(crate) fn from_query(query: &Query<'a>) -> Option<Self> {
+        let fields = query.enum_fields()?;
+        if !query.is_full_coverage() {
+            return None;
+        }
+        let span = query.span();
+        let fn_ident = query.fn_ident()?;
+        let vis = query.vis();
+        let doc = query.docs(false);
+        let argument_ident = query.argument_ident()?;
+        let (parameters, arguments) = query.collection_parameters_and_arguments(&argument_ident)?;
+        let chainable = query.chainable_set();
+        let attrs = query.attrs();
+
+        let arm_binding = fields.first()?.binding();
+        let field_binding: Ident = if arm_binding == &*argument_ident {
+            Ident::new(&format!("{arm_binding}_binding"), span)
+        } else {
+            arm_binding.clone()
+        };
+        let patterns = fields
+            .iter()
+            .map(|field| arm_pattern(field, Some(&field_binding)))
+            .collect();
+
+        Some(Self {
+            doc,
+            fn_ident,
+            span,
+            vis,
+            parameters,
+            arguments,
+            chainable,
+            field_binding,
+            patterns,
+            attrs,
+        })
+    }
+}
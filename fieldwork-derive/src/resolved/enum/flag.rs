@@ -0,0 +1,111 @@
+use crate::{Query, arm_pattern, common_settings::must_use_attr};
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, quote_spanned};
+use std::borrow::Cow;
+use syn::{Attribute, Expr, Ident, Visibility, parse_quote_spanned};
+
+/// `enable`, `disable`, and `toggle` (or their owned `enabled`, `disabled`, and `toggled`
+/// counterparts) for a `bool` field. Variants without the field are left unchanged.
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub(crate) struct Flag<'a> {
+    doc: Option<Cow<'a, str>>,
+    fn_ident: Cow<'a, Ident>,
+    span: Span,
+    vis: Cow<'a, Visibility>,
+    owned: bool,
+    chainable: bool,
+    value: Expr,
+    field_binding: &'a Ident,
+    patterns: Vec<TokenStream>,
+    full_coverage: bool,
+    attrs: Vec<Attribute>,
+}
+
+impl<'a> Flag<'a> {
+    pub(crate) fn build(&self) -> TokenStream {
+        let Self {
+            doc,
+            fn_ident,
+            span,
+            vis,
+            owned,
+            chainable,
+            value,
+            field_binding,
+            patterns,
+            full_coverage,
+            attrs,
+        } = self;
+        let doc = doc.as_deref().map(|d| quote_spanned!(*span => #[doc = #d]));
+        let must_use = must_use_attr(attrs);
+        let fallthrough = if *full_coverage {
+            quote! {}
+        } else {
+            quote! { _ => {} }
+        };
+        let match_body = quote! {
+            #(#patterns)|* => { *#field_binding = #value; }
+            #fallthrough
+        };
+
+        if *owned {
+            quote_spanned! {*span=>
+                #doc
+                #(#attrs)*
+                #must_use
+                #vis fn #fn_ident(mut self) -> Self {
+                    match &mut self { #match_body }
+                    self
+                }
+            }
+        } else if *chainable {
+            quote_spanned! {*span=>
+                #doc
+                #(#attrs)*
+                #vis fn #fn_ident(&mut self) -> &mut Self {
+                    match self { #match_body }
+                    self
+                }
+            }
+        } else {
+            quote_spanned! {*span=>
+                #doc
+                #(#attrs)*
+                #vis fn #fn_ident(&mut self) {
+                    match self { #match_body }
+                }
+            }
+        }
+    }
+
+    pub(crate) fn from_query(query: &Query<'a>) -> Option<Self> {
+        let fields = query.enum_fields()?;
+        let span = query.span();
+        let field_binding = fields.first()?.binding();
+        let (value, owned) = query.flag_value(&parse_quote_spanned!(span => *#field_binding))?;
+        let fn_ident = query.fn_ident()?;
+        let vis = query.vis();
+        let doc = query.docs(false);
+        let chainable = query.chainable_set();
+        let attrs = query.attrs();
+
+        let patterns = fields
+            .iter()
+            .map(|field| arm_pattern(field, None))
+            .collect();
+
+        Some(Self {
+            doc,
+            fn_ident,
+            span,
+            vis,
+            owned,
+            chainable,
+            value,
+            field_binding,
+            patterns,
+            full_coverage: query.is_full_coverage(),
+            attrs,
+        })
+    }
+}
@@ -1,8 +1,8 @@
-use crate::{Query, arm_pattern, option_handling::extract_option_type};
+use crate::{Method, Query, arm_pattern, option_handling::extract_option_type, variant_pattern};
 use proc_macro2::{Span, TokenStream};
 use quote::{quote, quote_spanned};
 use std::borrow::Cow;
-use syn::{Attribute, Ident, Type, Visibility, parse_quote_spanned};
+use syn::{Attribute, Ident, Token, Type, Visibility, parse_quote_spanned};
 
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub(crate) struct Get<'a> {
@@ -16,7 +16,17 @@ pub(crate) struct Get<'a> {
     /// Arm body expression, already wrapped in `Some(...)` for partial coverage if needed.
     arm_expr: TokenStream,
     full_coverage: bool,
-    deprecation_attr: Option<Attribute>,
+    /// The field's type differs across variants, so each variant needs its own arm rather than
+    /// sharing an or-pattern.
+    separate_arms: bool,
+    attrs: Vec<Attribute>,
+    /// `const` for `get(const)`, or why the getter can't be const.
+    constness: syn::Result<Option<Token![const]>>,
+    /// `has_` predicate ident and docs, when `option_predicates` applies.
+    option_predicate: Option<(Ident, Option<String>)>,
+    /// Body of the predicate: checks the `Option` in variants that have the field, and is `false`
+    /// for variants that lack it.
+    predicate_body: TokenStream,
 }
 
 impl<'a> Get<'a> {
@@ -30,35 +40,72 @@ impl<'a> Get<'a> {
             patterns,
             arm_expr,
             full_coverage,
-            deprecation_attr,
+            separate_arms,
+            attrs,
+            constness,
+            option_predicate,
+            predicate_body,
         } = self;
+        let (constness, error) = match constness {
+            Ok(constness) => (constness.as_ref(), None),
+            Err(error) => (None, Some(error.to_compile_error())),
+        };
         let doc = doc.as_deref().map(|d| quote_spanned!(*span => #[doc = #d]));
-        let match_body = if *full_coverage {
+        let match_body = if *full_coverage && *separate_arms {
+            quote! { #(#patterns => #arm_expr,)* }
+        } else if *full_coverage {
             quote! { #(#patterns)|* => #arm_expr }
         } else {
             quote! { #(#patterns => #arm_expr,)* _ => None }
         };
+        let option_predicate = option_predicate
+            .as_ref()
+            .map(|(predicate_ident, predicate_doc)| {
+                let predicate_doc = predicate_doc
+                    .as_deref()
+                    .map(|d| quote_spanned!(*span => #[doc = #d]));
+                quote_spanned! {*span=>
+                    #predicate_doc
+                    #(#attrs)*
+                    #vis #constness fn #predicate_ident(&self) -> bool {
+                        #predicate_body
+                    }
+                }
+            });
         quote_spanned! {*span=>
+            #error
             #doc
-            #deprecation_attr
-            #vis fn #fn_ident(&self) -> #return_ty {
+            #(#attrs)*
+            #vis #constness fn #fn_ident(&self) -> #return_ty {
                 match self { #match_body }
             }
+            #option_predicate
         }
     }
 
     pub(crate) fn from_query(query: &Query<'a>) -> Option<Self> {
+        // `cloned` shares the getter implementation, but only exists where cloning applies.
+        if query.method() == Method::Cloned && !query.is_get_clone() {
+            return None;
+        }
         let fields = query.enum_fields()?;
         let span = query.span();
         let fn_ident = query.fn_ident()?;
         let vis = query.vis();
         let doc = query.docs(false);
-        let deprecation_attr = query.deprecation_attr();
+        let attrs = query.attrs();
 
         let first_binding = fields.first()?.binding();
-        let base = parse_quote_spanned!(span => *#first_binding);
-        let (arm_expr_typed, return_ty, _is_copy) = query.apply_get_transforms(base);
+        let separate_arms = !query.is_uniform_type();
+        let (arm_expr_typed, return_ty) = if separate_arms {
+            query.apply_unified_get_transforms(first_binding)?
+        } else {
+            let base = parse_quote_spanned!(span => *#first_binding);
+            let (arm_expr_typed, return_ty, _is_copy) = query.apply_get_transforms(base);
+            (arm_expr_typed, return_ty)
+        };
 
+        let constness = query.constness(&arm_expr_typed);
         let full_coverage = query.is_full_coverage();
         let (return_ty, arm_expr) = if !full_coverage && extract_option_type(query.ty()).is_none() {
             (
@@ -69,11 +116,24 @@ impl<'a> Get<'a> {
             (return_ty, quote! { #arm_expr_typed })
         };
 
-        let patterns = fields
+        let patterns: Vec<_> = fields
             .iter()
             .map(|field| arm_pattern(field, None))
             .collect();
 
+        let option_predicate = query.option_predicate();
+        let predicate_body = if extract_option_type(query.ty()).is_none() {
+            let variant_patterns = fields.iter().map(variant_pattern);
+            quote! { matches!(self, #(#variant_patterns)|*) }
+        } else if separate_arms {
+            let fallback = (!full_coverage).then(|| quote!(_ => false,));
+            quote! { match self { #(#patterns => #first_binding.is_some(),)* #fallback } }
+        } else if full_coverage {
+            quote! { match self { #(#patterns)|* => #first_binding.is_some() } }
+        } else {
+            quote! { match self { #(#patterns)|* => #first_binding.is_some(), _ => false } }
+        };
+
         Some(Self {
             doc,
             fn_ident,
@@ -83,7 +143,11 @@ impl<'a> Get<'a> {
             patterns,
             arm_expr,
             full_coverage,
-            deprecation_attr,
+            separate_arms,
+            attrs,
+            constness,
+            option_predicate,
+            predicate_body,
         })
     }
 }
@@ -0,0 +1,89 @@
+use crate::{Query, arm_pattern};
+use proc_macro2::{Span, TokenStream};
+use quote::quote_spanned;
+use std::borrow::Cow;
+use syn::{Attribute, Ident, Type, Visibility};
+
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub(crate) struct GetItem<'a> {
+    doc: Option<Cow<'a, str>>,
+    fn_ident: Cow<'a, Ident>,
+    span: Span,
+    vis: Cow<'a, Visibility>,
+    value_ty: &'a Type,
+    key_generic: Ident,
+    key_bounds: TokenStream,
+    /// Binding ident used in arm patterns and body, suffixed with `_binding` when the field
+    /// name would otherwise shadow the `key` argument.
+    field_binding: Ident,
+    /// One pattern per variant (full coverage only).
+    patterns: Vec<TokenStream>,
+    attrs: Vec<Attribute>,
+}
+
+impl<'a> GetItem<'a> {
+    pub(crate) fn build(&self) -> TokenStream {
+        let Self {
+            doc,
+            fn_ident,
+            span,
+            vis,
+            value_ty,
+            key_generic,
+            key_bounds,
+            field_binding,
+            patterns,
+            attrs,
+        } = self;
+        let doc = doc.as_deref().map(|d| quote_spanned!(*span => #[doc = #d]));
+        quote_spanned! {*span=>
+            #doc
+            #(#attrs)*
+            #vis fn #fn_ident<#key_generic>(&self, key: &#key_generic) -> Option<&#value_ty>
+            where
+                #key_bounds
+            {
+                match self {
+                    #(#patterns)|* => #field_binding.get(key),
+                }
+            }
+        }
+    }
+
+    pub(crate) fn from_query(query: &Query<'a>) -> Option<Self> {
+        let fields = query.enum_fields()?;
+        if !query.is_full_coverage() {
+            return None;
+        }
+        let (value_ty, key_generic, key_bounds) = query.map_value_ty_and_key_bounds()?;
+        let span = query.span();
+        let fn_ident = query.fn_ident()?;
+        let vis = query.vis();
+        let doc = query.docs(false);
+        let attrs = query.attrs();
+
+        let arm_binding = fields.first()?.binding();
+        let field_binding: Ident = if arm_binding == "key" {
+            Ident::new(&format!("{arm_binding}_binding"), span)
+        } else {
+            arm_binding.clone()
+        };
+        let patterns = fields
+            .iter()
+            .map(|field| arm_pattern(field, Some(&field_binding)))
+            .collect();
+
+        Some(Self {
+            doc,
+            fn_ident,
+            span,
+            vis,
+            value_ty,
+            key_generic,
+            key_bounds,
+            field_binding,
+            patterns,
+            attrs,
+        })
+    }
+}
@@ -16,7 +16,7 @@ pub(crate) struct GetMut<'a> {
     /// Arm body expression, already wrapped in `Some(...)` for partial coverage if needed.
     arm_expr: TokenStream,
     full_coverage: bool,
-    deprecation_attr: Option<Attribute>,
+    attrs: Vec<Attribute>,
 }
 
 impl<'a> GetMut<'a> {
@@ -30,7 +30,7 @@ impl<'a> GetMut<'a> {
             patterns,
             arm_expr,
             full_coverage,
-            deprecation_attr,
+            attrs,
         } = self;
         let doc = doc.as_deref().map(|d| quote_spanned!(*span => #[doc = #d]));
         let match_body = if *full_coverage {
@@ -40,7 +40,7 @@ impl<'a> GetMut<'a> {
         };
         quote_spanned! {*span=>
             #doc
-            #deprecation_attr
+            #(#attrs)*
             #vis fn #fn_ident(&mut self) -> #return_ty {
                 match self { #match_body }
             }
@@ -53,7 +53,7 @@ impl<'a> GetMut<'a> {
         let fn_ident = query.fn_ident()?;
         let vis = query.vis();
         let doc = query.docs(false);
-        let deprecation_attr = query.deprecation_attr();
+        let attrs = query.attrs();
 
         let first_binding = fields.first()?.binding();
         let base = parse_quote_spanned!(span => *#first_binding);
@@ -83,7 +83,7 @@ impl<'a> GetMut<'a> {
             patterns,
             arm_expr,
             full_coverage,
-            deprecation_attr,
+            attrs,
         })
     }
 }
@@ -11,11 +11,13 @@ pub(crate) struct IntoField<'a> {
     span: Span,
     vis: Cow<'a, Visibility>,
     return_ty: Type,
-    /// One pattern per variant (full coverage only).
+    /// One pattern per variant that has the field.
     patterns: Vec<TokenStream>,
     /// The binding name shared across all arms.
     binding: Ident,
-    deprecation_attr: Option<Attribute>,
+    attrs: Vec<Attribute>,
+    /// Partial coverage: returns `Err(self)` for variants without the field.
+    fallible: bool,
 }
 
 impl<'a> IntoField<'a> {
@@ -28,12 +30,25 @@ impl<'a> IntoField<'a> {
             return_ty,
             patterns,
             binding,
-            deprecation_attr,
+            attrs,
+            fallible,
         } = self;
         let doc = doc.as_deref().map(|d| quote_spanned!(*span => #[doc = #d]));
+        if *fallible {
+            return quote_spanned! {*span=>
+                #doc
+                #(#attrs)*
+                #vis fn #fn_ident(self) -> Result<#return_ty, Self> {
+                    match self {
+                        #(#patterns)|* => Ok(#binding),
+                        other => Err(other),
+                    }
+                }
+            };
+        }
         quote_spanned! {*span=>
             #doc
-            #deprecation_attr
+            #(#attrs)*
             #vis fn #fn_ident(self) -> #return_ty {
                 match self { #(#patterns)|* => #binding }
             }
@@ -41,11 +56,11 @@ impl<'a> IntoField<'a> {
     }
 
     pub(crate) fn from_query(query: &Query<'a>) -> Option<Self> {
-        if query.is_get_copy(query.ty()) {
-            return None;
-        }
         let fields = query.enum_fields()?;
-        if !query.is_full_coverage() {
+        // Unlike `into_field`, a fallible `try_into_field` isn't redundant with a copy getter,
+        // since it hands back `self` for other variants.
+        let fallible = query.is_fallible();
+        if !fallible && (query.is_get_copy(query.ty()) || !query.is_full_coverage()) {
             return None;
         }
         let span = query.span();
@@ -54,7 +69,7 @@ impl<'a> IntoField<'a> {
         let doc = query.docs(false);
         let return_ty = query.ty().clone();
         let binding = fields.first()?.binding().clone();
-        let deprecation_attr = query.deprecation_attr();
+        let attrs = query.attrs();
 
         let patterns = fields
             .iter()
@@ -69,7 +84,8 @@ impl<'a> IntoField<'a> {
             return_ty,
             patterns,
             binding,
-            deprecation_attr,
+            attrs,
+            fallible,
         })
     }
 }
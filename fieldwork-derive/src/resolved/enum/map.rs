@@ -0,0 +1,70 @@
+use crate::{Query, common_settings::must_use_attr, rebuild_arm};
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, quote_spanned};
+use std::borrow::Cow;
+use syn::{Attribute, Ident, Type, Visibility};
+
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub(crate) struct Map<'a> {
+    doc: Option<Cow<'a, str>>,
+    fn_ident: Cow<'a, Ident>,
+    span: Span,
+    vis: Cow<'a, Visibility>,
+    ty: &'a Type,
+    /// One by-value arm per variant that rebuilds the variant with the mapped field.
+    arms: Vec<TokenStream>,
+    attrs: Vec<Attribute>,
+}
+
+impl<'a> Map<'a> {
+    pub(crate) fn build(&self) -> TokenStream {
+        let Self {
+            doc,
+            fn_ident,
+            span,
+            vis,
+            ty,
+            arms,
+            attrs,
+        } = self;
+        let doc = doc.as_deref().map(|d| quote_spanned!(*span => #[doc = #d]));
+        let must_use = must_use_attr(attrs);
+        quote_spanned! {*span=>
+            #doc
+            #(#attrs)*
+            #must_use
+            #vis fn #fn_ident(self, f: impl FnOnce(#ty) -> #ty) -> Self {
+                match self { #(#arms,)* }
+            }
+        }
+    }
+
+    pub(crate) fn from_query(query: &Query<'a>) -> Option<Self> {
+        let fields = query.enum_fields()?;
+        if !query.is_full_coverage() {
+            return None;
+        }
+        let span = query.span();
+        let fn_ident = query.fn_ident()?;
+        let vis = query.vis();
+        let doc = query.docs(false);
+        let ty = query.ty();
+        let attrs = query.attrs();
+
+        let closure = Ident::new("f", span);
+        let arms = fields
+            .iter()
+            .map(|field| rebuild_arm(field, &closure, |binding| quote! { f(#binding) }))
+            .collect();
+
+        Some(Self {
+            doc,
+            fn_ident,
+            span,
+            vis,
+            ty,
+            arms,
+            attrs,
+        })
+    }
+}
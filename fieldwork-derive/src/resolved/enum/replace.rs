@@ -0,0 +1,94 @@
+use crate::{Query, arm_pattern};
+use proc_macro2::{Span, TokenStream};
+use quote::quote_spanned;
+use std::borrow::Cow;
+use syn::{Attribute, Expr, Ident, Type, Visibility};
+
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub(crate) struct Replace<'a> {
+    doc: Option<Cow<'a, str>>,
+    fn_ident: Cow<'a, Ident>,
+    span: Span,
+    vis: Cow<'a, Visibility>,
+    argument_ident: Cow<'a, Ident>,
+    argument_ty: Cow<'a, Type>,
+    assigned_value: Expr,
+    return_ty: &'a Type,
+    /// Binding ident used in arm patterns and body — derived from the field name,
+    /// suffixed with `_binding` when the field name would otherwise shadow the
+    /// argument ident (e.g. field `x` with argument also named `x`).
+    field_binding: Ident,
+    /// One pattern per variant (full coverage only).
+    patterns: Vec<TokenStream>,
+    attrs: Vec<Attribute>,
+}
+
+impl<'a> Replace<'a> {
+    pub(crate) fn build(&self) -> TokenStream {
+        let Self {
+            doc,
+            fn_ident,
+            span,
+            vis,
+            argument_ident,
+            argument_ty,
+            assigned_value,
+            return_ty,
+            field_binding,
+            patterns,
+            attrs,
+        } = self;
+        let doc = doc.as_deref().map(|d| quote_spanned!(*span => #[doc = #d]));
+        quote_spanned! {*span=>
+            #doc
+            #(#attrs)*
+            #vis fn #fn_ident(&mut self, #argument_ident: #argument_ty) -> #return_ty {
+                match self {
+                    #(#patterns)|* => std::mem::replace(#field_binding, #assigned_value),
+                }
+            }
+        }
+    }
+
+    pub(crate) fn from_query(query: &Query<'a>) -> Option<Self> {
+        let fields = query.enum_fields()?;
+        if !query.is_full_coverage() {
+            return None;
+        }
+        let span = query.span();
+        let fn_ident = query.fn_ident()?;
+        let vis = query.vis();
+        let doc = query.docs(false);
+        let argument_ident = query.argument_ident()?;
+        let (argument_ty, assigned_value) =
+            query.determine_argument_ty_and_assigned_value(&argument_ident)?;
+        let argument_ty = argument_ty?;
+        let return_ty = query.ty();
+        let attrs = query.attrs();
+
+        let arm_binding = fields.first()?.binding();
+        let field_binding: Ident = if arm_binding == &*argument_ident {
+            Ident::new(&format!("{arm_binding}_binding"), span)
+        } else {
+            arm_binding.clone()
+        };
+        let patterns = fields
+            .iter()
+            .map(|field| arm_pattern(field, Some(&field_binding)))
+            .collect();
+
+        Some(Self {
+            doc,
+            fn_ident,
+            span,
+            vis,
+            argument_ident,
+            argument_ty,
+            assigned_value,
+            return_ty,
+            field_binding,
+            patterns,
+            attrs,
+        })
+    }
+}
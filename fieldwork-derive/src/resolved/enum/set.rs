@@ -19,7 +19,11 @@ pub(crate) struct Set<'a> {
     /// argument ident (e.g. field `x` with argument also named `x`).
     field_binding: Ident,
     patterns: Vec<TokenStream>,
-    deprecation_attr: Option<Attribute>,
+    attrs: Vec<Attribute>,
+    /// Validation statements and error type, for `try_set_*` setters.
+    validation: Option<(TokenStream, &'a Type)>,
+    /// The field type, for partial-coverage setters that return the value in other variants.
+    fallible: syn::Result<Option<&'a Type>>,
 }
 
 impl<'a> Set<'a> {
@@ -35,14 +39,64 @@ impl<'a> Set<'a> {
             chainable,
             field_binding,
             patterns,
-            deprecation_attr,
+            attrs,
+            validation,
+            fallible,
         } = self;
         let doc = doc.as_deref().map(|d| quote_spanned!(*span => #[doc = #d]));
         let match_body = quote! { #(#patterns => { *#field_binding = #assigned_value; })* };
-        if *chainable {
+        let fallible = match fallible {
+            Ok(fallible) => fallible,
+            Err(error) => return error.to_compile_error(),
+        };
+        if let Some(field_ty) = fallible {
+            let (return_ty, return_value) = if *chainable {
+                (
+                    quote_spanned!(*span => &mut Self),
+                    quote_spanned!(*span => self),
+                )
+            } else {
+                (quote_spanned!(*span => ()), quote_spanned!(*span => ()))
+            };
+            quote_spanned! {*span=>
+                #doc
+                #(#attrs)*
+                #vis fn #fn_ident(
+                    &mut self,
+                    #argument_ident: #argument_ty,
+                ) -> Result<#return_ty, #field_ty> {
+                    match self {
+                        #match_body
+                        _ => return Err(#assigned_value),
+                    }
+                    Ok(#return_value)
+                }
+            }
+        } else if let Some((validation, error_ty)) = validation {
+            let (return_ty, return_value) = if *chainable {
+                (
+                    quote_spanned!(*span => &mut Self),
+                    quote_spanned!(*span => self),
+                )
+            } else {
+                (quote_spanned!(*span => ()), quote_spanned!(*span => ()))
+            };
+            quote_spanned! {*span=>
+                #doc
+                #(#attrs)*
+                #vis fn #fn_ident(
+                    &mut self,
+                    #argument_ident: #argument_ty,
+                ) -> Result<#return_ty, #error_ty> {
+                    #validation
+                    match self { #match_body }
+                    Ok(#return_value)
+                }
+            }
+        } else if *chainable {
             quote_spanned! {*span=>
                 #doc
-                #deprecation_attr
+                #(#attrs)*
                 #vis fn #fn_ident(&mut self, #argument_ident: #argument_ty) -> &mut Self {
                     match self { #match_body }
                     self
@@ -51,7 +105,7 @@ impl<'a> Set<'a> {
         } else {
             quote_spanned! {*span=>
                 #doc
-                #deprecation_attr
+                #(#attrs)*
                 #vis fn #fn_ident(&mut self, #argument_ident: #argument_ty) {
                     match self { #match_body }
                 }
@@ -61,7 +115,8 @@ impl<'a> Set<'a> {
 
     pub(crate) fn from_query(query: &Query<'a>) -> Option<Self> {
         let fields = query.enum_fields()?;
-        if !query.is_full_coverage() {
+        let fallible = query.fallible_ty();
+        if !query.is_full_coverage() && !query.is_fallible() {
             return None;
         }
         let span = query.span();
@@ -72,8 +127,11 @@ impl<'a> Set<'a> {
         let (argument_ty, assigned_value) =
             query.determine_argument_ty_and_assigned_value(&argument_ident)?;
         let argument_ty = argument_ty?;
+        let (validation, assigned_value) =
+            query.validate_assigned_value(&argument_ident, assigned_value);
+        let validation = validation.zip(query.validation().map(|(_, error_ty)| error_ty));
         let chainable = query.chainable_set();
-        let deprecation_attr = query.deprecation_attr();
+        let attrs = query.attrs();
 
         let arm_binding = fields.first()?.binding();
         let field_binding: Ident = if arm_binding == &*argument_ident {
@@ -97,7 +155,9 @@ impl<'a> Set<'a> {
             chainable,
             field_binding,
             patterns,
-            deprecation_attr,
+            attrs,
+            validation,
+            fallible,
         })
     }
 }
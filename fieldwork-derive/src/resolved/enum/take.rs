@@ -16,7 +16,7 @@ pub(crate) struct Take<'a> {
     /// The binding name shared across all arms (used to call `.take()`).
     binding: Ident,
     full_coverage: bool,
-    deprecation_attr: Option<Attribute>,
+    attrs: Vec<Attribute>,
 }
 
 impl<'a> Take<'a> {
@@ -30,7 +30,7 @@ impl<'a> Take<'a> {
             patterns,
             binding,
             full_coverage,
-            deprecation_attr,
+            attrs,
         } = self;
         let doc = doc.as_deref().map(|d| quote_spanned!(*span => #[doc = #d]));
         let match_body = if *full_coverage {
@@ -40,7 +40,7 @@ impl<'a> Take<'a> {
         };
         quote_spanned! {*span=>
             #doc
-            #deprecation_attr
+            #(#attrs)*
             #vis fn #fn_ident(&mut self) -> #return_ty {
                 match self { #match_body }
             }
@@ -57,7 +57,7 @@ impl<'a> Take<'a> {
         let doc = query.docs(false);
         let return_ty = query.ty();
         let binding = fields.first()?.binding().clone();
-        let deprecation_attr = query.deprecation_attr();
+        let attrs = query.attrs();
 
         let patterns = fields
             .iter()
@@ -73,7 +73,7 @@ impl<'a> Take<'a> {
             patterns,
             binding,
             full_coverage: query.is_full_coverage(),
-            deprecation_attr,
+            attrs,
         })
     }
 }
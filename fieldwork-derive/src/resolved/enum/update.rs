@@ -0,0 +1,95 @@
+use crate::{Query, arm_pattern};
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, quote_spanned};
+use std::borrow::Cow;
+use syn::{Attribute, Expr, Ident, Type, Visibility, parse_quote_spanned};
+
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub(crate) struct Update<'a> {
+    doc: Option<Cow<'a, str>>,
+    fn_ident: Cow<'a, Ident>,
+    span: Span,
+    vis: Cow<'a, Visibility>,
+    argument_ty: Type,
+    arm_expr: Expr,
+    chainable: bool,
+    /// One pattern per variant (full coverage only).
+    patterns: Vec<TokenStream>,
+    attrs: Vec<Attribute>,
+}
+
+impl<'a> Update<'a> {
+    pub(crate) fn build(&self) -> TokenStream {
+        let Self {
+            doc,
+            fn_ident,
+            span,
+            vis,
+            argument_ty,
+            arm_expr,
+            chainable,
+            patterns,
+            attrs,
+        } = self;
+        let doc = doc.as_deref().map(|d| quote_spanned!(*span => #[doc = #d]));
+        let match_body = quote! { #(#patterns => f(#arm_expr),)* };
+        if *chainable {
+            quote_spanned! {*span=>
+                #doc
+                #(#attrs)*
+                #vis fn #fn_ident(&mut self, f: impl FnOnce(#argument_ty)) -> &mut Self {
+                    match self { #match_body }
+                    self
+                }
+            }
+        } else {
+            quote_spanned! {*span=>
+                #doc
+                #(#attrs)*
+                #vis fn #fn_ident(&mut self, f: impl FnOnce(#argument_ty)) {
+                    match self { #match_body }
+                }
+            }
+        }
+    }
+
+    pub(crate) fn from_query(query: &Query<'a>) -> Option<Self> {
+        let fields = query.enum_fields()?;
+        if !query.is_full_coverage() {
+            return None;
+        }
+        let span = query.span();
+        let fn_ident = query.fn_ident()?;
+        let vis = query.vis();
+        let doc = query.docs(false);
+        let chainable = query.chainable_set();
+        let attrs = query.attrs();
+
+        // The closure argument is always `f`, so a field binding of that name is suffixed.
+        let arm_binding = fields.first()?.binding();
+        let field_binding: Ident = if arm_binding == "f" {
+            Ident::new(&format!("{arm_binding}_binding"), span)
+        } else {
+            arm_binding.clone()
+        };
+        let base = parse_quote_spanned!(span => *#field_binding);
+        let (arm_expr, argument_ty) = query.apply_mut_transforms(base);
+
+        let patterns = fields
+            .iter()
+            .map(|field| arm_pattern(field, Some(&field_binding)))
+            .collect();
+
+        Some(Self {
+            doc,
+            fn_ident,
+            span,
+            vis,
+            argument_ty,
+            arm_expr,
+            chainable,
+            patterns,
+            attrs,
+        })
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{Query, arm_pattern};
+use crate::{Query, arm_pattern, common_settings::must_use_attr};
 use proc_macro2::{Span, TokenStream};
 use quote::{quote, quote_spanned};
 use std::borrow::Cow;
@@ -17,7 +17,12 @@ pub(crate) struct With<'a> {
     /// argument ident (e.g. field `x` with argument also named `x`).
     field_binding: Ident,
     patterns: Vec<TokenStream>,
-    deprecation_attr: Option<Attribute>,
+    attrs: Vec<Attribute>,
+    /// Validation statements and error type, for `try_with_*` setters.
+    validation: Option<(TokenStream, &'a Type)>,
+    /// The field type, for partial-coverage setters that return `self` (and the value) in other
+    /// variants.
+    fallible: syn::Result<Option<&'a Type>>,
 }
 
 impl<'a> With<'a> {
@@ -31,15 +36,59 @@ impl<'a> With<'a> {
             assigned_value,
             field_binding,
             patterns,
-            deprecation_attr,
+            attrs,
+            validation,
+            fallible,
         } = self;
         let doc = doc.as_deref().map(|d| quote_spanned!(*span => #[doc = #d]));
+        let must_use = must_use_attr(attrs);
         let match_body = quote! { #(#patterns => { *#field_binding = #assigned_value; })* };
-        if let Some((argument_ident, argument_ty)) = argument_ident_and_ty {
+        let fallible = match fallible {
+            Ok(fallible) => fallible,
+            Err(error) => return error.to_compile_error(),
+        };
+        if let Some(field_ty) = fallible {
+            let (argument, error_ty, error) = match argument_ident_and_ty {
+                Some((argument_ident, argument_ty)) => (
+                    Some(quote_spanned!(*span => , #argument_ident: #argument_ty)),
+                    quote_spanned!(*span => (Self, #field_ty)),
+                    quote_spanned!(*span => (self, #assigned_value)),
+                ),
+                None => (
+                    None,
+                    quote_spanned!(*span => Self),
+                    quote_spanned!(*span => self),
+                ),
+            };
+            quote_spanned! {*span=>
+                #doc
+                #(#attrs)*
+                #vis fn #fn_ident(mut self #argument) -> Result<Self, #error_ty> {
+                    match &mut self {
+                        #match_body
+                        _ => return Err(#error),
+                    }
+                    Ok(self)
+                }
+            }
+        } else if let Some((validation, error_ty)) = validation {
+            let argument = argument_ident_and_ty.as_ref().map(|(argument_ident, argument_ty)| {
+                quote_spanned!(*span => , #argument_ident: #argument_ty)
+            });
+            quote_spanned! {*span=>
+                #doc
+                #(#attrs)*
+                #vis fn #fn_ident(mut self #argument) -> Result<Self, #error_ty> {
+                    #validation
+                    match &mut self { #match_body }
+                    Ok(self)
+                }
+            }
+        } else if let Some((argument_ident, argument_ty)) = argument_ident_and_ty {
             quote_spanned! {*span=>
                 #doc
-                #deprecation_attr
-                #[must_use]
+                #(#attrs)*
+                #must_use
                 #vis fn #fn_ident(mut self, #argument_ident: #argument_ty) -> Self {
                     match &mut self { #match_body }
                     self
@@ -48,8 +97,8 @@ impl<'a> With<'a> {
         } else {
             quote_spanned! {*span=>
                 #doc
-                #deprecation_attr
-                #[must_use]
+                #(#attrs)*
+                #must_use
                 #vis fn #fn_ident(mut self) -> Self {
                     match &mut self { #match_body }
                     self
@@ -60,7 +109,8 @@ impl<'a> With<'a> {
 
     pub(crate) fn from_query(query: &Query<'a>) -> Option<Self> {
         let fields = query.enum_fields()?;
-        if !query.is_full_coverage() {
+        let fallible = query.fallible_ty();
+        if !query.is_full_coverage() && !query.is_fallible() {
             return None;
         }
         let span = query.span();
@@ -70,17 +120,22 @@ impl<'a> With<'a> {
         let argument_ident = query.argument_ident()?;
         let (argument_ty, assigned_value) =
             query.determine_argument_ty_and_assigned_value(&argument_ident)?;
+        let (validation, assigned_value) =
+            query.validate_assigned_value(&argument_ident, assigned_value);
+        let validation = validation.zip(query.validation().map(|(_, error_ty)| error_ty));
         let argument_ident_and_ty = argument_ty.map(|ty| (argument_ident.clone(), ty));
-        let deprecation_attr = query.deprecation_attr();
+        let attrs = query.attrs();
 
         let arm_binding = fields.first()?.binding();
-        // Only suffix when there's an actual function argument that could clash.
-        let field_binding: Ident =
-            if argument_ident_and_ty.is_some() && arm_binding == &*argument_ident {
-                Ident::new(&format!("{}_binding", arm_binding), span)
-            } else {
-                arm_binding.clone()
-            };
+        // Only suffix when there's an actual function argument (or validated binding) that could
+        // clash.
+        let field_binding: Ident = if (argument_ident_and_ty.is_some() || validation.is_some())
+            && arm_binding == &*argument_ident
+        {
+            Ident::new(&format!("{}_binding", arm_binding), span)
+        } else {
+            arm_binding.clone()
+        };
         let patterns = fields
             .iter()
             .map(|field| arm_pattern(field, Some(&field_binding)))
@@ -95,7 +150,9 @@ impl<'a> With<'a> {
             assigned_value,
             field_binding,
             patterns,
-            deprecation_attr,
+            attrs,
+            validation,
+            fallible,
         })
     }
 }
@@ -0,0 +1,97 @@
+use crate::{Query, arm_pattern, common_settings::must_use_attr};
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, quote_spanned};
+use std::borrow::Cow;
+use syn::{Attribute, Ident, Visibility};
+
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub(crate) struct WithItem<'a> {
+    doc: Option<Cow<'a, str>>,
+    fn_ident: Cow<'a, Ident>,
+    span: Span,
+    vis: Cow<'a, Visibility>,
+    parameters: TokenStream,
+    arguments: TokenStream,
+    add_method: Ident,
+    /// Binding ident used in arm patterns and body, suffixed with `_binding` when the field
+    /// name would otherwise shadow the argument ident.
+    field_binding: Ident,
+    /// One pattern per variant (full coverage only).
+    patterns: Vec<TokenStream>,
+    attrs: Vec<Attribute>,
+}
+
+impl<'a> WithItem<'a> {
+    pub(crate) fn build(&self) -> TokenStream {
+        let Self {
+            doc,
+            fn_ident,
+            span,
+            vis,
+            parameters,
+            arguments,
+            add_method,
+            field_binding,
+            patterns,
+            attrs,
+        } = self;
+        let doc = doc.as_deref().map(|d| quote_spanned!(*span => #[doc = #d]));
+        let must_use = must_use_attr(attrs);
+        let match_body = quote! { #(#patterns)|* => { #field_binding.#add_method(#arguments); } };
+        quote_spanned! {*span=>
+            #doc
+            #(#attrs)*
+            #must_use
+            #vis fn #fn_ident(mut self, #parameters) -> Self {
+                match &mut self { #match_body }
+                self
+            }
+        }
+    }
+
+    pub(crate) fn from_query(query: &Query<'a>) -> Option<Self> {
+        let fields = query.enum_fields()?;
+        if !query.is_full_coverage() {
+            return None;
+        }
+        let (collection, _, value_ty) = query.collection()?;
+        let span = query.span();
+        let fn_ident = query.fn_ident()?;
+        let vis = query.vis();
+        let doc = query.docs(false);
+        let argument_ident = query.argument_ident()?;
+        let (parameters, arguments) = query.collection_parameters_and_arguments(&argument_ident)?;
+        let add_method = Ident::new(collection.add_method(), span);
+        let attrs = query.attrs();
+
+        let arm_binding = fields.first()?.binding();
+        // Map entries are passed as `key` and `value` instead of the argument ident.
+        let reserved = if value_ty.is_some() {
+            arm_binding == "key" || arm_binding == "value"
+        } else {
+            arm_binding == &*argument_ident
+        };
+        let field_binding: Ident = if reserved {
+            Ident::new(&format!("{arm_binding}_binding"), span)
+        } else {
+            arm_binding.clone()
+        };
+        let patterns = fields
+            .iter()
+            .map(|field| arm_pattern(field, Some(&field_binding)))
+            .collect();
+
+        Some(Self {
+            doc,
+            fn_ident,
+            span,
+            vis,
+            parameters,
+            arguments,
+            add_method,
+            field_binding,
+            patterns,
+            attrs,
+        })
+    }
+}
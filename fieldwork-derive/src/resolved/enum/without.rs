@@ -1,4 +1,4 @@
-use crate::{Query, arm_pattern};
+use crate::{Query, arm_pattern, common_settings::must_use_attr};
 use proc_macro2::{Span, TokenStream};
 use quote::{quote, quote_spanned};
 use std::borrow::Cow;
@@ -16,7 +16,7 @@ pub(crate) struct Without<'a> {
     field_binding: Ident,
     patterns: Vec<TokenStream>,
     full_coverage: bool,
-    deprecation_attr: Option<Attribute>,
+    attrs: Vec<Attribute>,
 }
 
 impl<'a> Without<'a> {
@@ -30,9 +30,10 @@ impl<'a> Without<'a> {
             field_binding,
             patterns,
             full_coverage,
-            deprecation_attr,
+            attrs,
         } = self;
         let doc = doc.as_deref().map(|d| quote_spanned!(*span => #[doc = #d]));
+        let must_use = must_use_attr(attrs);
         let fallthrough = if *full_coverage {
             quote! {}
         } else {
@@ -40,8 +41,8 @@ impl<'a> Without<'a> {
         };
         quote_spanned! {*span=>
             #doc
-            #deprecation_attr
-            #[must_use]
+            #(#attrs)*
+            #must_use
             #vis fn #fn_ident(mut self) -> Self {
                 match &mut self {
                     #(#patterns => { *#field_binding = #assigned_value; })*
@@ -61,7 +62,7 @@ impl<'a> Without<'a> {
         let argument_ident = query.argument_ident()?;
         let (_, assigned_value) =
             query.determine_argument_ty_and_assigned_value(&argument_ident)?;
-        let deprecation_attr = query.deprecation_attr();
+        let attrs = query.attrs();
 
         // `without` takes no function argument, so the field binding can always
         // be the field name itself with no risk of shadowing.
@@ -80,7 +81,7 @@ impl<'a> Without<'a> {
             field_binding,
             patterns,
             full_coverage: query.is_full_coverage(),
-            deprecation_attr,
+            attrs,
         })
     }
 }
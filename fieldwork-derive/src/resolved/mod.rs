@@ -14,6 +14,18 @@ pub(crate) enum Resolved<'a> {
     StructGetMut(r#struct::GetMut<'a>),
     StructTake(r#struct::Take<'a>),
     StructIntoField(r#struct::IntoField<'a>),
+    StructReplace(r#struct::Replace<'a>),
+    StructUpdate(r#struct::Update<'a>),
+    StructMap(r#struct::Map<'a>),
+    StructPush(r#struct::Push<'a>),
+    StructExtend(r#struct::Extend<'a>),
+    StructClear(r#struct::Clear<'a>),
+    StructWithItem(r#struct::WithItem<'a>),
+    StructGetItem(r#struct::GetItem<'a>),
+    StructGetItemMut(r#struct::GetItemMut<'a>),
+    StructInsertItem(r#struct::InsertItem<'a>),
+    StructRemoveItem(r#struct::RemoveItem<'a>),
+    StructFlag(r#struct::Flag<'a>),
     EnumGet(r#enum::Get<'a>),
     EnumSet(r#enum::Set<'a>),
     EnumWith(r#enum::With<'a>),
@@ -21,6 +33,18 @@ pub(crate) enum Resolved<'a> {
     EnumGetMut(r#enum::GetMut<'a>),
     EnumTake(r#enum::Take<'a>),
     EnumIntoField(r#enum::IntoField<'a>),
+    EnumReplace(r#enum::Replace<'a>),
+    EnumUpdate(r#enum::Update<'a>),
+    EnumMap(r#enum::Map<'a>),
+    EnumPush(r#enum::Push<'a>),
+    EnumExtend(r#enum::Extend<'a>),
+    EnumClear(r#enum::Clear<'a>),
+    EnumWithItem(r#enum::WithItem<'a>),
+    EnumGetItem(r#enum::GetItem<'a>),
+    EnumGetItemMut(r#enum::GetItemMut<'a>),
+    EnumInsertItem(r#enum::InsertItem<'a>),
+    EnumRemoveItem(r#enum::RemoveItem<'a>),
+    EnumFlag(r#enum::Flag<'a>),
 }
 
 impl<'a> Resolved<'a> {
@@ -33,6 +57,18 @@ impl<'a> Resolved<'a> {
             Resolved::StructGetMut(r) => r.build(),
             Resolved::StructTake(r) => r.build(),
             Resolved::StructIntoField(r) => r.build(),
+            Resolved::StructReplace(r) => r.build(),
+            Resolved::StructUpdate(r) => r.build(),
+            Resolved::StructMap(r) => r.build(),
+            Resolved::StructPush(r) => r.build(),
+            Resolved::StructExtend(r) => r.build(),
+            Resolved::StructClear(r) => r.build(),
+            Resolved::StructWithItem(r) => r.build(),
+            Resolved::StructGetItem(r) => r.build(),
+            Resolved::StructGetItemMut(r) => r.build(),
+            Resolved::StructInsertItem(r) => r.build(),
+            Resolved::StructRemoveItem(r) => r.build(),
+            Resolved::StructFlag(r) => r.build(),
             Resolved::EnumGet(r) => r.build(),
             Resolved::EnumSet(r) => r.build(),
             Resolved::EnumWith(r) => r.build(),
@@ -40,6 +76,18 @@ impl<'a> Resolved<'a> {
             Resolved::EnumGetMut(r) => r.build(),
             Resolved::EnumTake(r) => r.build(),
             Resolved::EnumIntoField(r) => r.build(),
+            Resolved::EnumReplace(r) => r.build(),
+            Resolved::EnumUpdate(r) => r.build(),
+            Resolved::EnumMap(r) => r.build(),
+            Resolved::EnumPush(r) => r.build(),
+            Resolved::EnumExtend(r) => r.build(),
+            Resolved::EnumClear(r) => r.build(),
+            Resolved::EnumWithItem(r) => r.build(),
+            Resolved::EnumGetItem(r) => r.build(),
+            Resolved::EnumGetItemMut(r) => r.build(),
+            Resolved::EnumInsertItem(r) => r.build(),
+            Resolved::EnumRemoveItem(r) => r.build(),
+            Resolved::EnumFlag(r) => r.build(),
         }
     }
 
@@ -48,18 +96,48 @@ impl<'a> Resolved<'a> {
             return None;
         }
         if query.is_enum() {
+            if !query.is_uniform_type() && query.method() != Method::Get {
+                return None;
+            }
             match query.method() {
-                Method::Get => r#enum::Get::from_query(query).map(Self::EnumGet),
+                Method::Get | Method::Cloned => r#enum::Get::from_query(query).map(Self::EnumGet),
                 Method::Set => r#enum::Set::from_query(query).map(Self::EnumSet),
                 Method::With => r#enum::With::from_query(query).map(Self::EnumWith),
                 Method::GetMut => r#enum::GetMut::from_query(query).map(Self::EnumGetMut),
                 Method::Without => r#enum::Without::from_query(query).map(Self::EnumWithout),
                 Method::Take => r#enum::Take::from_query(query).map(Self::EnumTake),
                 Method::IntoField => r#enum::IntoField::from_query(query).map(Self::EnumIntoField),
+                Method::Replace => r#enum::Replace::from_query(query).map(Self::EnumReplace),
+                Method::Update => r#enum::Update::from_query(query).map(Self::EnumUpdate),
+                Method::Map => r#enum::Map::from_query(query).map(Self::EnumMap),
+                Method::Push => r#enum::Push::from_query(query).map(Self::EnumPush),
+                Method::Extend => r#enum::Extend::from_query(query).map(Self::EnumExtend),
+                Method::Clear => r#enum::Clear::from_query(query).map(Self::EnumClear),
+                Method::WithItem => r#enum::WithItem::from_query(query).map(Self::EnumWithItem),
+                Method::GetItem => r#enum::GetItem::from_query(query).map(Self::EnumGetItem),
+                Method::GetItemMut => {
+                    r#enum::GetItemMut::from_query(query).map(Self::EnumGetItemMut)
+                }
+                Method::InsertItem => {
+                    r#enum::InsertItem::from_query(query).map(Self::EnumInsertItem)
+                }
+                Method::RemoveItem => {
+                    r#enum::RemoveItem::from_query(query).map(Self::EnumRemoveItem)
+                }
+                Method::Enable
+                | Method::Disable
+                | Method::Toggle
+                | Method::Enabled
+                | Method::Disabled
+                | Method::Toggled => r#enum::Flag::from_query(query).map(Self::EnumFlag),
+                // `new` and `builder` take every field at once, so they're built separately.
+                Method::New | Method::Builder => None,
             }
         } else {
             match query.method() {
-                Method::Get => r#struct::Get::from_query(query).map(Self::StructGet),
+                Method::Get | Method::Cloned => {
+                    r#struct::Get::from_query(query).map(Self::StructGet)
+                }
                 Method::Set => r#struct::Set::from_query(query).map(Self::StructSet),
                 Method::With => r#struct::With::from_query(query).map(Self::StructWith),
                 Method::GetMut => r#struct::GetMut::from_query(query).map(Self::StructGetMut),
@@ -68,6 +146,30 @@ impl<'a> Resolved<'a> {
                 Method::IntoField => {
                     r#struct::IntoField::from_query(query).map(Self::StructIntoField)
                 }
+                Method::Replace => r#struct::Replace::from_query(query).map(Self::StructReplace),
+                Method::Update => r#struct::Update::from_query(query).map(Self::StructUpdate),
+                Method::Map => r#struct::Map::from_query(query).map(Self::StructMap),
+                Method::Push => r#struct::Push::from_query(query).map(Self::StructPush),
+                Method::Extend => r#struct::Extend::from_query(query).map(Self::StructExtend),
+                Method::Clear => r#struct::Clear::from_query(query).map(Self::StructClear),
+                Method::WithItem => r#struct::WithItem::from_query(query).map(Self::StructWithItem),
+                Method::GetItem => r#struct::GetItem::from_query(query).map(Self::StructGetItem),
+                Method::GetItemMut => {
+                    r#struct::GetItemMut::from_query(query).map(Self::StructGetItemMut)
+                }
+                Method::InsertItem => {
+                    r#struct::InsertItem::from_query(query).map(Self::StructInsertItem)
+                }
+                Method::RemoveItem => {
+                    r#struct::RemoveItem::from_query(query).map(Self::StructRemoveItem)
+                }
+                Method::Enable
+                | Method::Disable
+                | Method::Toggle
+                | Method::Enabled
+                | Method::Disabled
+                | Method::Toggled => r#struct::Flag::from_query(query).map(Self::StructFlag),
+                Method::New | Method::Builder => None,
             }
         }
     }
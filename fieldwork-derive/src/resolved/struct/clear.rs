@@ -0,0 +1,73 @@
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote_spanned;
+use std::borrow::Cow;
+use syn::{Attribute, Ident, Member, Visibility};
+
+use crate::Query;
+
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub(crate) struct Clear<'a> {
+    chainable: bool,
+    doc: Option<Cow<'a, str>>,
+    fn_ident: Cow<'a, Ident>,
+    span: Span,
+    member: &'a Member,
+    vis: Cow<'a, Visibility>,
+    attrs: Vec<Attribute>,
+}
+
+impl<'a> Clear<'a> {
+    pub(crate) fn build(&self) -> TokenStream2 {
+        let Clear {
+            chainable,
+            doc,
+            fn_ident,
+            span,
+            member,
+            vis,
+            attrs,
+        } = self;
+
+        let doc = doc.as_deref().map(|d| quote_spanned!(*span => #[doc = #d]));
+
+        if *chainable {
+            quote_spanned! {*span=>
+                #doc
+                #(#attrs)*
+                #vis fn #fn_ident(&mut self) -> &mut Self {
+                    self.#member.clear();
+                    self
+                }
+            }
+        } else {
+            quote_spanned! {*span=>
+                #doc
+                #(#attrs)*
+                #vis fn #fn_ident(&mut self) {
+                    self.#member.clear();
+                }
+            }
+        }
+    }
+
+    pub(crate) fn from_query(query: &Query<'a>) -> Option<Self> {
+        query.collection()?;
+        let span = query.span();
+        let vis = query.vis();
+        let fn_ident = query.fn_ident()?;
+        let member = query.member();
+        let chainable = query.chainable_set();
+        let doc = query.docs(false);
+        let attrs = query.attrs();
+
+        Some(Self {
+            chainable,
+            doc,
+            fn_ident,
+            span,
+            member,
+            vis,
+            attrs,
+        })
+    }
+}
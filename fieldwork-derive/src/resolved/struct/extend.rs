@@ -0,0 +1,80 @@
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote_spanned;
+use std::borrow::Cow;
+use syn::{Attribute, Ident, Member, Visibility};
+
+use crate::Query;
+
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub(crate) struct Extend<'a> {
+    parameters: TokenStream2,
+    arguments: TokenStream2,
+    chainable: bool,
+    doc: Option<Cow<'a, str>>,
+    fn_ident: Cow<'a, Ident>,
+    span: Span,
+    member: &'a Member,
+    vis: Cow<'a, Visibility>,
+    attrs: Vec<Attribute>,
+}
+
+impl<'a> Extend<'a> {
+    pub(crate) fn build(&self) -> TokenStream2 {
+        let Extend {
+            parameters,
+            arguments,
+            chainable,
+            doc,
+            fn_ident,
+            span,
+            member,
+            vis,
+            attrs,
+        } = self;
+
+        let doc = doc.as_deref().map(|d| quote_spanned!(*span => #[doc = #d]));
+
+        if *chainable {
+            quote_spanned! {*span=>
+                #doc
+                #(#attrs)*
+                #vis fn #fn_ident(&mut self, #parameters) -> &mut Self {
+                    self.#member.extend(#arguments);
+                    self
+                }
+            }
+        } else {
+            quote_spanned! {*span=>
+                #doc
+                #(#attrs)*
+                #vis fn #fn_ident(&mut self, #parameters) {
+                    self.#member.extend(#arguments);
+                }
+            }
+        }
+    }
+
+    pub(crate) fn from_query(query: &Query<'a>) -> Option<Self> {
+        let span = query.span();
+        let vis = query.vis();
+        let fn_ident = query.fn_ident()?;
+        let member = query.member();
+        let argument_ident = query.argument_ident()?;
+        let (parameters, arguments) = query.collection_parameters_and_arguments(&argument_ident)?;
+        let chainable = query.chainable_set();
+        let doc = query.docs(false);
+        let attrs = query.attrs();
+
+        Some(Self {
+            parameters,
+            arguments,
+            chainable,
+            doc,
+            fn_ident,
+            span,
+            member,
+            vis,
+            attrs,
+        })
+    }
+}
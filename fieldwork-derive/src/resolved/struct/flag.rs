@@ -0,0 +1,94 @@
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote_spanned;
+use std::borrow::Cow;
+use syn::{Attribute, Expr, Ident, Visibility, parse_quote_spanned};
+
+use crate::{Query, common_settings::must_use_attr};
+
+/// `enable`, `disable`, and `toggle` (or their owned `enabled`, `disabled`, and `toggled`
+/// counterparts) for a `bool` field.
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub(crate) struct Flag<'a> {
+    owned: bool,
+    chainable: bool,
+    target: Expr,
+    value: Expr,
+    doc: Option<Cow<'a, str>>,
+    fn_ident: Cow<'a, Ident>,
+    span: Span,
+    vis: Cow<'a, Visibility>,
+    attrs: Vec<Attribute>,
+}
+
+impl<'a> Flag<'a> {
+    pub(crate) fn build(&self) -> TokenStream2 {
+        let Flag {
+            owned,
+            chainable,
+            target,
+            value,
+            doc,
+            fn_ident,
+            span,
+            vis,
+            attrs,
+        } = self;
+
+        let doc = doc.as_deref().map(|d| quote_spanned!(*span => #[doc = #d]));
+
+        let must_use = must_use_attr(attrs);
+
+        if *owned {
+            quote_spanned! {*span=>
+                #doc
+                #(#attrs)*
+                #must_use
+                #vis fn #fn_ident(mut self) -> Self {
+                    #target = #value;
+                    self
+                }
+            }
+        } else if *chainable {
+            quote_spanned! {*span=>
+                #doc
+                #(#attrs)*
+                #vis fn #fn_ident(&mut self) -> &mut Self {
+                    #target = #value;
+                    self
+                }
+            }
+        } else {
+            quote_spanned! {*span=>
+                #doc
+                #(#attrs)*
+                #vis fn #fn_ident(&mut self) {
+                    #target = #value;
+                }
+            }
+        }
+    }
+
+    pub(crate) fn from_query(query: &Query<'a>) -> Option<Self> {
+        let span = query.span();
+        let member = query.member();
+        let target: Expr = parse_quote_spanned!(span => self.#member);
+        let (value, owned) = query.flag_value(&target)?;
+        let vis = query.vis();
+        let fn_ident = query.fn_ident()?;
+        let chainable = query.chainable_set();
+        let doc = query.docs(false);
+        let attrs = query.attrs();
+
+        Some(Self {
+            owned,
+            chainable,
+            target,
+            value,
+            doc,
+            fn_ident,
+            span,
+            vis,
+            attrs,
+        })
+    }
+}
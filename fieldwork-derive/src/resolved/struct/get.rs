@@ -1,8 +1,8 @@
-use crate::Query;
+use crate::{Method, Query};
 use proc_macro2::{Span, TokenStream};
 use quote::quote_spanned;
 use std::borrow::Cow;
-use syn::{Attribute, Expr, Ident, Type, Visibility};
+use syn::{Attribute, Expr, Ident, Member, Token, Type, Visibility};
 
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub(crate) struct Get<'a> {
@@ -12,7 +12,12 @@ pub(crate) struct Get<'a> {
     ty: Type,
     expr: Expr,
     vis: Cow<'a, Visibility>,
-    deprecation_attr: Option<Attribute>,
+    attrs: Vec<Attribute>,
+    /// `const` for `get(const)`, or why the getter can't be const.
+    constness: syn::Result<Option<Token![const]>>,
+    member: &'a Member,
+    /// `has_` predicate ident and docs, when `option_predicates` applies.
+    option_predicate: Option<(Ident, Option<String>)>,
 }
 
 impl<'a> Get<'a> {
@@ -24,20 +29,48 @@ impl<'a> Get<'a> {
             ty,
             vis,
             expr,
-            deprecation_attr,
+            attrs,
+            constness,
+            member,
+            option_predicate,
         } = self;
+        let (constness, error) = match constness {
+            Ok(constness) => (constness.as_ref(), None),
+            Err(error) => (None, Some(error.to_compile_error())),
+        };
         let doc = doc.as_deref().map(|d| quote_spanned!(*span => #[doc = #d]));
 
+        let option_predicate = option_predicate
+            .as_ref()
+            .map(|(predicate_ident, predicate_doc)| {
+                let predicate_doc = predicate_doc
+                    .as_deref()
+                    .map(|d| quote_spanned!(*span => #[doc = #d]));
+                quote_spanned! {*span=>
+                    #predicate_doc
+                    #(#attrs)*
+                    #vis #constness fn #predicate_ident(&self) -> bool {
+                        self.#member.is_some()
+                    }
+                }
+            });
+
         quote_spanned! {*span=>
+            #error
             #doc
-            #deprecation_attr
-            #vis fn #fn_ident(&self) -> #ty {
+            #(#attrs)*
+            #vis #constness fn #fn_ident(&self) -> #ty {
                 #expr
             }
+            #option_predicate
         }
     }
 
     pub(crate) fn from_query(query: &Query<'a>) -> Option<Self> {
+        // `cloned` shares the getter implementation, but only exists where cloning applies.
+        if query.method() == Method::Cloned && !query.is_get_clone() {
+            return None;
+        }
         let span = query.span();
         let vis = query.vis();
         let fn_ident = query.fn_ident()?;
@@ -45,7 +78,10 @@ impl<'a> Get<'a> {
         let (expr, ty, is_get_copy) = query.get_access_expr_type_and_copy();
 
         let doc = query.docs(is_get_copy);
-        let deprecation_attr = query.deprecation_attr();
+        let attrs = query.attrs();
+        let constness = query.constness(&expr);
+        let member = query.member();
+        let option_predicate = query.option_predicate();
 
         Some(Self {
             doc,
@@ -54,7 +90,10 @@ impl<'a> Get<'a> {
             ty,
             expr,
             vis,
-            deprecation_attr,
+            attrs,
+            constness,
+            member,
+            option_predicate,
         })
     }
 }
@@ -0,0 +1,70 @@
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote_spanned;
+use std::borrow::Cow;
+use syn::{Attribute, Ident, Member, Type, Visibility};
+
+use crate::Query;
+
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub(crate) struct GetItem<'a> {
+    value_ty: &'a Type,
+    key_generic: Ident,
+    key_bounds: TokenStream2,
+    doc: Option<Cow<'a, str>>,
+    fn_ident: Cow<'a, Ident>,
+    span: Span,
+    member: &'a Member,
+    vis: Cow<'a, Visibility>,
+    attrs: Vec<Attribute>,
+}
+
+impl<'a> GetItem<'a> {
+    pub(crate) fn build(&self) -> TokenStream2 {
+        let GetItem {
+            value_ty,
+            key_generic,
+            key_bounds,
+            doc,
+            fn_ident,
+            span,
+            member,
+            vis,
+            attrs,
+        } = self;
+
+        let doc = doc.as_deref().map(|d| quote_spanned!(*span => #[doc = #d]));
+
+        quote_spanned! {*span=>
+            #doc
+            #(#attrs)*
+            #vis fn #fn_ident<#key_generic>(&self, key: &#key_generic) -> Option<&#value_ty>
+            where
+                #key_bounds
+            {
+                self.#member.get(key)
+            }
+        }
+    }
+
+    pub(crate) fn from_query(query: &Query<'a>) -> Option<Self> {
+        let (value_ty, key_generic, key_bounds) = query.map_value_ty_and_key_bounds()?;
+        let span = query.span();
+        let vis = query.vis();
+        let fn_ident = query.fn_ident()?;
+        let member = query.member();
+        let doc = query.docs(false);
+        let attrs = query.attrs();
+
+        Some(Self {
+            value_ty,
+            key_generic,
+            key_bounds,
+            doc,
+            fn_ident,
+            span,
+            member,
+            vis,
+            attrs,
+        })
+    }
+}
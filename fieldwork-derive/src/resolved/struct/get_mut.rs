@@ -13,7 +13,7 @@ pub(crate) struct GetMut<'a> {
     pub(crate) ty: Type,
     pub(crate) vis: Cow<'a, Visibility>,
     pub(crate) access_expr: Expr,
-    pub(crate) deprecation_attr: Option<Attribute>,
+    pub(crate) attrs: Vec<Attribute>,
 }
 
 impl<'a> GetMut<'a> {
@@ -24,7 +24,7 @@ impl<'a> GetMut<'a> {
         let doc = query.docs(false);
 
         let (access_expr, ty) = query.mut_access_expr_and_type();
-        let deprecation_attr = query.deprecation_attr();
+        let attrs = query.attrs();
 
         Some(Self {
             doc,
@@ -33,7 +33,7 @@ impl<'a> GetMut<'a> {
             ty,
             vis,
             access_expr,
-            deprecation_attr,
+            attrs,
         })
     }
 
@@ -45,12 +45,12 @@ impl<'a> GetMut<'a> {
             ty,
             vis,
             access_expr,
-            deprecation_attr,
+            attrs,
         } = self;
         let doc = doc.as_deref().map(|d| quote_spanned!(*span => #[doc = #d]));
         quote_spanned! {*span=>
             #doc
-            #deprecation_attr
+            #(#attrs)*
             #vis fn #fn_ident(&mut self) -> #ty {
                 #access_expr
             }
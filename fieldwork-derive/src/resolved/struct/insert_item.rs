@@ -0,0 +1,71 @@
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote_spanned;
+use std::borrow::Cow;
+use syn::{Attribute, Ident, Member, Type, Visibility};
+
+use crate::Query;
+
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub(crate) struct InsertItem<'a> {
+    parameters: TokenStream2,
+    arguments: TokenStream2,
+    value_ty: &'a Type,
+    doc: Option<Cow<'a, str>>,
+    fn_ident: Cow<'a, Ident>,
+    span: Span,
+    member: &'a Member,
+    vis: Cow<'a, Visibility>,
+    attrs: Vec<Attribute>,
+}
+
+impl<'a> InsertItem<'a> {
+    pub(crate) fn build(&self) -> TokenStream2 {
+        let InsertItem {
+            parameters,
+            arguments,
+            value_ty,
+            doc,
+            fn_ident,
+            span,
+            member,
+            vis,
+            attrs,
+        } = self;
+
+        let doc = doc.as_deref().map(|d| quote_spanned!(*span => #[doc = #d]));
+
+        quote_spanned! {*span=>
+            #doc
+            #(#attrs)*
+            #vis fn #fn_ident(&mut self, #parameters) -> Option<#value_ty> {
+                self.#member.insert(#arguments)
+            }
+        }
+    }
+
+    pub(crate) fn from_query(query: &Query<'a>) -> Option<Self> {
+        let (_, _, Some(value_ty)) = query.collection()? else {
+            return None;
+        };
+        let span = query.span();
+        let vis = query.vis();
+        let fn_ident = query.fn_ident()?;
+        let member = query.member();
+        let argument_ident = query.argument_ident()?;
+        let (parameters, arguments) = query.collection_parameters_and_arguments(&argument_ident)?;
+        let doc = query.docs(false);
+        let attrs = query.attrs();
+
+        Some(Self {
+            parameters,
+            arguments,
+            value_ty,
+            doc,
+            fn_ident,
+            span,
+            member,
+            vis,
+            attrs,
+        })
+    }
+}
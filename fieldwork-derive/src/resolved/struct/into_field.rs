@@ -12,7 +12,7 @@ pub(crate) struct IntoField<'a> {
     ty: Type,
     member: &'a Member,
     vis: Cow<'a, Visibility>,
-    deprecation_attr: Option<Attribute>,
+    attrs: Vec<Attribute>,
 }
 
 impl<'a> IntoField<'a> {
@@ -24,13 +24,13 @@ impl<'a> IntoField<'a> {
             ty,
             member,
             vis,
-            deprecation_attr,
+            attrs,
         } = self;
         let doc = doc.as_deref().map(|d| quote_spanned!(*span => #[doc = #d]));
 
         quote_spanned! {*span=>
             #doc
-            #deprecation_attr
+            #(#attrs)*
             #vis fn #fn_ident(self) -> #ty {
                 self.#member
             }
@@ -48,7 +48,7 @@ impl<'a> IntoField<'a> {
         let member = query.member();
         let ty = query.ty().clone();
         let doc = query.docs(false);
-        let deprecation_attr = query.deprecation_attr();
+        let attrs = query.attrs();
 
         Some(Self {
             doc,
@@ -57,7 +57,7 @@ impl<'a> IntoField<'a> {
             ty,
             member,
             vis,
-            deprecation_attr,
+            attrs,
         })
     }
 }
@@ -0,0 +1,63 @@
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote_spanned;
+use std::borrow::Cow;
+use syn::{Attribute, Ident, Member, Type, Visibility};
+
+use crate::{Query, common_settings::must_use_attr};
+
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub(crate) struct Map<'a> {
+    doc: Option<Cow<'a, str>>,
+    fn_ident: Cow<'a, Ident>,
+    span: Span,
+    ty: &'a Type,
+    member: &'a Member,
+    vis: Cow<'a, Visibility>,
+    attrs: Vec<Attribute>,
+}
+
+impl<'a> Map<'a> {
+    pub(crate) fn build(&self) -> TokenStream2 {
+        let Map {
+            doc,
+            fn_ident,
+            span,
+            ty,
+            member,
+            vis,
+            attrs,
+        } = self;
+        let doc = doc.as_deref().map(|d| quote_spanned!(*span => #[doc = #d]));
+        let must_use = must_use_attr(attrs);
+
+        quote_spanned! {*span=>
+            #doc
+            #(#attrs)*
+            #must_use
+            #vis fn #fn_ident(mut self, f: impl FnOnce(#ty) -> #ty) -> Self {
+                self.#member = f(self.#member);
+                self
+            }
+        }
+    }
+
+    pub(crate) fn from_query(query: &Query<'a>) -> Option<Self> {
+        let span = query.span();
+        let vis = query.vis();
+        let fn_ident = query.fn_ident()?;
+        let member = query.member();
+        let ty = query.ty();
+        let doc = query.docs(false);
+        let attrs = query.attrs();
+
+        Some(Self {
+            doc,
+            fn_ident,
+            span,
+            ty,
+            member,
+            vis,
+            attrs,
+        })
+    }
+}
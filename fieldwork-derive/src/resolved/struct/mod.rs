@@ -1,15 +1,39 @@
+mod clear;
+mod extend;
+mod flag;
 mod get;
+mod get_item;
+mod get_item_mut;
 mod get_mut;
+mod insert_item;
 mod into_field;
+mod map;
+mod push;
+mod remove_item;
+mod replace;
 mod set;
 mod take;
+mod update;
 mod with;
+mod with_item;
 mod without;
 
+pub(crate) use clear::Clear;
+pub(crate) use extend::Extend;
+pub(crate) use flag::Flag;
 pub(crate) use get::Get;
+pub(crate) use get_item::GetItem;
+pub(crate) use get_item_mut::GetItemMut;
 pub(crate) use get_mut::GetMut;
+pub(crate) use insert_item::InsertItem;
 pub(crate) use into_field::IntoField;
+pub(crate) use map::Map;
+pub(crate) use push::Push;
+pub(crate) use remove_item::RemoveItem;
+pub(crate) use replace::Replace;
 pub(crate) use set::Set;
 pub(crate) use take::Take;
+pub(crate) use update::Update;
 pub(crate) use with::With;
+pub(crate) use with_item::WithItem;
 pub(crate) use without::Without;
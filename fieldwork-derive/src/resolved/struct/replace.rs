@@ -0,0 +1,74 @@
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote_spanned;
+use std::borrow::Cow;
+use syn::{Attribute, Expr, Ident, Member, Type, Visibility};
+
+use crate::Query;
+
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub(crate) struct Replace<'a> {
+    argument_ident: Cow<'a, Ident>,
+    argument_ty: Cow<'a, Type>,
+    assigned_value: Expr,
+    doc: Option<Cow<'a, str>>,
+    fn_ident: Cow<'a, Ident>,
+    span: Span,
+    ty: &'a Type,
+    member: &'a Member,
+    vis: Cow<'a, Visibility>,
+    attrs: Vec<Attribute>,
+}
+
+impl<'a> Replace<'a> {
+    pub(crate) fn build(&self) -> TokenStream2 {
+        let Replace {
+            argument_ident,
+            argument_ty,
+            assigned_value,
+            doc,
+            fn_ident,
+            span,
+            ty,
+            member,
+            vis,
+            attrs,
+        } = self;
+
+        let doc = doc.as_deref().map(|d| quote_spanned!(*span => #[doc = #d]));
+
+        quote_spanned! {*span=>
+            #doc
+            #(#attrs)*
+            #vis fn #fn_ident(&mut self, #argument_ident: #argument_ty) -> #ty {
+                std::mem::replace(&mut self.#member, #assigned_value)
+            }
+        }
+    }
+
+    pub(crate) fn from_query(query: &Query<'a>) -> Option<Self> {
+        let span = query.span();
+        let vis = query.vis();
+        let fn_ident = query.fn_ident()?;
+        let member = query.member();
+        let ty = query.ty();
+        let argument_ident = query.argument_ident()?;
+        let (argument_ty, assigned_value) =
+            query.determine_argument_ty_and_assigned_value(&argument_ident)?;
+        let argument_ty = argument_ty?;
+        let doc = query.docs(false);
+        let attrs = query.attrs();
+
+        Some(Self {
+            argument_ident,
+            argument_ty,
+            assigned_value,
+            doc,
+            fn_ident,
+            span,
+            ty,
+            member,
+            vis,
+            attrs,
+        })
+    }
+}
@@ -16,7 +16,9 @@ pub(crate) struct Set<'a> {
     pub(crate) span: Span,
     pub(crate) member: &'a Member,
     pub(crate) vis: Cow<'a, Visibility>,
-    pub(crate) deprecation_attr: Option<Attribute>,
+    pub(crate) attrs: Vec<Attribute>,
+    /// Validation statements and error type, for `try_set_*` setters.
+    pub(crate) validation: Option<(TokenStream2, &'a Type)>,
 }
 impl<'a> Set<'a> {
     pub(crate) fn build(&self) -> TokenStream2 {
@@ -30,15 +32,37 @@ impl<'a> Set<'a> {
             span,
             member,
             vis,
-            deprecation_attr,
+            attrs,
+            validation,
         } = self;
 
         let doc = doc.as_deref().map(|d| quote_spanned!(*span => #[doc = #d]));
 
-        if *chainable_set {
+        if let Some((validation, error_ty)) = validation {
+            let (return_ty, return_value) = if *chainable_set {
+                (
+                    quote_spanned!(*span => &mut Self),
+                    quote_spanned!(*span => self),
+                )
+            } else {
+                (quote_spanned!(*span => ()), quote_spanned!(*span => ()))
+            };
             quote_spanned! {*span=>
                 #doc
-                #deprecation_attr
+                #(#attrs)*
+                #vis fn #fn_ident(
+                    &mut self,
+                    #argument_ident: #argument_ty,
+                ) -> Result<#return_ty, #error_ty> {
+                    #validation
+                    self.#member = #assigned_value;
+                    Ok(#return_value)
+                }
+            }
+        } else if *chainable_set {
+            quote_spanned! {*span=>
+                #doc
+                #(#attrs)*
                 #vis fn #fn_ident(&mut self, #argument_ident: #argument_ty) -> &mut Self {
                     self.#member = #assigned_value;
                     self
@@ -47,7 +71,7 @@ impl<'a> Set<'a> {
         } else {
             quote_spanned! {*span=>
                 #doc
-                #deprecation_attr
+                #(#attrs)*
                 #vis fn #fn_ident(&mut self, #argument_ident: #argument_ty) {
                     self.#member = #assigned_value;
                 }
@@ -65,8 +89,11 @@ impl<'a> Set<'a> {
         let (argument_ty, assigned_value) =
             query.determine_argument_ty_and_assigned_value(&argument_ident)?;
         let argument_ty = argument_ty?;
+        let (validation, assigned_value) =
+            query.validate_assigned_value(&argument_ident, assigned_value);
+        let validation = validation.zip(query.validation().map(|(_, error_ty)| error_ty));
         let doc = query.docs(false);
-        let deprecation_attr = query.deprecation_attr();
+        let attrs = query.attrs();
 
         Some(Self {
             argument_ident,
@@ -78,7 +105,8 @@ impl<'a> Set<'a> {
             span,
             member,
             vis,
-            deprecation_attr,
+            attrs,
+            validation,
         })
     }
 }
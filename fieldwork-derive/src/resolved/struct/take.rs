@@ -12,7 +12,7 @@ pub(crate) struct Take<'a> {
     ty: &'a Type,
     member: &'a Member,
     vis: Cow<'a, Visibility>,
-    deprecation_attr: Option<Attribute>,
+    attrs: Vec<Attribute>,
 }
 
 impl<'a> Take<'a> {
@@ -24,13 +24,13 @@ impl<'a> Take<'a> {
             ty,
             vis,
             member,
-            deprecation_attr,
+            attrs,
         } = self;
         let doc = doc.as_deref().map(|d| quote_spanned!(*span => #[doc = #d]));
 
         quote_spanned! {*span=>
             #doc
-            #deprecation_attr
+            #(#attrs)*
             #vis fn #fn_ident(&mut self) -> #ty {
                 self.#member.take()
             }
@@ -46,7 +46,7 @@ impl<'a> Take<'a> {
         let fn_ident = query.fn_ident()?;
         let doc = query.docs(false);
         let member = query.member();
-        let deprecation_attr = query.deprecation_attr();
+        let attrs = query.attrs();
 
         Some(Self {
             doc,
@@ -55,7 +55,7 @@ impl<'a> Take<'a> {
             ty,
             member,
             vis,
-            deprecation_attr,
+            attrs,
         })
     }
 }
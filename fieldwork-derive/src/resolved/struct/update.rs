@@ -0,0 +1,74 @@
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote_spanned;
+use std::borrow::Cow;
+use syn::{Attribute, Expr, Ident, Type, Visibility};
+
+use crate::Query;
+
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub(crate) struct Update<'a> {
+    doc: Option<Cow<'a, str>>,
+    fn_ident: Cow<'a, Ident>,
+    span: Span,
+    ty: Type,
+    vis: Cow<'a, Visibility>,
+    access_expr: Expr,
+    chainable: bool,
+    attrs: Vec<Attribute>,
+}
+
+impl<'a> Update<'a> {
+    pub(crate) fn build(&self) -> TokenStream2 {
+        let Update {
+            doc,
+            fn_ident,
+            span,
+            ty,
+            vis,
+            access_expr,
+            chainable,
+            attrs,
+        } = self;
+        let doc = doc.as_deref().map(|d| quote_spanned!(*span => #[doc = #d]));
+
+        if *chainable {
+            quote_spanned! {*span=>
+                #doc
+                #(#attrs)*
+                #vis fn #fn_ident(&mut self, f: impl FnOnce(#ty)) -> &mut Self {
+                    f(#access_expr);
+                    self
+                }
+            }
+        } else {
+            quote_spanned! {*span=>
+                #doc
+                #(#attrs)*
+                #vis fn #fn_ident(&mut self, f: impl FnOnce(#ty)) {
+                    f(#access_expr);
+                }
+            }
+        }
+    }
+
+    pub(crate) fn from_query(query: &Query<'a>) -> Option<Self> {
+        let span = query.span();
+        let vis = query.vis();
+        let fn_ident = query.fn_ident()?;
+        let chainable = query.chainable_set();
+        let doc = query.docs(false);
+        let (access_expr, ty) = query.mut_access_expr_and_type();
+        let attrs = query.attrs();
+
+        Some(Self {
+            doc,
+            fn_ident,
+            span,
+            ty,
+            vis,
+            access_expr,
+            chainable,
+            attrs,
+        })
+    }
+}
@@ -3,7 +3,7 @@ use quote::quote_spanned;
 use std::borrow::Cow;
 use syn::{Attribute, Expr, Ident, Member, Type, Visibility};
 
-use crate::Query;
+use crate::{Query, common_settings::must_use_attr};
 
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub(crate) struct With<'a> {
@@ -14,7 +14,9 @@ pub(crate) struct With<'a> {
     pub(crate) span: Span,
     pub(crate) member: &'a Member,
     pub(crate) vis: Cow<'a, Visibility>,
-    pub(crate) deprecation_attr: Option<Attribute>,
+    pub(crate) attrs: Vec<Attribute>,
+    /// Validation statements and error type, for `try_with_*` setters.
+    pub(crate) validation: Option<(TokenStream2, &'a Type)>,
 }
 
 impl<'a> With<'a> {
@@ -27,14 +29,29 @@ impl<'a> With<'a> {
             doc,
             assigned_value,
             span,
-            deprecation_attr,
+            attrs,
+            validation,
         } = self;
         let doc = doc.as_deref().map(|d| quote_spanned!(*span => #[doc = #d]));
-        if let Some((argument_ident, argument_ty)) = argument_ident_and_ty {
+        let must_use = must_use_attr(attrs);
+        if let Some((validation, error_ty)) = validation {
+            let argument = argument_ident_and_ty
+                .as_ref()
+                .map(|(argument_ident, argument_ty)| quote_spanned!(*span => , #argument_ident: #argument_ty));
             quote_spanned! {*span=>
                 #doc
-                #deprecation_attr
-                #[must_use]
+                #(#attrs)*
+                #vis fn #fn_ident(mut self #argument) -> Result<Self, #error_ty> {
+                    #validation
+                    self.#member = #assigned_value;
+                    Ok(self)
+                }
+            }
+        } else if let Some((argument_ident, argument_ty)) = argument_ident_and_ty {
+            quote_spanned! {*span=>
+                #doc
+                #(#attrs)*
+                #must_use
                 #vis fn #fn_ident(mut self, #argument_ident: #argument_ty) -> Self {
                     self.#member = #assigned_value;
                     self
@@ -43,8 +60,8 @@ impl<'a> With<'a> {
         } else {
             quote_spanned! {*span=>
                 #doc
-                #deprecation_attr
-                #[must_use]
+                #(#attrs)*
+                #must_use
                 #vis fn #fn_ident(mut self) -> Self {
                     self.#member = #assigned_value;
                     self
@@ -61,8 +78,11 @@ impl<'a> With<'a> {
         let argument_ident = query.argument_ident()?;
         let (argument_ty, assigned_value) =
             query.determine_argument_ty_and_assigned_value(&argument_ident)?;
+        let (validation, assigned_value) =
+            query.validate_assigned_value(&argument_ident, assigned_value);
+        let validation = validation.zip(query.validation().map(|(_, error_ty)| error_ty));
         let doc = query.docs(false);
-        let deprecation_attr = query.deprecation_attr();
+        let attrs = query.attrs();
 
         let argument_ident_and_ty = argument_ty.map(|ty| (argument_ident, ty));
 
@@ -74,7 +94,8 @@ impl<'a> With<'a> {
             span,
             member,
             vis,
-            deprecation_attr,
+            attrs,
+            validation,
         })
     }
 }
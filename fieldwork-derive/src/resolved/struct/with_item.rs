@@ -0,0 +1,74 @@
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote_spanned;
+use std::borrow::Cow;
+use syn::{Attribute, Ident, Member, Visibility};
+
+use crate::{Query, common_settings::must_use_attr};
+
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub(crate) struct WithItem<'a> {
+    parameters: TokenStream2,
+    arguments: TokenStream2,
+    add_method: Ident,
+    doc: Option<Cow<'a, str>>,
+    fn_ident: Cow<'a, Ident>,
+    span: Span,
+    member: &'a Member,
+    vis: Cow<'a, Visibility>,
+    attrs: Vec<Attribute>,
+}
+
+impl<'a> WithItem<'a> {
+    pub(crate) fn build(&self) -> TokenStream2 {
+        let WithItem {
+            parameters,
+            arguments,
+            add_method,
+            doc,
+            fn_ident,
+            span,
+            member,
+            vis,
+            attrs,
+        } = self;
+
+        let doc = doc.as_deref().map(|d| quote_spanned!(*span => #[doc = #d]));
+
+        let must_use = must_use_attr(attrs);
+
+        quote_spanned! {*span=>
+            #doc
+            #(#attrs)*
+            #must_use
+            #vis fn #fn_ident(mut self, #parameters) -> Self {
+                self.#member.#add_method(#arguments);
+                self
+            }
+        }
+    }
+
+    pub(crate) fn from_query(query: &Query<'a>) -> Option<Self> {
+        let (collection, _, _) = query.collection()?;
+        let span = query.span();
+        let vis = query.vis();
+        let fn_ident = query.fn_ident()?;
+        let member = query.member();
+        let argument_ident = query.argument_ident()?;
+        let (parameters, arguments) = query.collection_parameters_and_arguments(&argument_ident)?;
+        let add_method = Ident::new(collection.add_method(), span);
+        let doc = query.docs(false);
+        let attrs = query.attrs();
+
+        Some(Self {
+            parameters,
+            arguments,
+            add_method,
+            doc,
+            fn_ident,
+            span,
+            member,
+            vis,
+            attrs,
+        })
+    }
+}
@@ -3,7 +3,7 @@ use quote::quote_spanned;
 use std::borrow::Cow;
 use syn::{Attribute, Expr, Ident, Member, Visibility};
 
-use crate::Query;
+use crate::{Query, common_settings::must_use_attr};
 
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub(crate) struct Without<'a> {
@@ -13,7 +13,7 @@ pub(crate) struct Without<'a> {
     pub(crate) span: Span,
     pub(crate) member: &'a Member,
     pub(crate) vis: Cow<'a, Visibility>,
-    pub(crate) deprecation_attr: Option<Attribute>,
+    pub(crate) attrs: Vec<Attribute>,
 }
 
 impl<'a> Without<'a> {
@@ -25,14 +25,15 @@ impl<'a> Without<'a> {
             doc,
             assigned_value,
             span,
-            deprecation_attr,
+            attrs,
         } = self;
         let doc = doc.as_deref().map(|d| quote_spanned!(*span => #[doc = #d]));
+        let must_use = must_use_attr(attrs);
 
         quote_spanned! {*span=>
             #doc
-            #deprecation_attr
-            #[must_use]
+            #(#attrs)*
+            #must_use
             #vis fn #fn_ident(mut self) -> Self {
                 self.#member = #assigned_value;
                 self
@@ -49,7 +50,7 @@ impl<'a> Without<'a> {
         let (_, assigned_value) =
             query.determine_argument_ty_and_assigned_value(&argument_ident)?;
         let doc = query.docs(false);
-        let deprecation_attr = query.deprecation_attr();
+        let attrs = query.attrs();
 
         Some(Self {
             assigned_value,
@@ -58,7 +59,7 @@ impl<'a> Without<'a> {
             span,
             member,
             vis,
-            deprecation_attr,
+            attrs,
         })
     }
 }
@@ -26,6 +26,24 @@ impl Parse for Struct {
         };
         let ident = input.ident;
         let mut attributes = ItemAttributes::build(&input.attrs)?;
+        if let Some(span) = attributes.variants {
+            return Err(Error::new(span, "`variants` is only supported on enums"));
+        }
+        if let Some(span) = attributes.kind {
+            return Err(Error::new(span, "`kind` is only supported on enums"));
+        }
+        if let Some(name) = attributes.positional.values().next() {
+            return Err(Error::new(
+                name.span(),
+                "`positional` is only supported on enums",
+            ));
+        }
+        if let Some(transition) = attributes.transitions.first() {
+            return Err(Error::new(
+                transition.span,
+                "`transition` is only supported on enums",
+            ));
+        }
         let fields = ds
             .fields
             .iter()
@@ -0,0 +1,301 @@
+use proc_macro2::TokenStream;
+use quote::{ToTokens, format_ident, quote, quote_spanned};
+use syn::{Attribute, Error, Fields, Ident, Member, Type, Visibility, spanned::Spanned};
+
+use crate::{
+    CommonSettings, Enum, Variants,
+    common_settings::{Vis, passthrough_attrs},
+    r#enum::EnumVariant,
+    item_attributes::TransitionSettings,
+    variants::snake_case,
+};
+
+/// The item-level `transition(from = A, to = B)` methods for enums, which move a value from one
+/// variant to another, carrying over the fields the two share and taking the rest as arguments.
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub(crate) struct Transitions<'a> {
+    transitions: Vec<Transition<'a>>,
+    common_settings: &'a CommonSettings,
+}
+
+#[cfg_attr(feature = "debug", derive(Debug))]
+struct Transition<'a> {
+    fn_ident: Ident,
+    from: &'a EnumVariant,
+    to: &'a EnumVariant,
+    /// The target's fields in declaration order, each either carried over from the source or
+    /// taken as an argument.
+    fields: Vec<TransitionField<'a>>,
+}
+
+#[cfg_attr(feature = "debug", derive(Debug))]
+struct TransitionField<'a> {
+    member: Member,
+    binding: Ident,
+    ty: &'a Type,
+    /// The source member this field is carried over from, if it's shared.
+    source: Option<Member>,
+}
+
+impl<'a> Transitions<'a> {
+    pub(crate) fn new(enum_item: &'a Enum) -> syn::Result<Option<Self>> {
+        let attributes = &enum_item.attributes;
+        if attributes.transitions.is_empty() {
+            return Ok(None);
+        }
+
+        let transitions = attributes
+            .transitions
+            .iter()
+            .map(|settings| Transition::new(enum_item, settings))
+            .collect::<syn::Result<Vec<_>>>()?;
+
+        for (index, transition) in transitions.iter().enumerate() {
+            if let Some(earlier) = transitions[..index]
+                .iter()
+                .find(|earlier| earlier.fn_ident == transition.fn_ident)
+            {
+                let mut err = Error::new(
+                    transition.fn_ident.span(),
+                    format!(
+                        "duplicate transition method `{}`; use `name = ...` to tell them apart",
+                        transition.fn_ident
+                    ),
+                );
+                err.combine(Error::new(earlier.fn_ident.span(), "first defined here"));
+                return Err(err);
+            }
+        }
+
+        if let Some(variants) = Variants::new(enum_item) {
+            let variant_methods = variants.method_idents();
+            if let Some(transition) = transitions
+                .iter()
+                .find(|transition| variant_methods.contains(&transition.fn_ident))
+            {
+                return Err(Error::new(
+                    transition.fn_ident.span(),
+                    format!(
+                        "`{}` is also generated by `variants`; use `name = ...` to name this \
+                         transition",
+                        transition.fn_ident
+                    ),
+                ));
+            }
+        }
+
+        Ok(Some(Self {
+            transitions,
+            common_settings: &attributes.common_settings,
+        }))
+    }
+
+    /// The names of the transition methods.
+    pub(crate) fn method_idents(&self) -> impl Iterator<Item = &Ident> {
+        self.transitions
+            .iter()
+            .map(|transition| &transition.fn_ident)
+    }
+
+    pub(crate) fn methods(&self) -> TokenStream {
+        let vis = self
+            .common_settings
+            .vis
+            .as_ref()
+            .unwrap_or(&Vis::Default)
+            .as_visibility();
+        let attrs = passthrough_attrs([self.common_settings]);
+        self.transitions
+            .iter()
+            .map(|transition| transition.method(&vis, &attrs))
+            .collect()
+    }
+}
+
+impl<'a> Transition<'a> {
+    fn new(enum_item: &'a Enum, settings: &TransitionSettings) -> syn::Result<Self> {
+        let find_variant = |ident: &Ident| {
+            enum_item
+                .variants
+                .iter()
+                .find(|variant| &variant.ident == ident)
+                .ok_or_else(|| {
+                    Error::new(
+                        ident.span(),
+                        format!("no variant `{ident}` in `{}`", enum_item.ident),
+                    )
+                })
+        };
+        let from = find_variant(&settings.from)?;
+        let to = find_variant(&settings.to)?;
+        if from.ident == to.ident {
+            return Err(Error::new(
+                settings.span,
+                "`transition` needs two different variants",
+            ));
+        }
+
+        let fn_ident = settings.fn_ident.clone().unwrap_or_else(|| {
+            let name =
+                to.attributes.rename.clone().unwrap_or_else(|| {
+                    Ident::new(&snake_case(&to.ident.to_string()), to.ident.span())
+                });
+            format_ident!("into_{name}", span = settings.to.span())
+        });
+
+        let fields = to
+            .shape
+            .iter()
+            .enumerate()
+            .map(|(index, syn_field)| {
+                let member = syn_field
+                    .ident
+                    .clone()
+                    .map_or_else(|| Member::from(index), Member::Named);
+                let Some(target) = to.fields.iter().find(|field| field.member == member) else {
+                    return Ok(TransitionField {
+                        binding: format_ident!("field_{index}", span = syn_field.span()),
+                        member,
+                        ty: &syn_field.ty,
+                        source: None,
+                    });
+                };
+
+                // Fields are shared when they have the same binding, as in the virtual-field
+                // grouping of `Enum::named_fields`.
+                let source = from
+                    .fields
+                    .iter()
+                    .find(|field| field.binding() == target.binding());
+                if let Some(source) = source
+                    && source.ty != target.ty
+                {
+                    let mut err = Error::new(
+                        target.span,
+                        format!(
+                            "field `{}` has type `{}` in variant `{}`, but type `{}` in \
+                             variant `{}`, so `{fn_ident}` cannot carry it over",
+                            target.binding(),
+                            target.ty.to_token_stream(),
+                            to.ident,
+                            source.ty.to_token_stream(),
+                            from.ident,
+                        ),
+                    );
+                    err.combine(Error::new(source.span, "source field here"));
+                    return Err(err);
+                }
+
+                Ok(TransitionField {
+                    member,
+                    binding: target.binding().clone(),
+                    ty: &syn_field.ty,
+                    source: source.map(|source| source.member.clone()),
+                })
+            })
+            .collect::<syn::Result<_>>()?;
+
+        Ok(Self {
+            fn_ident,
+            from,
+            to,
+            fields,
+        })
+    }
+
+    fn method(&self, vis: &Visibility, attrs: &[&Attribute]) -> TokenStream {
+        let Self {
+            fn_ident,
+            from,
+            to,
+            fields,
+        } = self;
+        let from_ident = &from.ident;
+        let to_ident = &to.ident;
+        let span = fn_ident.span();
+
+        let (carried, arguments): (Vec<_>, Vec<_>) =
+            fields.iter().partition(|field| field.source.is_some());
+        let source_pattern = match &from.shape {
+            Fields::Named(_) => {
+                let bindings = carried.iter().map(|field| {
+                    let binding = &field.binding;
+                    match &field.source {
+                        Some(Member::Named(member)) if member == binding => quote!(#binding),
+                        source => quote!(#source: #binding),
+                    }
+                });
+                quote!(Self::#from_ident { #(#bindings,)* .. })
+            }
+            Fields::Unnamed(_) => {
+                let mut bindings = vec![quote!(_); from.shape.len()];
+                for field in &carried {
+                    if let Some(Member::Unnamed(index)) = &field.source {
+                        bindings[index.index as usize] = field.binding.to_token_stream();
+                    }
+                }
+                let last = bindings
+                    .iter()
+                    .rposition(|binding| binding.to_string() != "_");
+                bindings.truncate(last.map_or(0, |last| last + 1));
+                quote!(Self::#from_ident(#(#bindings,)* ..))
+            }
+            Fields::Unit => quote!(Self::#from_ident),
+        };
+
+        let target = match &to.shape {
+            Fields::Named(_) => {
+                let values = fields.iter().map(|field| {
+                    let binding = &field.binding;
+                    match &field.member {
+                        Member::Named(member) if member == binding => quote!(#binding),
+                        member => quote!(#member: #binding),
+                    }
+                });
+                quote!(Self::#to_ident { #(#values),* })
+            }
+            Fields::Unnamed(_) => {
+                let values = fields.iter().map(|field| &field.binding);
+                quote!(Self::#to_ident(#(#values),*))
+            }
+            Fields::Unit => quote!(Self::#to_ident),
+        };
+
+        let arguments = arguments.iter().map(|field| {
+            let TransitionField { binding, ty, .. } = field;
+            quote!(#binding: #ty)
+        });
+        let doc = if carried.is_empty() {
+            format!(
+                "Moves `Self::{from_ident}` to `Self::{to_ident}`, or returns `Err(self)` if \
+                 this is another variant"
+            )
+        } else {
+            let mut carried: Vec<_> = carried
+                .iter()
+                .map(|field| format!("`{}`", field.binding))
+                .collect();
+            let last = carried.pop().unwrap_or_default();
+            let carried = if carried.is_empty() {
+                last
+            } else {
+                format!("{} and {last}", carried.join(", "))
+            };
+            format!(
+                "Moves `Self::{from_ident}` to `Self::{to_ident}`, carrying over {carried}, or \
+                 returns `Err(self)` if this is another variant"
+            )
+        };
+
+        quote_spanned! {span=>
+            #[doc = #doc]
+            #(#attrs)*
+            #vis fn #fn_ident(self, #(#arguments),*) -> Result<Self, Self> {
+                match self {
+                    #source_pattern => Ok(#target),
+                    other => Err(other),
+                }
+            }
+        }
+    }
+}
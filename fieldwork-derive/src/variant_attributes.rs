@@ -0,0 +1,312 @@
+use crate::{
+    CommonSettings, Method, MethodSettings,
+    errors::{check_duplicate_keys, expr_key, invalid_key},
+    field_attributes::raw_keyword_keys,
+    with_common_settings, with_methods,
+};
+use proc_macro2::Span;
+use quote::ToTokens;
+use syn::{
+    Attribute, Error, Expr, ExprAssign, ExprCall, ExprLit, ExprPath, Ident, Lit, LitBool, LitStr,
+    Meta, MetaNameValue, Path, Type, TypePath, ext::IdentExt, parse::Parser,
+    punctuated::Punctuated, spanned::Spanned, token::Comma,
+};
+
+// this represents the configuration passed to #[variant] on an enum variant
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub(crate) struct VariantAttributes {
+    /// The name used in place of the variant's for its methods, such as `is_{name}`.
+    pub(crate) rename: Option<Ident>,
+    pub(crate) deref: Option<Type>,
+    /// `variants = false` leaves the variant out of the `variants` helpers alone.
+    pub(crate) variants: Option<bool>,
+    pub(crate) method_attributes: MethodSettings<(Span, VariantMethodAttributes)>,
+
+    pub(crate) common_settings: CommonSettings,
+}
+
+// this represents the configuration for every field of a variant, for a particular method
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub(crate) struct VariantMethodAttributes {
+    pub(crate) deref: Option<Type>,
+
+    pub(crate) common_settings: CommonSettings,
+}
+
+impl VariantAttributes {
+    const VALID_KEYS: &[&str] = with_methods!(with_common_settings!("rename", "variants",));
+
+    pub(crate) fn build(attributes: &[Attribute]) -> syn::Result<Self> {
+        let mut variant_attributes = Self::default();
+        let mut list = Punctuated::<Expr, Comma>::new();
+        let mut keys = Vec::new();
+        for attr in attributes.iter().filter(|attr| is_variant_attr(attr)) {
+            match &attr.meta {
+                Meta::List(meta_list) => {
+                    let exprs = Punctuated::<Expr, Comma>::parse_terminated
+                        .parse2(raw_keyword_keys(meta_list.tokens.clone()))?;
+                    keys.push(exprs.iter().filter_map(expr_key).collect());
+                    list.extend(exprs);
+                }
+
+                Meta::NameValue(MetaNameValue {
+                    value:
+                        Expr::Lit(ExprLit {
+                            lit: Lit::Str(value),
+                            ..
+                        }),
+                    ..
+                }) => {
+                    keys.push(vec![(String::from("rename"), value.span())]);
+                    variant_attributes.rename = Some(value.parse()?);
+                }
+
+                meta => return Err(Error::new(meta.span(), "unexpected attribute format")),
+            }
+        }
+
+        check_duplicate_keys(keys, &["attr"])?;
+        variant_attributes.handle_list(&list)?;
+
+        Ok(variant_attributes)
+    }
+
+    /// Returns `true` if this variant opts its fields into `method` with a `#[variant(method)]`
+    /// or `#[variant(method(...))]` that isn't a skip.
+    pub(crate) fn opts_into(&self, method: Method) -> bool {
+        self.method_attributes
+            .retrieve(method)
+            .is_some_and(|(_, vma)| !vma.common_settings.skip)
+    }
+
+    /// Returns `true` if this variant's fields should not get `method`, either because the
+    /// whole variant is skipped or because it skips that method.
+    pub(crate) fn skips(&self, method: Method) -> bool {
+        self.common_settings.skip
+            || self
+                .method_attributes
+                .retrieve(method)
+                .is_some_and(|(_, vma)| vma.common_settings.skip)
+    }
+
+    /// Returns `true` if this variant gets `variants` helpers such as `is_{variant}`.
+    pub(crate) fn in_variants(&self) -> bool {
+        !self.common_settings.skip && self.variants != Some(false)
+    }
+
+    fn handle_list(&mut self, list: &Punctuated<Expr, Comma>) -> syn::Result<()> {
+        for expr in list {
+            match expr {
+                Expr::Assign(assign) => self.handle_assign(assign)?,
+
+                Expr::Path(ExprPath { path, .. }) => self.handle_assign_bool_lit(
+                    path.span(),
+                    &path.require_ident()?.unraw().to_string(),
+                    true,
+                )?,
+
+                Expr::Call(ExprCall { func, args, .. }) => {
+                    let Expr::Path(ExprPath { path: method, .. }) = &**func else {
+                        return Err(invalid_key(
+                            func.span(),
+                            &func.to_token_stream().to_string(),
+                            Self::VALID_KEYS,
+                        ));
+                    };
+                    if method.is_ident("attr") {
+                        self.common_settings.handle_attr(args)?;
+                    } else {
+                        let method = method.try_into().map_err(|_| {
+                            invalid_key(
+                                method.span(),
+                                &method.to_token_stream().to_string(),
+                                Self::VALID_KEYS,
+                            )
+                        })?;
+                        self.method_attributes
+                            .insert(method, (expr.span(), VariantMethodAttributes::build(args)?));
+                    }
+                }
+
+                expr => return Err(Error::new(expr.span(), "not recognized")),
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_assign(&mut self, assign: &ExprAssign) -> syn::Result<()> {
+        let ExprAssign { left, right, .. } = assign;
+        let span = assign.span();
+        let lhs = if let Expr::Path(ExprPath { path: lhs, .. }) = &**left {
+            lhs.get_ident().map(|ident| ident.unraw().to_string())
+        } else {
+            None
+        }
+        .ok_or_else(|| {
+            invalid_key(
+                left.span(),
+                &left.to_token_stream().to_string(),
+                Self::VALID_KEYS,
+            )
+        })?;
+
+        match &**right {
+            Expr::Lit(ExprLit {
+                lit: Lit::Str(rhs), ..
+            }) => self.handle_assign_str_lit(span, &lhs, rhs),
+
+            Expr::Path(ExprPath { path: rhs, .. }) => self.handle_assign_path(span, &lhs, rhs),
+
+            Expr::Lit(ExprLit {
+                lit: Lit::Bool(LitBool { value, .. }),
+                ..
+            }) => self.handle_assign_bool_lit(span, &lhs, *value),
+
+            _ => Err(Error::new(span, "not recognized")),
+        }
+    }
+
+    fn handle_assign_str_lit(&mut self, span: Span, lhs: &str, rhs: &LitStr) -> syn::Result<()> {
+        if !self.common_settings.handle_assign_str_lit(lhs, rhs)? {
+            match lhs {
+                "rename" => self.rename = Some(rhs.parse()?),
+                "deref" => self.deref = Some(rhs.parse()?),
+                _ => return Err(invalid_key(span, lhs, Self::VALID_KEYS)),
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_assign_path(&mut self, span: Span, lhs: &str, rhs: &Path) -> syn::Result<()> {
+        match lhs {
+            "rename" => self.rename = Some(rhs.require_ident().cloned()?),
+            "deref" => self.deref = Some(type_from_path(rhs)),
+            _ => return Err(invalid_key(span, lhs, Self::VALID_KEYS)),
+        }
+        Ok(())
+    }
+
+    fn handle_assign_bool_lit(&mut self, span: Span, lhs: &str, value: bool) -> syn::Result<()> {
+        if self.common_settings.handle_assign_bool_lit(lhs, value) {
+            return Ok(());
+        }
+
+        if lhs == "variants" {
+            self.variants = Some(value);
+            return Ok(());
+        }
+
+        let method = Method::from_str_with_span(lhs, span)
+            .map_err(|_| invalid_key(span, lhs, Self::VALID_KEYS))?;
+        let vma = VariantMethodAttributes {
+            common_settings: CommonSettings {
+                skip: !value,
+                ..CommonSettings::default()
+            },
+            ..VariantMethodAttributes::default()
+        };
+        self.method_attributes.insert(method, (span, vma));
+        Ok(())
+    }
+}
+
+impl VariantMethodAttributes {
+    const VALID_KEYS: &[&str] = with_common_settings!();
+
+    fn build(exprs: &Punctuated<Expr, Comma>) -> syn::Result<Self> {
+        let mut variant_method_attributes = Self::default();
+        for expr in exprs {
+            match expr {
+                Expr::Assign(assign) => variant_method_attributes.handle_assign(assign)?,
+
+                Expr::Path(ExprPath { path, .. }) => variant_method_attributes
+                    .handle_assign_bool_lit(
+                        path.span(),
+                        &path.require_ident()?.unraw().to_string(),
+                        true,
+                    )?,
+
+                Expr::Call(ExprCall { func, args, .. }) => match &**func {
+                    Expr::Path(ExprPath { path, .. }) if path.is_ident("attr") => {
+                        variant_method_attributes
+                            .common_settings
+                            .handle_attr(args)?;
+                    }
+                    func => {
+                        return Err(invalid_key(
+                            func.span(),
+                            &func.to_token_stream().to_string(),
+                            Self::VALID_KEYS,
+                        ));
+                    }
+                },
+
+                expr => return Err(Error::new(expr.span(), "not recognized")),
+            }
+        }
+        Ok(variant_method_attributes)
+    }
+
+    fn handle_assign(&mut self, assign: &ExprAssign) -> syn::Result<()> {
+        let ExprAssign { left, right, .. } = assign;
+        let span = assign.span();
+        let lhs = if let Expr::Path(ExprPath { path: lhs, .. }) = &**left {
+            lhs.get_ident().map(|ident| ident.unraw().to_string())
+        } else {
+            None
+        }
+        .ok_or_else(|| Error::new(span, "not recognized"))?;
+
+        match &**right {
+            Expr::Lit(ExprLit {
+                lit: Lit::Str(rhs), ..
+            }) => {
+                if !self.common_settings.handle_assign_str_lit(&lhs, rhs)? {
+                    match &*lhs {
+                        "deref" => self.deref = Some(rhs.parse()?),
+                        _ => return Err(invalid_key(span, &lhs, Self::VALID_KEYS)),
+                    }
+                }
+                Ok(())
+            }
+
+            Expr::Path(ExprPath { path: rhs, .. }) => {
+                if lhs == "deref" {
+                    self.deref = Some(type_from_path(rhs));
+                    Ok(())
+                } else {
+                    Err(invalid_key(span, &lhs, Self::VALID_KEYS))
+                }
+            }
+
+            Expr::Lit(ExprLit {
+                lit: Lit::Bool(LitBool { value, .. }),
+                ..
+            }) => self.handle_assign_bool_lit(span, &lhs, *value),
+
+            _ => Err(Error::new(span, "not recognized")),
+        }
+    }
+
+    fn handle_assign_bool_lit(&mut self, span: Span, lhs: &str, value: bool) -> syn::Result<()> {
+        if self.common_settings.handle_assign_bool_lit(lhs, value) {
+            Ok(())
+        } else {
+            Err(invalid_key(span, lhs, Self::VALID_KEYS))
+        }
+    }
+}
+
+fn type_from_path(path: &Path) -> Type {
+    Type::Path(TypePath {
+        attrs: Vec::new(),
+        qself: None,
+        path: path.clone(),
+    })
+}
+
+pub(crate) fn is_variant_attr(attr: &Attribute) -> bool {
+    attr.path().is_ident("variant")
+}
@@ -0,0 +1,208 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, quote_spanned};
+use std::borrow::Cow;
+use syn::{Fields, Ident, Visibility, spanned::Spanned};
+
+use crate::{
+    CommonSettings, Enum,
+    common_settings::{Vis, passthrough_attrs},
+    r#enum::EnumVariant,
+};
+
+/// The item-level `variants` methods for enums: `is_{variant}`, and for variants with fields,
+/// `as_{variant}`, `as_{variant}_mut`, and `into_{variant}`, which project the variant's fields
+/// as a reference, a tuple of references, or a tuple of owned values.
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub(crate) struct Variants<'a> {
+    variants: &'a [EnumVariant],
+    common_settings: &'a CommonSettings,
+}
+
+impl<'a> Variants<'a> {
+    pub(crate) fn new(enum_item: &'a Enum) -> Option<Self> {
+        enum_item.attributes.variants?;
+
+        Some(Self {
+            variants: &enum_item.variants,
+            common_settings: &enum_item.attributes.common_settings,
+        })
+    }
+
+    /// The visibility and `attr(...)` attributes of one variant's methods.
+    fn vis_and_attrs(&self, variant: &'a EnumVariant) -> (Cow<'a, Visibility>, TokenStream) {
+        let vis = variant
+            .attributes
+            .common_settings
+            .vis
+            .as_ref()
+            .or(self.common_settings.vis.as_ref())
+            .unwrap_or(&Vis::Default)
+            .as_visibility();
+        let attrs = passthrough_attrs([self.common_settings, &variant.attributes.common_settings]);
+        (vis, quote!(#(#attrs)*))
+    }
+
+    /// The names of every method these generate, so other generated methods can avoid them.
+    pub(crate) fn method_idents(&self) -> Vec<Ident> {
+        self.variants
+            .iter()
+            .filter(|variant| variant.attributes.in_variants())
+            .flat_map(|variant| {
+                let name = variant_name(variant);
+                let mut idents = vec![format_ident!("is_{name}")];
+                if !variant.shape.is_empty() {
+                    idents.extend([
+                        format_ident!("as_{name}"),
+                        format_ident!("as_{name}_mut"),
+                        format_ident!("into_{name}"),
+                    ]);
+                }
+                idents
+            })
+            .collect()
+    }
+
+    pub(crate) fn methods(&self) -> TokenStream {
+        let exhaustive = self.variants.len() == 1;
+        self.variants
+            .iter()
+            .filter(|variant| variant.attributes.in_variants())
+            .map(|variant| self.variant_methods(variant, exhaustive))
+            .collect()
+    }
+
+    /// The methods for one variant. When it's the only variant, matches on it don't need a
+    /// fallback arm.
+    fn variant_methods(&self, variant: &'a EnumVariant, exhaustive: bool) -> TokenStream {
+        let (vis, attrs) = self.vis_and_attrs(variant);
+        let variant_ident = &variant.ident;
+        let span = variant_ident.span();
+        let name = variant_name(variant);
+
+        let is_ident = format_ident!("is_{name}");
+        let is_doc = format!("Returns `true` if this is `Self::{variant_ident}`");
+        let wildcard = match &variant.shape {
+            Fields::Named(_) => quote!(Self::#variant_ident { .. }),
+            Fields::Unnamed(_) => quote!(Self::#variant_ident(..)),
+            Fields::Unit => quote!(Self::#variant_ident),
+        };
+        let is_method = quote_spanned! {span=>
+            #[doc = #is_doc]
+            #attrs
+            #vis fn #is_ident(&self) -> bool {
+                matches!(self, #wildcard)
+            }
+        };
+
+        if variant.shape.is_empty() {
+            return is_method;
+        }
+
+        let bindings: Vec<_> = variant
+            .shape
+            .iter()
+            .enumerate()
+            .map(|(index, field)| {
+                field
+                    .ident
+                    .clone()
+                    .unwrap_or_else(|| format_ident!("field_{index}", span = field.span()))
+            })
+            .collect();
+        let tys: Vec<_> = variant.shape.iter().map(|field| &field.ty).collect();
+        let pattern = if let Fields::Named(_) = &variant.shape {
+            quote!(Self::#variant_ident { #(#bindings),* })
+        } else {
+            quote!(Self::#variant_ident(#(#bindings),*))
+        };
+        let (values, ref_tys, mut_tys, owned_tys) = if let ([binding], [ty]) = (&*bindings, &*tys) {
+            (
+                quote!(#binding),
+                quote!(&#ty),
+                quote!(&mut #ty),
+                quote!(#ty),
+            )
+        } else {
+            (
+                quote!((#(#bindings),*)),
+                quote!((#(&#tys),*)),
+                quote!((#(&mut #tys),*)),
+                quote!((#(#tys),*)),
+            )
+        };
+        let none_arm = (!exhaustive).then(|| quote!(_ => None,));
+        let err_arm = (!exhaustive).then(|| quote!(other => Err(other),));
+
+        let as_ident = format_ident!("as_{name}");
+        let as_mut_ident = format_ident!("as_{name}_mut");
+        let into_ident = format_ident!("into_{name}");
+        let as_doc = format!("Borrows the fields of `Self::{variant_ident}`, if this is one");
+        let as_mut_doc =
+            format!("Mutably borrows the fields of `Self::{variant_ident}`, if this is one");
+        let into_doc = format!(
+            "Consumes self, returning the fields of `Self::{variant_ident}`, or `Err(self)` if \
+             this is another variant"
+        );
+
+        quote_spanned! {span=>
+            #is_method
+
+            #[doc = #as_doc]
+            #attrs
+            #vis fn #as_ident(&self) -> Option<#ref_tys> {
+                match self {
+                    #pattern => Some(#values),
+                    #none_arm
+                }
+            }
+
+            #[doc = #as_mut_doc]
+            #attrs
+            #vis fn #as_mut_ident(&mut self) -> Option<#mut_tys> {
+                match self {
+                    #pattern => Some(#values),
+                    #none_arm
+                }
+            }
+
+            #[doc = #into_doc]
+            #attrs
+            #vis fn #into_ident(self) -> Result<#owned_tys, Self> {
+                match self {
+                    #pattern => Ok(#values),
+                    #err_arm
+                }
+            }
+        }
+    }
+}
+
+/// The variant's `#[variant(rename = ...)]`, or its name in `snake_case`.
+fn variant_name(variant: &EnumVariant) -> Ident {
+    variant.attributes.rename.clone().unwrap_or_else(|| {
+        Ident::new(
+            &snake_case(&variant.ident.to_string()),
+            variant.ident.span(),
+        )
+    })
+}
+
+/// `HttpRequest` and `HTTPRequest` are both `http_request`.
+pub(crate) fn snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut snake = String::new();
+    for (index, c) in chars.iter().enumerate() {
+        if c.is_uppercase() && index > 0 {
+            let previous = chars[index - 1];
+            let next_is_lower = chars.get(index + 1).is_some_and(|next| next.is_lowercase());
+            if previous.is_lowercase()
+                || previous.is_numeric()
+                || (previous.is_uppercase() && next_is_lower)
+            {
+                snake.push('_');
+            }
+        }
+        snake.extend(c.to_lowercase());
+    }
+    snake
+}
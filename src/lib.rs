@@ -20,38 +20,56 @@
 //! | Module | Generated method | Description |
 //! |--------|-----------------|-------------|
 //! | [`get`] | `field_name()` | Borrow or copy a field |
+//! | [`get::clone`] | `field_name_cloned()` | Return an owned clone of an `Arc` or `Rc` field |
 //! | [`set`] | `set_field_name()` | Mutating setter, returns `&mut Self` by default |
 //! | [`get_mut`] | `field_name_mut()` | Mutably borrow a field |
 //! | [`with`] | `with_field_name()` | Owned chainable setter |
 //! | [`without`] | `without_field_name()` | Set bool to `false` or Option to `None` |
 //! | [`take`] | `take_field_name()` | Take the value out of an `Option` field |
 //! | [`into_field`] | `into_field_name()` | Consume `self`, returning an owned field value |
+//! | [`replace`] | `replace_field_name()` | Assign a new value, returning the previous one |
+//! | [`update`] | `update_field_name()` | Mutate a field in place with a closure |
+//! | [`map`] | `map_field_name()` | Owned chainable transformation of a field with a closure |
+//! | [`collections`] | `push_item()`, `extend_items()`, `clear_items()`, `with_item()` | Add to or clear a `Vec`, `VecDeque`, set, or map field |
+//! | [`collections`] | `item()`, `item_mut()`, `insert_item()`, `remove_item()` | Keyed access to a `HashMap` or `BTreeMap` field |
+//! | [`new`] | `new()`, `new_variant_name()` | Constructor taking every field without a `default` |
+//! | [`builder`] | `builder()`, `with_field_name()`, `build()` | A `StructBuilder` that checks required fields when built |
+//! | [`parts`] | `into_parts()`, `from_parts()` | Move every field out of or into the struct at once |
+//! | [`flags`] | `enable_flag()`, `disable_flag()`, `toggle_flag()`, `flag_enabled()` | Switch a `bool` field without an argument |
 //!
 //! ## Enum support
 //!
 //! All method types work on enums. Whether a field appears in all variants or
 //! only some determines the return type and whether setters are generated. See
-//! [`enums`] for the full/partial coverage concept and examples.
+//! [`enums`] for the full/partial coverage concept and examples. [`variants`] adds `is_`, `as_`,
+//! and `into_` methods for each variant, [`kind`] adds a fieldless kind enum and
+//! `variant_name()`, and [`transitions`] moves values between variants.
 //!
 //! ## Configuration
 //!
 //! Fieldwork has four levels of configuration that cascade from broadest to most specific.
 //! See [`configuration`] for a full explanation.
 //!
-//! Options shared across setter methods ([`into`], [`option_set_some`]) are documented at the
-//! top level since they apply equally to [`set`] and [`with`].
+//! Options shared across setter methods ([`into`], [`option_set_some`], [`validate`]) are
+//! documented at the top level since they apply equally to [`set`] and [`with`].
 
 pub use fieldwork_derive::Fieldwork;
 
 #[cfg(doc)]
 #[doc = include_str!("../docs/get.md")]
 pub mod get {
+    #[doc = include_str!("../docs/get/clone.md")]
+    pub mod clone {}
+    #[doc = include_str!("../docs/get/const.md")]
+    pub mod r#const {}
     #[doc = include_str!("../docs/get/copy.md")]
     pub mod copy {}
     #[doc = include_str!("../docs/get/deref.md")]
     pub mod deref {}
     #[doc = include_str!("../docs/get/option_borrow_inner.md")]
     pub mod option_borrow_inner {}
+    #[doc = include_str!("../docs/get/option_predicates.md")]
+    pub mod option_predicates {}
     #[doc = include_str!("../docs/get/rename_predicates.md")]
     pub mod rename_predicates {}
 }
@@ -65,6 +83,8 @@ pub mod set {
     pub mod into {}
     #[doc = include_str!("../docs/option_set_some.md")]
     pub mod option_set_some {}
+    #[doc = include_str!("../docs/validate.md")]
+    pub mod validate {}
 }
 
 #[cfg(doc)]
@@ -83,6 +103,8 @@ pub mod with {
     pub mod into {}
     #[doc = include_str!("../docs/option_set_some.md")]
     pub mod option_set_some {}
+    #[doc = include_str!("../docs/validate.md")]
+    pub mod validate {}
 }
 
 #[cfg(doc)]
@@ -97,6 +119,68 @@ pub mod take {}
 #[doc = include_str!("../docs/into_field.md")]
 pub mod into_field {}
 
+#[cfg(doc)]
+#[doc = include_str!("../docs/replace.md")]
+pub mod replace {
+    #[doc = include_str!("../docs/into.md")]
+    pub mod into {}
+    #[doc = include_str!("../docs/option_set_some.md")]
+    pub mod option_set_some {}
+}
+
+#[cfg(doc)]
+#[doc = include_str!("../docs/update.md")]
+pub mod update {
+    #[doc = include_str!("../docs/set/chain.md")]
+    pub mod chain {}
+    #[doc = include_str!("../docs/get/deref.md")]
+    pub mod deref {}
+    #[doc = include_str!("../docs/get/option_borrow_inner.md")]
+    pub mod option_borrow_inner {}
+}
+
+#[cfg(doc)]
+#[doc = include_str!("../docs/map.md")]
+pub mod map {}
+
+#[cfg(doc)]
+#[doc = include_str!("../docs/collections.md")]
+pub mod collections {
+    #[doc = include_str!("../docs/set/chain.md")]
+    pub mod chain {}
+    #[doc = include_str!("../docs/into.md")]
+    pub mod into {}
+}
+
+#[cfg(doc)]
+#[doc = include_str!("../docs/new.md")]
+pub mod new {
+    #[doc = include_str!("../docs/into.md")]
+    pub mod into {}
+    #[doc = include_str!("../docs/option_set_some.md")]
+    pub mod option_set_some {}
+}
+
+#[cfg(doc)]
+#[doc = include_str!("../docs/builder.md")]
+pub mod builder {
+    #[doc = include_str!("../docs/into.md")]
+    pub mod into {}
+    #[doc = include_str!("../docs/option_set_some.md")]
+    pub mod option_set_some {}
+}
+
+#[cfg(doc)]
+#[doc = include_str!("../docs/parts.md")]
+pub mod parts {}
+
+#[cfg(doc)]
+#[doc = include_str!("../docs/flags.md")]
+pub mod flags {
+    #[doc = include_str!("../docs/set/chain.md")]
+    pub mod chain {}
+}
+
 #[cfg(doc)]
 #[doc = include_str!("../docs/into.md")]
 pub mod into {}
@@ -105,6 +189,10 @@ pub mod into {}
 #[doc = include_str!("../docs/option_set_some.md")]
 pub mod option_set_some {}
 
+#[cfg(doc)]
+#[doc = include_str!("../docs/validate.md")]
+pub mod validate {}
+
 #[cfg(doc)]
 #[doc = include_str!("../docs/deprecate.md")]
 pub mod deprecate {}
@@ -113,6 +201,18 @@ pub mod deprecate {}
 #[doc = include_str!("../docs/enums.md")]
 pub mod enums {}
 
+#[cfg(doc)]
+#[doc = include_str!("../docs/variants.md")]
+pub mod variants {}
+
+#[cfg(doc)]
+#[doc = include_str!("../docs/kind.md")]
+pub mod kind {}
+
+#[cfg(doc)]
+#[doc = include_str!("../docs/transitions.md")]
+pub mod transitions {}
+
 #[cfg(doc)]
 #[doc = include_str!("../docs/configuration.md")]
 pub mod configuration {}
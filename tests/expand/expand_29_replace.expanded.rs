@@ -0,0 +1,67 @@
+#[fieldwork(replace)]
+struct Connection {
+    /// the current state
+    state: String,
+    /// the retry count
+    retries: u32,
+    #[field(replace = false)]
+    id: u64,
+}
+impl Connection {
+    ///Replaces the current state, returning the previous value
+    pub fn replace_state(&mut self, state: String) -> String {
+        std::mem::replace(&mut self.state, state)
+    }
+    ///Replaces the retry count, returning the previous value
+    pub fn replace_retries(&mut self, retries: u32) -> u32 {
+        std::mem::replace(&mut self.retries, retries)
+    }
+}
+#[fieldwork(replace(template = "swap_{}"), into)]
+struct Templated {
+    name: String,
+}
+impl Templated {
+    pub fn swap_name(&mut self, name: impl Into<String>) -> String {
+        std::mem::replace(&mut self.name, name.into())
+    }
+}
+struct FieldLevel {
+    #[field(replace(option_set_some, into))]
+    token: Option<String>,
+    #[field(replace(name = exchange_payload, argument = new_payload))]
+    payload: Vec<u8>,
+    untouched: bool,
+}
+impl FieldLevel {
+    pub fn replace_token(&mut self, token: impl Into<String>) -> Option<String> {
+        std::mem::replace(&mut self.token, Some(token.into()))
+    }
+    pub fn exchange_payload(&mut self, new_payload: Vec<u8>) -> Vec<u8> {
+        std::mem::replace(&mut self.payload, new_payload)
+    }
+}
+/// Enum: replace on full-coverage fields, partial-coverage fields are skipped
+#[fieldwork(replace)]
+enum Machine {
+    Idle { label: String, since: u64 },
+    Running { label: String, since: u64, pid: u32 },
+}
+impl Machine {
+    pub fn replace_label(&mut self, label: String) -> String {
+        match self {
+            Self::Idle { label: label_binding, .. }
+            | Self::Running { label: label_binding, .. } => {
+                std::mem::replace(label_binding, label)
+            }
+        }
+    }
+    pub fn replace_since(&mut self, since: u64) -> u64 {
+        match self {
+            Self::Idle { since: since_binding, .. }
+            | Self::Running { since: since_binding, .. } => {
+                std::mem::replace(since_binding, since)
+            }
+        }
+    }
+}
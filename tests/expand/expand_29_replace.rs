@@ -0,0 +1,33 @@
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(replace)]
+struct Connection {
+    /// the current state
+    state: String,
+    /// the retry count
+    retries: u32,
+    #[field(replace = false)]
+    id: u64,
+}
+
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(replace(template = "swap_{}"), into)]
+struct Templated {
+    name: String,
+}
+
+#[derive(fieldwork::Fieldwork)]
+struct FieldLevel {
+    #[field(replace(option_set_some, into))]
+    token: Option<String>,
+    #[field(replace(name = exchange_payload, argument = new_payload))]
+    payload: Vec<u8>,
+    untouched: bool,
+}
+
+/// Enum: replace on full-coverage fields, partial-coverage fields are skipped
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(replace)]
+enum Machine {
+    Idle { label: String, since: u64 },
+    Running { label: String, since: u64, pid: u32 },
+}
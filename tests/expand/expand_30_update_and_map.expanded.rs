@@ -0,0 +1,169 @@
+#[fieldwork(update, map)]
+struct Counter {
+    /// the current count
+    count: u32,
+    /// the display label
+    label: String,
+    /// optional notes
+    notes: Option<String>,
+    #[field(map = false)]
+    history: Vec<u32>,
+}
+impl Counter {
+    ///Updates the current count in place, returning `&mut Self` for chaining
+    pub fn update_count(&mut self, f: impl FnOnce(&mut u32)) -> &mut Self {
+        f(&mut self.count);
+        self
+    }
+    ///Owned chainable transformation of the current count, returning `Self`
+    #[must_use]
+    pub fn map_count(mut self, f: impl FnOnce(u32) -> u32) -> Self {
+        self.count = f(self.count);
+        self
+    }
+    ///Updates the display label in place, returning `&mut Self` for chaining
+    pub fn update_label(&mut self, f: impl FnOnce(&mut String)) -> &mut Self {
+        f(&mut self.label);
+        self
+    }
+    ///Owned chainable transformation of the display label, returning `Self`
+    #[must_use]
+    pub fn map_label(mut self, f: impl FnOnce(String) -> String) -> Self {
+        self.label = f(self.label);
+        self
+    }
+    ///Updates optional notes in place, returning `&mut Self` for chaining
+    pub fn update_notes(&mut self, f: impl FnOnce(&mut Option<String>)) -> &mut Self {
+        f(&mut self.notes);
+        self
+    }
+    ///Owned chainable transformation of optional notes, returning `Self`
+    #[must_use]
+    pub fn map_notes(
+        mut self,
+        f: impl FnOnce(Option<String>) -> Option<String>,
+    ) -> Self {
+        self.notes = f(self.notes);
+        self
+    }
+    pub fn update_history(&mut self, f: impl FnOnce(&mut Vec<u32>)) -> &mut Self {
+        f(&mut self.history);
+        self
+    }
+}
+#[fieldwork(update(chain = false), map(template = "transform_{}"))]
+struct Options {
+    name: String,
+    #[field(update(name = edit_flags))]
+    flags: Vec<String>,
+}
+impl Options {
+    pub fn update_name(&mut self, f: impl FnOnce(&mut String)) {
+        f(&mut self.name);
+    }
+    #[must_use]
+    pub fn transform_name(mut self, f: impl FnOnce(String) -> String) -> Self {
+        self.name = f(self.name);
+        self
+    }
+    pub fn edit_flags(&mut self, f: impl FnOnce(&mut Vec<String>)) {
+        f(&mut self.flags);
+    }
+    #[must_use]
+    pub fn transform_flags(
+        mut self,
+        f: impl FnOnce(Vec<String>) -> Vec<String>,
+    ) -> Self {
+        self.flags = f(self.flags);
+        self
+    }
+}
+/// Struct: opting back into the `get_mut` view of the field
+#[fieldwork(update(deref = true, option_borrow_inner = true))]
+struct Borrowed {
+    label: String,
+    notes: Option<String>,
+    samples: [u8; 4],
+}
+impl Borrowed {
+    pub fn update_label(&mut self, f: impl FnOnce(&mut str)) -> &mut Self {
+        f(&mut *self.label);
+        self
+    }
+    pub fn update_notes(&mut self, f: impl FnOnce(Option<&mut str>)) -> &mut Self {
+        f(self.notes.as_deref_mut());
+        self
+    }
+    pub fn update_samples(&mut self, f: impl FnOnce(&mut [u8])) -> &mut Self {
+        f(&mut self.samples[..]);
+        self
+    }
+}
+/// Enum: update and map on full-coverage fields, partial-coverage fields are skipped
+#[fieldwork(update, map)]
+enum Shape {
+    Circle { radius: f64, label: String },
+    Rect { width: f64, height: f64, label: String },
+}
+impl Shape {
+    pub fn update_label(&mut self, f: impl FnOnce(&mut String)) -> &mut Self {
+        match self {
+            Self::Circle { label, .. } => f(label),
+            Self::Rect { label, .. } => f(label),
+        }
+        self
+    }
+    #[must_use]
+    pub fn map_label(self, f: impl FnOnce(String) -> String) -> Self {
+        match self {
+            Self::Circle { radius, label } => {
+                Self::Circle {
+                    radius,
+                    label: f(label),
+                }
+            }
+            Self::Rect { width, height, label } => {
+                Self::Rect {
+                    width,
+                    height,
+                    label: f(label),
+                }
+            }
+        }
+    }
+}
+/// Enum: tuple variants, renamed fields, and a field that would shadow the closure
+#[fieldwork(update, map)]
+enum Message {
+    Ping(#[field = "id"] u64, u8),
+    Data { #[field = "id"] request_id: u64, f: Vec<u8> },
+    Close { f: Vec<u8>, id: u64 },
+}
+impl Message {
+    pub fn update_id(&mut self, f: impl FnOnce(&mut u64)) -> &mut Self {
+        match self {
+            Self::Ping(id, ..) => f(id),
+            Self::Data { request_id: id, .. } => f(id),
+            Self::Close { id, .. } => f(id),
+        }
+        self
+    }
+    #[must_use]
+    pub fn map_id(self, f: impl FnOnce(u64) -> u64) -> Self {
+        match self {
+            Self::Ping(id, field_1) => Self::Ping(f(id), field_1),
+            Self::Data { request_id: id, f: f_binding } => {
+                Self::Data {
+                    request_id: f(id),
+                    f: f_binding,
+                }
+            }
+            Self::Close { f: f_binding, id } => {
+                Self::Close {
+                    f: f_binding,
+                    id: f(id),
+                }
+            }
+        }
+    }
+}
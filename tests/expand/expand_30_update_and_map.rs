@@ -0,0 +1,60 @@
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(update, map)]
+struct Counter {
+    /// the current count
+    count: u32,
+    /// the display label
+    label: String,
+    /// optional notes
+    notes: Option<String>,
+    #[field(map = false)]
+    history: Vec<u32>,
+}
+
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(update(chain = false), map(template = "transform_{}"))]
+struct Options {
+    name: String,
+    #[field(update(name = edit_flags))]
+    flags: Vec<String>,
+}
+
+/// Struct: opting back into the `get_mut` view of the field
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(update(deref = true, option_borrow_inner = true))]
+struct Borrowed {
+    label: String,
+    notes: Option<String>,
+    samples: [u8; 4],
+}
+
+/// Enum: update and map on full-coverage fields, partial-coverage fields are skipped
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(update, map)]
+enum Shape {
+    Circle {
+        radius: f64,
+        label: String,
+    },
+    Rect {
+        width: f64,
+        height: f64,
+        label: String,
+    },
+}
+
+/// Enum: tuple variants, renamed fields, and a field that would shadow the closure
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(update, map)]
+enum Message {
+    Ping(#[field = "id"] u64, u8),
+    Data {
+        #[field = "id"]
+        request_id: u64,
+        f: Vec<u8>,
+    },
+    Close {
+        f: Vec<u8>,
+        id: u64,
+    },
+}
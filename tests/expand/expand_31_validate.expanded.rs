@@ -0,0 +1,156 @@
+pub struct InvalidPort;
+#[automatically_derived]
+impl ::core::fmt::Debug for InvalidPort {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::write_str(f, "InvalidPort")
+    }
+}
+fn check_port(port: &u16) -> Result<(), InvalidPort> {
+    if *port == 0 { Err(InvalidPort) } else { Ok(()) }
+}
+fn check_host(host: &str) -> Result<(), String> {
+    if host.is_empty() { Err("host must not be empty".into()) } else { Ok(()) }
+}
+fn check_nothing<T>(_: &T) -> Result<(), String> {
+    Ok(())
+}
+#[fieldwork(set, with)]
+struct Server {
+    /// the port to listen on
+    #[field(validate = check_port, error = InvalidPort)]
+    port: u16,
+    /// the hostname
+    #[field(into, set(validate = check_host, error = String))]
+    host: String,
+    /// optional tag
+    #[field(option_set_some, with(validate = "check_nothing", error = "String"))]
+    tag: Option<String>,
+    /// whether tls is enabled
+    #[field(validate = check_nothing, error = String)]
+    tls: bool,
+}
+impl Server {
+    ///Sets the port to listen on after validating it, returning `&mut Self` for chaining
+    pub fn try_set_port(&mut self, port: u16) -> Result<&mut Self, InvalidPort> {
+        check_port(&port)?;
+        self.port = port;
+        Ok(self)
+    }
+    ///Owned chainable setter for the port to listen on after validating it, returning `Self`
+    pub fn try_with_port(mut self, port: u16) -> Result<Self, InvalidPort> {
+        check_port(&port)?;
+        self.port = port;
+        Ok(self)
+    }
+    ///Sets the hostname after validating it, returning `&mut Self` for chaining
+    pub fn try_set_host(
+        &mut self,
+        host: impl Into<String>,
+    ) -> Result<&mut Self, String> {
+        let host = host.into();
+        check_host(&host)?;
+        self.host = host;
+        Ok(self)
+    }
+    ///Owned chainable setter for the hostname, returning `Self`
+    #[must_use]
+    pub fn with_host(mut self, host: impl Into<String>) -> Self {
+        self.host = host.into();
+        self
+    }
+    ///Sets optional tag, returning `&mut Self` for chaining
+    pub fn set_tag(&mut self, tag: String) -> &mut Self {
+        self.tag = Some(tag);
+        self
+    }
+    ///Owned chainable setter for optional tag after validating it, returning `Self`
+    pub fn try_with_tag(mut self, tag: String) -> Result<Self, String> {
+        check_nothing(&tag)?;
+        self.tag = Some(tag);
+        Ok(self)
+    }
+    ///Sets whether tls is enabled after validating it, returning `&mut Self` for chaining
+    pub fn try_set_tls(&mut self, tls: bool) -> Result<&mut Self, String> {
+        check_nothing(&tls)?;
+        self.tls = tls;
+        Ok(self)
+    }
+    ///Owned chainable setter for whether tls is enabled after validating it, returning `Self`
+    pub fn try_with_tls(mut self, tls: bool) -> Result<Self, String> {
+        check_nothing(&tls)?;
+        self.tls = tls;
+        Ok(self)
+    }
+}
+#[fieldwork(set(chain = false))]
+struct Unchained {
+    #[field(set(validate = check_port, error = InvalidPort))]
+    port: u16,
+}
+impl Unchained {
+    pub fn try_set_port(&mut self, port: u16) -> Result<(), InvalidPort> {
+        check_port(&port)?;
+        self.port = port;
+        Ok(())
+    }
+}
+/// Enum: validation configured on the annotated occurrence of a virtual field
+#[fieldwork(set, with)]
+enum Listener {
+    Tcp { #[field(validate = check_port, error = InvalidPort)] port: u16, active: bool },
+    Udp {
+        port: u16,
+        #[field(with(validate = check_nothing, error = String))]
+        active: bool,
+    },
+}
+impl Listener {
+    pub fn set_active(&mut self, active: bool) -> &mut Self {
+        match self {
+            Self::Tcp { active: active_binding, .. } => {
+                *active_binding = active;
+            }
+            Self::Udp { active: active_binding, .. } => {
+                *active_binding = active;
+            }
+        }
+        self
+    }
+    pub fn try_with_active(mut self, active: bool) -> Result<Self, String> {
+        check_nothing(&active)?;
+        match &mut self {
+            Self::Tcp { active: active_binding, .. } => {
+                *active_binding = active;
+            }
+            Self::Udp { active: active_binding, .. } => {
+                *active_binding = active;
+            }
+        }
+        Ok(self)
+    }
+    pub fn try_set_port(&mut self, port: u16) -> Result<&mut Self, InvalidPort> {
+        check_port(&port)?;
+        match self {
+            Self::Tcp { port: port_binding, .. } => {
+                *port_binding = port;
+            }
+            Self::Udp { port: port_binding, .. } => {
+                *port_binding = port;
+            }
+        }
+        Ok(self)
+    }
+    pub fn try_with_port(mut self, port: u16) -> Result<Self, InvalidPort> {
+        check_port(&port)?;
+        match &mut self {
+            Self::Tcp { port: port_binding, .. } => {
+                *port_binding = port;
+            }
+            Self::Udp { port: port_binding, .. } => {
+                *port_binding = port;
+            }
+        }
+        Ok(self)
+    }
+}
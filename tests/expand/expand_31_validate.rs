@@ -0,0 +1,61 @@
+#[derive(Debug)]
+pub struct InvalidPort;
+
+fn check_port(port: &u16) -> Result<(), InvalidPort> {
+    if *port == 0 { Err(InvalidPort) } else { Ok(()) }
+}
+
+fn check_host(host: &str) -> Result<(), String> {
+    if host.is_empty() {
+        Err("host must not be empty".into())
+    } else {
+        Ok(())
+    }
+}
+
+fn check_nothing<T>(_: &T) -> Result<(), String> {
+    Ok(())
+}
+
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(set, with)]
+struct Server {
+    /// the port to listen on
+    #[field(validate = check_port, error = InvalidPort)]
+    port: u16,
+
+    /// the hostname
+    #[field(into, set(validate = check_host, error = String))]
+    host: String,
+
+    /// optional tag
+    #[field(option_set_some, with(validate = "check_nothing", error = "String"))]
+    tag: Option<String>,
+
+    /// whether tls is enabled
+    #[field(validate = check_nothing, error = String)]
+    tls: bool,
+}
+
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(set(chain = false))]
+struct Unchained {
+    #[field(set(validate = check_port, error = InvalidPort))]
+    port: u16,
+}
+
+/// Enum: validation configured on the annotated occurrence of a virtual field
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(set, with)]
+enum Listener {
+    Tcp {
+        #[field(validate = check_port, error = InvalidPort)]
+        port: u16,
+        active: bool,
+    },
+    Udp {
+        port: u16,
+        #[field(with(validate = check_nothing, error = String))]
+        active: bool,
+    },
+}
@@ -0,0 +1,212 @@
+use std::collections::{BTreeSet, HashSet, VecDeque};
+#[fieldwork(push, extend, clear, with_item)]
+struct Request {
+    /// request tags
+    tags: Vec<String>,
+    /// pending jobs
+    queue: VecDeque<u32>,
+    /// distinct ids
+    #[field(into)]
+    ids: HashSet<u64>,
+    /// ordered names
+    names: BTreeSet<String>,
+    status: u16,
+    #[field(push(name = add_header), with_item(name = and_header))]
+    headers: Vec<(String, String)>,
+    #[field(into)]
+    data: Vec<u8>,
+}
+impl Request {
+    ///Adds an item to request tags, returning `&mut Self` for chaining
+    pub fn push_tag(&mut self, tag: String) -> &mut Self {
+        self.tags.push(tag);
+        self
+    }
+    ///Extends request tags with the contents of an iterator, returning `&mut Self` for chaining
+    pub fn extend_tags(&mut self, tags: impl IntoIterator<Item = String>) -> &mut Self {
+        self.tags.extend(tags);
+        self
+    }
+    ///Removes all items from request tags, returning `&mut Self` for chaining
+    pub fn clear_tags(&mut self) -> &mut Self {
+        self.tags.clear();
+        self
+    }
+    ///Owned chainable setter that adds an item to request tags, returning `Self`
+    #[must_use]
+    pub fn with_tag(mut self, tag: String) -> Self {
+        self.tags.push(tag);
+        self
+    }
+    ///Adds an item to pending jobs, returning `&mut Self` for chaining
+    pub fn push_queue(&mut self, queue: u32) -> &mut Self {
+        self.queue.push_back(queue);
+        self
+    }
+    ///Extends pending jobs with the contents of an iterator, returning `&mut Self` for chaining
+    pub fn extend_queue(&mut self, queue: impl IntoIterator<Item = u32>) -> &mut Self {
+        self.queue.extend(queue);
+        self
+    }
+    ///Removes all items from pending jobs, returning `&mut Self` for chaining
+    pub fn clear_queue(&mut self) -> &mut Self {
+        self.queue.clear();
+        self
+    }
+    ///Adds an item to distinct ids, returning `&mut Self` for chaining
+    pub fn push_id(&mut self, id: impl Into<u64>) -> &mut Self {
+        self.ids.insert(id.into());
+        self
+    }
+    ///Extends distinct ids with the contents of an iterator, returning `&mut Self` for chaining
+    pub fn extend_ids(
+        &mut self,
+        ids: impl IntoIterator<Item = impl Into<u64>>,
+    ) -> &mut Self {
+        self.ids.extend(ids.into_iter().map(Into::into));
+        self
+    }
+    ///Removes all items from distinct ids, returning `&mut Self` for chaining
+    pub fn clear_ids(&mut self) -> &mut Self {
+        self.ids.clear();
+        self
+    }
+    ///Owned chainable setter that adds an item to distinct ids, returning `Self`
+    #[must_use]
+    pub fn with_id(mut self, id: impl Into<u64>) -> Self {
+        self.ids.insert(id.into());
+        self
+    }
+    ///Adds an item to ordered names, returning `&mut Self` for chaining
+    pub fn push_name(&mut self, name: String) -> &mut Self {
+        self.names.insert(name);
+        self
+    }
+    ///Extends ordered names with the contents of an iterator, returning `&mut Self` for chaining
+    pub fn extend_names(
+        &mut self,
+        names: impl IntoIterator<Item = String>,
+    ) -> &mut Self {
+        self.names.extend(names);
+        self
+    }
+    ///Removes all items from ordered names, returning `&mut Self` for chaining
+    pub fn clear_names(&mut self) -> &mut Self {
+        self.names.clear();
+        self
+    }
+    ///Owned chainable setter that adds an item to ordered names, returning `Self`
+    #[must_use]
+    pub fn with_name(mut self, name: String) -> Self {
+        self.names.insert(name);
+        self
+    }
+    pub fn add_header(&mut self, header: (String, String)) -> &mut Self {
+        self.headers.push(header);
+        self
+    }
+    pub fn extend_headers(
+        &mut self,
+        headers: impl IntoIterator<Item = (String, String)>,
+    ) -> &mut Self {
+        self.headers.extend(headers);
+        self
+    }
+    pub fn clear_headers(&mut self) -> &mut Self {
+        self.headers.clear();
+        self
+    }
+    #[must_use]
+    pub fn and_header(mut self, header: (String, String)) -> Self {
+        self.headers.push(header);
+        self
+    }
+    pub fn push_data(&mut self, data: impl Into<u8>) -> &mut Self {
+        self.data.push(data.into());
+        self
+    }
+    pub fn extend_data(
+        &mut self,
+        data: impl IntoIterator<Item = impl Into<u8>>,
+    ) -> &mut Self {
+        self.data.extend(data.into_iter().map(Into::into));
+        self
+    }
+    pub fn clear_data(&mut self) -> &mut Self {
+        self.data.clear();
+        self
+    }
+}
+#[fieldwork(push(chain = false), extend(chain = false), clear(template = "reset_{}"))]
+struct Unchained {
+    values: Vec<i32>,
+    #[field(push(name = push_entry, argument = entry))]
+    entries: Vec<String>,
+}
+impl Unchained {
+    pub fn push_value(&mut self, value: i32) {
+        self.values.push(value);
+    }
+    pub fn extend_values(&mut self, values: impl IntoIterator<Item = i32>) {
+        self.values.extend(values);
+    }
+    pub fn reset_values(&mut self) -> &mut Self {
+        self.values.clear();
+        self
+    }
+    pub fn push_entry(&mut self, entry: String) {
+        self.entries.push(entry);
+    }
+    pub fn extend_entries(&mut self, entries: impl IntoIterator<Item = String>) {
+        self.entries.extend(entries);
+    }
+    pub fn reset_entries(&mut self) -> &mut Self {
+        self.entries.clear();
+        self
+    }
+}
+/// Enum: collection methods on full-coverage fields, partial-coverage fields are skipped
+#[fieldwork(push, extend, clear, with_item)]
+enum Message {
+    Text { recipients: Vec<String>, body: String },
+    Broadcast { recipients: Vec<String>, channels: Vec<String> },
+}
+impl Message {
+    pub fn push_recipient(&mut self, recipient: String) -> &mut Self {
+        match self {
+            Self::Text { recipients, .. } | Self::Broadcast { recipients, .. } => {
+                recipients.push(recipient);
+            }
+        }
+        self
+    }
+    pub fn extend_recipients(
+        &mut self,
+        recipients: impl IntoIterator<Item = String>,
+    ) -> &mut Self {
+        match self {
+            Self::Text { recipients: recipients_binding, .. }
+            | Self::Broadcast { recipients: recipients_binding, .. } => {
+                recipients_binding.extend(recipients);
+            }
+        }
+        self
+    }
+    pub fn clear_recipients(&mut self) -> &mut Self {
+        match self {
+            Self::Text { recipients, .. } | Self::Broadcast { recipients, .. } => {
+                recipients.clear()
+            }
+        }
+        self
+    }
+    #[must_use]
+    pub fn with_recipient(mut self, recipient: String) -> Self {
+        match &mut self {
+            Self::Text { recipients, .. } | Self::Broadcast { recipients, .. } => {
+                recipients.push(recipient);
+            }
+        }
+        self
+    }
+}
@@ -0,0 +1,54 @@
+use std::collections::{BTreeSet, HashSet, VecDeque};
+
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(push, extend, clear, with_item)]
+struct Request {
+    /// request tags
+    tags: Vec<String>,
+
+    /// pending jobs
+    queue: VecDeque<u32>,
+
+    /// distinct ids
+    #[field(into)]
+    ids: HashSet<u64>,
+
+    /// ordered names
+    names: BTreeSet<String>,
+
+    // not a collection, so no methods are generated
+    status: u16,
+
+    #[field(push(name = add_header), with_item(name = and_header))]
+    headers: Vec<(String, String)>,
+
+    // no derivable singular, so `with_item` is skipped
+    #[field(into)]
+    data: Vec<u8>,
+}
+
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(
+    push(chain = false),
+    extend(chain = false),
+    clear(template = "reset_{}")
+)]
+struct Unchained {
+    values: Vec<i32>,
+    #[field(push(name = push_entry, argument = entry))]
+    entries: Vec<String>,
+}
+
+/// Enum: collection methods on full-coverage fields, partial-coverage fields are skipped
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(push, extend, clear, with_item)]
+enum Message {
+    Text {
+        recipients: Vec<String>,
+        body: String,
+    },
+    Broadcast {
+        recipients: Vec<String>,
+        channels: Vec<String>,
+    },
+}
@@ -0,0 +1,297 @@
+use std::collections::{BTreeMap, HashMap};
+#[fieldwork(get_item, get_item_mut, insert_item, remove_item, with_item, extend, clear)]
+struct Request {
+    /// request headers
+    headers: HashMap<String, String>,
+    /// query parameters
+    #[field(into)]
+    params: BTreeMap<String, Vec<String>>,
+    metadata: HashMap<String, u64>,
+    tags: Vec<String>,
+}
+impl Request {
+    ///Extends request headers with the contents of an iterator, returning `&mut Self` for chaining
+    pub fn extend_headers(
+        &mut self,
+        headers: impl IntoIterator<Item = (String, String)>,
+    ) -> &mut Self {
+        self.headers.extend(headers);
+        self
+    }
+    ///Removes all items from request headers, returning `&mut Self` for chaining
+    pub fn clear_headers(&mut self) -> &mut Self {
+        self.headers.clear();
+        self
+    }
+    ///Owned chainable setter that adds an item to request headers, returning `Self`
+    #[must_use]
+    pub fn with_header(mut self, key: String, value: String) -> Self {
+        self.headers.insert(key, value);
+        self
+    }
+    ///Borrows the value for a key in request headers
+    pub fn header<Q>(&self, key: &Q) -> Option<&String>
+    where
+        String: std::borrow::Borrow<Q>,
+        Q: std::hash::Hash + Eq + ?Sized,
+    {
+        self.headers.get(key)
+    }
+    ///Mutably borrows the value for a key in request headers
+    pub fn header_mut<Q>(&mut self, key: &Q) -> Option<&mut String>
+    where
+        String: std::borrow::Borrow<Q>,
+        Q: std::hash::Hash + Eq + ?Sized,
+    {
+        self.headers.get_mut(key)
+    }
+    ///Inserts a key and value into request headers, returning the previous value for the key
+    pub fn insert_header(&mut self, key: String, value: String) -> Option<String> {
+        self.headers.insert(key, value)
+    }
+    ///Removes a key from request headers, returning its value
+    pub fn remove_header<Q>(&mut self, key: &Q) -> Option<String>
+    where
+        String: std::borrow::Borrow<Q>,
+        Q: std::hash::Hash + Eq + ?Sized,
+    {
+        self.headers.remove(key)
+    }
+    ///Extends query parameters with the contents of an iterator, returning `&mut Self` for chaining
+    pub fn extend_params(
+        &mut self,
+        params: impl IntoIterator<Item = (impl Into<String>, impl Into<Vec<String>>)>,
+    ) -> &mut Self {
+        self.params
+            .extend(params.into_iter().map(|(key, value)| (key.into(), value.into())));
+        self
+    }
+    ///Removes all items from query parameters, returning `&mut Self` for chaining
+    pub fn clear_params(&mut self) -> &mut Self {
+        self.params.clear();
+        self
+    }
+    ///Owned chainable setter that adds an item to query parameters, returning `Self`
+    #[must_use]
+    pub fn with_param(
+        mut self,
+        key: impl Into<String>,
+        value: impl Into<Vec<String>>,
+    ) -> Self {
+        self.params.insert(key.into(), value.into());
+        self
+    }
+    ///Borrows the value for a key in query parameters
+    pub fn param<Q>(&self, key: &Q) -> Option<&Vec<String>>
+    where
+        String: std::borrow::Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.params.get(key)
+    }
+    ///Mutably borrows the value for a key in query parameters
+    pub fn param_mut<Q>(&mut self, key: &Q) -> Option<&mut Vec<String>>
+    where
+        String: std::borrow::Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.params.get_mut(key)
+    }
+    ///Inserts a key and value into query parameters, returning the previous value for the key
+    pub fn insert_param(
+        &mut self,
+        key: impl Into<String>,
+        value: impl Into<Vec<String>>,
+    ) -> Option<Vec<String>> {
+        self.params.insert(key.into(), value.into())
+    }
+    ///Removes a key from query parameters, returning its value
+    pub fn remove_param<Q>(&mut self, key: &Q) -> Option<Vec<String>>
+    where
+        String: std::borrow::Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.params.remove(key)
+    }
+    pub fn extend_metadata(
+        &mut self,
+        metadata: impl IntoIterator<Item = (String, u64)>,
+    ) -> &mut Self {
+        self.metadata.extend(metadata);
+        self
+    }
+    pub fn clear_metadata(&mut self) -> &mut Self {
+        self.metadata.clear();
+        self
+    }
+    pub fn insert_metadata(&mut self, key: String, value: u64) -> Option<u64> {
+        self.metadata.insert(key, value)
+    }
+    pub fn remove_metadata<Q>(&mut self, key: &Q) -> Option<u64>
+    where
+        String: std::borrow::Borrow<Q>,
+        Q: std::hash::Hash + Eq + ?Sized,
+    {
+        self.metadata.remove(key)
+    }
+    pub fn extend_tags(&mut self, tags: impl IntoIterator<Item = String>) -> &mut Self {
+        self.tags.extend(tags);
+        self
+    }
+    pub fn clear_tags(&mut self) -> &mut Self {
+        self.tags.clear();
+        self
+    }
+    #[must_use]
+    pub fn with_tag(mut self, tag: String) -> Self {
+        self.tags.push(tag);
+        self
+    }
+}
+#[fieldwork(get, get_item)]
+struct Registry {
+    /// registered handlers
+    #[field(get_item(name = handler_named))]
+    handlers: HashMap<&'static str, usize>,
+}
+impl Registry {
+    ///Borrows registered handlers
+    pub fn handlers(&self) -> &HashMap<&'static str, usize> {
+        &self.handlers
+    }
+    ///Borrows the value for a key in registered handlers
+    pub fn handler_named<Q>(&self, key: &Q) -> Option<&usize>
+    where
+        &'static str: std::borrow::Borrow<Q>,
+        Q: std::hash::Hash + Eq + ?Sized,
+    {
+        self.handlers.get(key)
+    }
+}
+/// Enum: keyed accessors on full-coverage fields, including a binding that would shadow `value`
+#[fieldwork(get_item, get_item_mut, insert_item, remove_item, with_item)]
+enum Document {
+    Draft {
+        labels: BTreeMap<String, String>,
+        keys: HashMap<u32, String>,
+        value: HashMap<u32, u32>,
+    },
+    Published {
+        labels: BTreeMap<String, String>,
+        keys: HashMap<u32, String>,
+        value: HashMap<u32, u32>,
+    },
+}
+impl Document {
+    #[must_use]
+    pub fn with_key(mut self, key: u32, value: String) -> Self {
+        match &mut self {
+            Self::Draft { keys, .. } | Self::Published { keys, .. } => {
+                keys.insert(key, value);
+            }
+        }
+        self
+    }
+    pub fn key<Q>(&self, key: &Q) -> Option<&String>
+    where
+        u32: std::borrow::Borrow<Q>,
+        Q: std::hash::Hash + Eq + ?Sized,
+    {
+        match self {
+            Self::Draft { keys, .. } | Self::Published { keys, .. } => keys.get(key),
+        }
+    }
+    pub fn key_mut<Q>(&mut self, key: &Q) -> Option<&mut String>
+    where
+        u32: std::borrow::Borrow<Q>,
+        Q: std::hash::Hash + Eq + ?Sized,
+    {
+        match self {
+            Self::Draft { keys, .. } | Self::Published { keys, .. } => keys.get_mut(key),
+        }
+    }
+    pub fn insert_key(&mut self, key: u32, value: String) -> Option<String> {
+        match self {
+            Self::Draft { keys, .. } | Self::Published { keys, .. } => {
+                keys.insert(key, value)
+            }
+        }
+    }
+    pub fn remove_key<Q>(&mut self, key: &Q) -> Option<String>
+    where
+        u32: std::borrow::Borrow<Q>,
+        Q: std::hash::Hash + Eq + ?Sized,
+    {
+        match self {
+            Self::Draft { keys, .. } | Self::Published { keys, .. } => keys.remove(key),
+        }
+    }
+    #[must_use]
+    pub fn with_label(mut self, key: String, value: String) -> Self {
+        match &mut self {
+            Self::Draft { labels, .. } | Self::Published { labels, .. } => {
+                labels.insert(key, value);
+            }
+        }
+        self
+    }
+    pub fn label<Q>(&self, key: &Q) -> Option<&String>
+    where
+        String: std::borrow::Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        match self {
+            Self::Draft { labels, .. } | Self::Published { labels, .. } => {
+                labels.get(key)
+            }
+        }
+    }
+    pub fn label_mut<Q>(&mut self, key: &Q) -> Option<&mut String>
+    where
+        String: std::borrow::Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        match self {
+            Self::Draft { labels, .. } | Self::Published { labels, .. } => {
+                labels.get_mut(key)
+            }
+        }
+    }
+    pub fn insert_label(&mut self, key: String, value: String) -> Option<String> {
+        match self {
+            Self::Draft { labels, .. } | Self::Published { labels, .. } => {
+                labels.insert(key, value)
+            }
+        }
+    }
+    pub fn remove_label<Q>(&mut self, key: &Q) -> Option<String>
+    where
+        String: std::borrow::Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        match self {
+            Self::Draft { labels, .. } | Self::Published { labels, .. } => {
+                labels.remove(key)
+            }
+        }
+    }
+    pub fn insert_value(&mut self, key: u32, value: u32) -> Option<u32> {
+        match self {
+            Self::Draft { value: value_binding, .. }
+            | Self::Published { value: value_binding, .. } => {
+                value_binding.insert(key, value)
+            }
+        }
+    }
+    pub fn remove_value<Q>(&mut self, key: &Q) -> Option<u32>
+    where
+        u32: std::borrow::Borrow<Q>,
+        Q: std::hash::Hash + Eq + ?Sized,
+    {
+        match self {
+            Self::Draft { value, .. } | Self::Published { value, .. } => {
+                value.remove(key)
+            }
+        }
+    }
+}
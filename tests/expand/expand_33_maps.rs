@@ -0,0 +1,50 @@
+use std::collections::{BTreeMap, HashMap};
+
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(
+    get_item,
+    get_item_mut,
+    insert_item,
+    remove_item,
+    with_item,
+    extend,
+    clear
+)]
+struct Request {
+    /// request headers
+    headers: HashMap<String, String>,
+
+    /// query parameters
+    #[field(into)]
+    params: BTreeMap<String, Vec<String>>,
+
+    // no derivable singular, so `get_item`, `get_item_mut` and `with_item` are skipped
+    metadata: HashMap<String, u64>,
+
+    // not a map, so only the methods shared with other collections are generated
+    tags: Vec<String>,
+}
+
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(get, get_item)]
+struct Registry {
+    /// registered handlers
+    #[field(get_item(name = handler_named))]
+    handlers: HashMap<&'static str, usize>,
+}
+
+/// Enum: keyed accessors on full-coverage fields, including a binding that would shadow `value`
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(get_item, get_item_mut, insert_item, remove_item, with_item)]
+enum Document {
+    Draft {
+        labels: BTreeMap<String, String>,
+        keys: HashMap<u32, String>,
+        value: HashMap<u32, u32>,
+    },
+    Published {
+        labels: BTreeMap<String, String>,
+        keys: HashMap<u32, String>,
+        value: HashMap<u32, u32>,
+    },
+}
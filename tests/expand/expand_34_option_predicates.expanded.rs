@@ -0,0 +1,164 @@
+#[fieldwork(get, option_predicates)]
+struct Session {
+    /// the auth token
+    token: Option<String>,
+    /// the user id
+    user_id: u64,
+    #[field(get(name = expiry))]
+    expires_at: Option<u64>,
+    #[field(option_predicates = false)]
+    refresh: Option<String>,
+}
+impl Session {
+    ///Borrows the auth token
+    pub fn token(&self) -> Option<&str> {
+        self.token.as_deref()
+    }
+    /**Returns `true` if `token` is `Some`
+
+the auth token*/
+    pub fn has_token(&self) -> bool {
+        self.token.is_some()
+    }
+    ///Returns a copy of the user id
+    pub fn user_id(&self) -> u64 {
+        self.user_id
+    }
+    pub fn expiry(&self) -> Option<u64> {
+        self.expires_at
+    }
+    pub fn has_expiry(&self) -> bool {
+        self.expires_at.is_some()
+    }
+    pub fn refresh(&self) -> Option<&str> {
+        self.refresh.as_deref()
+    }
+}
+#[fieldwork(get)]
+struct Opted {
+    #[field(option_predicates)]
+    parent: Option<usize>,
+    child: Option<usize>,
+}
+impl Opted {
+    pub fn parent(&self) -> Option<usize> {
+        self.parent
+    }
+    pub fn has_parent(&self) -> bool {
+        self.parent.is_some()
+    }
+    pub fn child(&self) -> Option<usize> {
+        self.child
+    }
+}
+/// Enum: predicates are false for variants that lack the field
+#[fieldwork(get, option_predicates)]
+enum Event {
+    Click {
+        /// the element under the pointer
+        target: Option<String>,
+        x: i32,
+    },
+    Key {
+        target: Option<String>,
+        /// the key code
+        code: u32,
+    },
+    Scroll { delta: f64, x: i32 },
+}
+impl Event {
+    ///Borrows the key code
+    pub fn code(&self) -> Option<u32> {
+        match self {
+            Self::Key { code, .. } => Some(*code),
+            _ => None,
+        }
+    }
+    /**Returns `true` if this variant has `code`
+
+the key code*/
+    pub fn has_code(&self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Key { .. } => true,
+            _ => false,
+        }
+    }
+    pub fn delta(&self) -> Option<f64> {
+        match self {
+            Self::Scroll { delta, .. } => Some(*delta),
+            _ => None,
+        }
+    }
+    pub fn has_delta(&self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Scroll { .. } => true,
+            _ => false,
+        }
+    }
+    ///Borrows the element under the pointer
+    pub fn target(&self) -> Option<&str> {
+        match self {
+            Self::Click { target, .. } => target.as_deref(),
+            Self::Key { target, .. } => target.as_deref(),
+            _ => None,
+        }
+    }
+    /**Returns `true` if this variant has `target` and it is `Some`
+
+the element under the pointer*/
+    pub fn has_target(&self) -> bool {
+        match self {
+            Self::Click { target, .. } | Self::Key { target, .. } => target.is_some(),
+            _ => false,
+        }
+    }
+    pub fn x(&self) -> Option<i32> {
+        match self {
+            Self::Click { x, .. } => Some(*x),
+            Self::Scroll { x, .. } => Some(*x),
+            _ => None,
+        }
+    }
+    pub fn has_x(&self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Click { .. } | Self::Scroll { .. } => true,
+            _ => false,
+        }
+    }
+}
+#[fieldwork(get, option_predicates)]
+enum Shape {
+    Circle(#[field = "radius"] f64, Option<String>),
+    Square(#[field = "side"] f64),
+}
+impl Shape {
+    pub fn radius(&self) -> Option<f64> {
+        match self {
+            Self::Circle(radius, ..) => Some(*radius),
+            _ => None,
+        }
+    }
+    pub fn has_radius(&self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Circle(..) => true,
+            _ => false,
+        }
+    }
+    pub fn side(&self) -> Option<f64> {
+        match self {
+            Self::Square(side, ..) => Some(*side),
+            _ => None,
+        }
+    }
+    pub fn has_side(&self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Square(..) => true,
+            _ => false,
+        }
+    }
+}
@@ -0,0 +1,51 @@
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(get, option_predicates)]
+struct Session {
+    /// the auth token
+    token: Option<String>,
+
+    /// the user id
+    user_id: u64,
+
+    #[field(get(name = expiry))]
+    expires_at: Option<u64>,
+
+    #[field(option_predicates = false)]
+    refresh: Option<String>,
+}
+
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(get)]
+struct Opted {
+    #[field(option_predicates)]
+    parent: Option<usize>,
+
+    child: Option<usize>,
+}
+
+/// Enum: predicates are false for variants that lack the field
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(get, option_predicates)]
+enum Event {
+    Click {
+        /// the element under the pointer
+        target: Option<String>,
+        x: i32,
+    },
+    Key {
+        target: Option<String>,
+        /// the key code
+        code: u32,
+    },
+    Scroll {
+        delta: f64,
+        x: i32,
+    },
+}
+
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(get, option_predicates)]
+enum Shape {
+    Circle(#[field = "radius"] f64, Option<String>),
+    Square(#[field = "side"] f64),
+}
@@ -0,0 +1,174 @@
+/// Struct: flag methods for bool fields; non-bool fields are ignored
+#[fieldwork(enable, disable, toggle, enabled, disabled, toggled)]
+struct Features {
+    /// whether verbose output is on
+    verbose: bool,
+    color: bool,
+    level: u8,
+}
+impl Features {
+    ///Sets whether verbose output is on to `true`, returning `&mut Self` for chaining
+    pub fn enable_verbose(&mut self) -> &mut Self {
+        self.verbose = true;
+        self
+    }
+    ///Sets whether verbose output is on to `false`, returning `&mut Self` for chaining
+    pub fn disable_verbose(&mut self) -> &mut Self {
+        self.verbose = false;
+        self
+    }
+    ///Toggles whether verbose output is on, returning `&mut Self` for chaining
+    pub fn toggle_verbose(&mut self) -> &mut Self {
+        self.verbose = !self.verbose;
+        self
+    }
+    ///Owned chainable setter that sets whether verbose output is on to `true`, returning `Self`
+    #[must_use]
+    pub fn verbose_enabled(mut self) -> Self {
+        self.verbose = true;
+        self
+    }
+    ///Owned chainable setter that sets whether verbose output is on to `false`, returning `Self`
+    #[must_use]
+    pub fn verbose_disabled(mut self) -> Self {
+        self.verbose = false;
+        self
+    }
+    ///Owned chainable setter that toggles whether verbose output is on, returning `Self`
+    #[must_use]
+    pub fn verbose_toggled(mut self) -> Self {
+        self.verbose = !self.verbose;
+        self
+    }
+    pub fn enable_color(&mut self) -> &mut Self {
+        self.color = true;
+        self
+    }
+    pub fn disable_color(&mut self) -> &mut Self {
+        self.color = false;
+        self
+    }
+    pub fn toggle_color(&mut self) -> &mut Self {
+        self.color = !self.color;
+        self
+    }
+    #[must_use]
+    pub fn color_enabled(mut self) -> Self {
+        self.color = true;
+        self
+    }
+    #[must_use]
+    pub fn color_disabled(mut self) -> Self {
+        self.color = false;
+        self
+    }
+    #[must_use]
+    pub fn color_toggled(mut self) -> Self {
+        self.color = !self.color;
+        self
+    }
+}
+/// Struct: templated names and unchained setters
+#[fieldwork(
+    enable(template = "turn_on_{}"),
+    toggle(chain = false),
+    enabled(template = "{}")
+)]
+struct Switches {
+    power: bool,
+    #[field(toggle(name = flip_mute))]
+    mute: bool,
+    #[field(skip)]
+    locked: bool,
+}
+impl Switches {
+    pub fn turn_on_power(&mut self) -> &mut Self {
+        self.power = true;
+        self
+    }
+    pub fn toggle_power(&mut self) {
+        self.power = !self.power;
+    }
+    #[must_use]
+    pub fn power(mut self) -> Self {
+        self.power = true;
+        self
+    }
+    pub fn turn_on_mute(&mut self) -> &mut Self {
+        self.mute = true;
+        self
+    }
+    pub fn flip_mute(&mut self) {
+        self.mute = !self.mute;
+    }
+    #[must_use]
+    pub fn mute(mut self) -> Self {
+        self.mute = true;
+        self
+    }
+}
+/// Enum: flag methods leave variants without the field unchanged
+#[fieldwork(enable, toggle, disabled)]
+enum Node {
+    Leaf { visible: bool, weight: u32 },
+    Branch { visible: bool, expanded: bool },
+    Hidden { weight: u32 },
+}
+impl Node {
+    pub fn enable_expanded(&mut self) -> &mut Self {
+        match self {
+            Self::Branch { expanded, .. } => {
+                *expanded = true;
+            }
+            _ => {}
+        }
+        self
+    }
+    pub fn toggle_expanded(&mut self) -> &mut Self {
+        match self {
+            Self::Branch { expanded, .. } => {
+                *expanded = !*expanded;
+            }
+            _ => {}
+        }
+        self
+    }
+    #[must_use]
+    pub fn expanded_disabled(mut self) -> Self {
+        match &mut self {
+            Self::Branch { expanded, .. } => {
+                *expanded = false;
+            }
+            _ => {}
+        }
+        self
+    }
+    pub fn enable_visible(&mut self) -> &mut Self {
+        match self {
+            Self::Leaf { visible, .. } | Self::Branch { visible, .. } => {
+                *visible = true;
+            }
+            _ => {}
+        }
+        self
+    }
+    pub fn toggle_visible(&mut self) -> &mut Self {
+        match self {
+            Self::Leaf { visible, .. } | Self::Branch { visible, .. } => {
+                *visible = !*visible;
+            }
+            _ => {}
+        }
+        self
+    }
+    #[must_use]
+    pub fn visible_disabled(mut self) -> Self {
+        match &mut self {
+            Self::Leaf { visible, .. } | Self::Branch { visible, .. } => {
+                *visible = false;
+            }
+            _ => {}
+        }
+        self
+    }
+}
@@ -0,0 +1,33 @@
+/// Struct: flag methods for bool fields; non-bool fields are ignored
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(enable, disable, toggle, enabled, disabled, toggled)]
+struct Features {
+    /// whether verbose output is on
+    verbose: bool,
+    color: bool,
+    level: u8,
+}
+
+/// Struct: templated names and unchained setters
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(
+    enable(template = "turn_on_{}"),
+    toggle(chain = false),
+    enabled(template = "{}")
+)]
+struct Switches {
+    power: bool,
+    #[field(toggle(name = flip_mute))]
+    mute: bool,
+    #[field(skip)]
+    locked: bool,
+}
+
+/// Enum: flag methods leave variants without the field unchanged
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(enable, toggle, disabled)]
+enum Node {
+    Leaf { visible: bool, weight: u32 },
+    Branch { visible: bool, expanded: bool },
+    Hidden { weight: u32 },
+}
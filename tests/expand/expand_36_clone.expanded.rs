@@ -0,0 +1,113 @@
+use std::{rc::Rc, sync::Arc};
+/// Struct: item-level `clone` applies to Arc and Rc fields, including options of them
+#[fieldwork(get(clone))]
+struct Handle {
+    /// the shared pool
+    pool: Arc<Vec<u8>>,
+    cache: Option<Rc<str>>,
+    name: String,
+    count: usize,
+    #[field(get(clone = false))]
+    metrics: Arc<u64>,
+}
+impl Handle {
+    ///Returns a clone of the shared pool
+    pub fn pool(&self) -> Arc<Vec<u8>> {
+        self.pool.clone()
+    }
+    pub fn cache(&self) -> Option<Rc<str>> {
+        self.cache.clone()
+    }
+    pub fn name(&self) -> &str {
+        &*self.name
+    }
+    pub fn count(&self) -> usize {
+        self.count
+    }
+    pub fn metrics(&self) -> u64 {
+        *self.metrics
+    }
+}
+/// Struct: field-level `clone` applies to any type
+#[fieldwork(get)]
+struct Settings {
+    #[field(get(clone))]
+    label: String,
+    shared: Arc<String>,
+}
+impl Settings {
+    pub fn label(&self) -> String {
+        self.label.clone()
+    }
+    pub fn shared(&self) -> &str {
+        &**self.shared
+    }
+}
+/// Struct: the `cloned` method sits alongside the auto-dereferencing getter
+#[fieldwork(get, cloned)]
+struct Service {
+    /// the service config
+    config: Arc<String>,
+    #[field(cloned)]
+    name: String,
+    port: u16,
+}
+impl Service {
+    ///Borrows the service config
+    pub fn config(&self) -> &str {
+        &**self.config
+    }
+    ///Returns a clone of the service config
+    pub fn config_cloned(&self) -> Arc<String> {
+        self.config.clone()
+    }
+    pub fn name(&self) -> &str {
+        &*self.name
+    }
+    pub fn name_cloned(&self) -> String {
+        self.name.clone()
+    }
+    pub fn port(&self) -> u16 {
+        self.port
+    }
+}
+/// Enum: clones in every arm, wrapped in `Option` for partial coverage
+#[fieldwork(get(clone), cloned(template = "clone_{}"))]
+enum Job {
+    Queued { payload: Arc<str>, attempt: u8 },
+    Running { payload: Arc<str>, worker: Rc<String> },
+}
+impl Job {
+    pub fn attempt(&self) -> Option<u8> {
+        match self {
+            Self::Queued { attempt, .. } => Some(*attempt),
+            _ => None,
+        }
+    }
+    pub fn payload(&self) -> Arc<str> {
+        match self {
+            Self::Queued { payload, .. } | Self::Running { payload, .. } => {
+                payload.clone()
+            }
+        }
+    }
+    pub fn clone_payload(&self) -> Arc<str> {
+        match self {
+            Self::Queued { payload, .. } | Self::Running { payload, .. } => {
+                payload.clone()
+            }
+        }
+    }
+    pub fn worker(&self) -> Option<Rc<String>> {
+        match self {
+            Self::Running { worker, .. } => Some(worker.clone()),
+            _ => None,
+        }
+    }
+    pub fn clone_worker(&self) -> Option<Rc<String>> {
+        match self {
+            Self::Running { worker, .. } => Some(worker.clone()),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use std::{rc::Rc, sync::Arc};
+
+/// Struct: item-level `clone` applies to Arc and Rc fields, including options of them
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(get(clone))]
+struct Handle {
+    /// the shared pool
+    pool: Arc<Vec<u8>>,
+    cache: Option<Rc<str>>,
+    name: String,
+    count: usize,
+    #[field(get(clone = false))]
+    metrics: Arc<u64>,
+}
+
+/// Struct: field-level `clone` applies to any type
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(get)]
+struct Settings {
+    #[field(get(clone))]
+    label: String,
+    shared: Arc<String>,
+}
+
+/// Struct: the `cloned` method sits alongside the auto-dereferencing getter
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(get, cloned)]
+struct Service {
+    /// the service config
+    config: Arc<String>,
+    #[field(cloned)]
+    name: String,
+    port: u16,
+}
+
+/// Enum: clones in every arm, wrapped in `Option` for partial coverage
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(get(clone), cloned(template = "clone_{}"))]
+enum Job {
+    Queued {
+        payload: Arc<str>,
+        attempt: u8,
+    },
+    Running {
+        payload: Arc<str>,
+        worker: Rc<String>,
+    },
+}
@@ -0,0 +1,98 @@
+/// Struct: tuple parts, with skipped fields filled in by `from_parts`
+#[fieldwork(into_parts, from_parts)]
+struct Connection {
+    host: String,
+    port: u16,
+    #[field(skip, default = 3)]
+    retries: u8,
+    #[field(skip)]
+    buffer: Vec<u8>,
+}
+impl Connection {
+    /// Consumes self, returning its fields
+    #[must_use]
+    pub fn into_parts(self) -> (String, u16) {
+        (self.host, self.port)
+    }
+    /// Constructs self from its fields
+    pub fn from_parts(parts: (String, u16)) -> Self {
+        Self {
+            host: parts.0,
+            port: parts.1,
+            retries: 3,
+            buffer: Default::default(),
+        }
+    }
+}
+/// Struct: a generated parts struct carries field docs
+#[fieldwork(into_parts, from_parts, parts)]
+struct Request<T> {
+    /// the request path
+    path: String,
+    /// the request body
+    body: T,
+    #[field(skip, default = std::time::Instant::now())]
+    started: std::time::Instant,
+}
+impl<T> Request<T> {
+    /// Consumes self, returning its fields
+    #[must_use]
+    pub fn into_parts(self) -> RequestParts<T> {
+        RequestParts {
+            path: self.path,
+            body: self.body,
+        }
+    }
+    /// Constructs self from its fields
+    pub fn from_parts(parts: RequestParts<T>) -> Self {
+        Self {
+            path: parts.path,
+            body: parts.body,
+            started: std::time::Instant::now(),
+        }
+    }
+}
+///The fields of `Request`, as moved by `into_parts` and `from_parts`
+pub struct RequestParts<T> {
+    ///the request path
+    pub path: String,
+    ///the request body
+    pub body: T,
+}
+/// Struct: a custom parts name, only one direction, and restricted visibility
+#[fieldwork(into_parts, parts = "Pieces", vis = "pub(crate)")]
+struct Secret {
+    key: Vec<u8>,
+    label: &'static str,
+}
+impl Secret {
+    /// Consumes self, returning its fields
+    #[must_use]
+    pub(crate) fn into_parts(self) -> Pieces {
+        Pieces {
+            key: self.key,
+            label: self.label,
+        }
+    }
+}
+///The fields of `Secret`, as moved by `into_parts`
+pub(crate) struct Pieces {
+    pub key: Vec<u8>,
+    pub label: &'static str,
+}
+/// Tuple struct: parts are positional
+#[fieldwork(into_parts, from_parts, parts)]
+struct Pair(u8, #[field(skip)] bool, String);
+impl Pair {
+    /// Consumes self, returning its fields
+    #[must_use]
+    pub fn into_parts(self) -> PairParts {
+        PairParts(self.0, self.2)
+    }
+    /// Constructs self from its fields
+    pub fn from_parts(parts: PairParts) -> Self {
+        Self(parts.0, Default::default(), parts.1)
+    }
+}
+///The fields of `Pair`, as moved by `into_parts` and `from_parts`
+pub struct PairParts(pub u8, pub String);
@@ -0,0 +1,36 @@
+/// Struct: tuple parts, with skipped fields filled in by `from_parts`
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(into_parts, from_parts)]
+struct Connection {
+    host: String,
+    port: u16,
+    #[field(skip, default = 3)]
+    retries: u8,
+    #[field(skip)]
+    buffer: Vec<u8>,
+}
+
+/// Struct: a generated parts struct carries field docs
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(into_parts, from_parts, parts)]
+struct Request<T> {
+    /// the request path
+    path: String,
+    /// the request body
+    body: T,
+    #[field(skip, default = std::time::Instant::now())]
+    started: std::time::Instant,
+}
+
+/// Struct: a custom parts name, only one direction, and restricted visibility
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(into_parts, parts = "Pieces", vis = "pub(crate)")]
+struct Secret {
+    key: Vec<u8>,
+    label: &'static str,
+}
+
+/// Tuple struct: parts are positional
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(into_parts, from_parts, parts)]
+struct Pair(u8, #[field(skip)] bool, String);
@@ -0,0 +1,86 @@
+/// Struct: defaulted fields are filled in, the rest become arguments
+#[fieldwork(new, get)]
+struct Server {
+    host: String,
+    port: u16,
+    #[field(default = 30)]
+    timeout: u64,
+    #[field(default)]
+    handlers: Vec<String>,
+}
+impl Server {
+    ///Constructs a new `Server`
+    pub fn new(host: String, port: u16) -> Self {
+        Self {
+            host,
+            port,
+            timeout: 30,
+            handlers: Default::default(),
+        }
+    }
+    pub fn host(&self) -> &str {
+        &*self.host
+    }
+    pub fn port(&self) -> u16 {
+        self.port
+    }
+    pub fn timeout(&self) -> u64 {
+        self.timeout
+    }
+    pub fn handlers(&self) -> &[String] {
+        &*self.handlers
+    }
+}
+/// Struct: `into` applies to `new` like any other method, and `argument` renames carry over
+#[fieldwork(new(into, vis = "pub(crate)"))]
+struct User {
+    #[field(argument = display_name)]
+    name: String,
+    #[field(new(into = false))]
+    age: u8,
+    #[field(new = false)]
+    tags: Vec<String>,
+    #[field(option_set_some)]
+    email: Option<String>,
+}
+impl User {
+    ///Constructs a new `User`
+    pub(crate) fn new(
+        display_name: impl Into<String>,
+        age: u8,
+        email: impl Into<String>,
+    ) -> Self {
+        Self {
+            name: display_name.into(),
+            age,
+            tags: Default::default(),
+            email: Some(email.into()),
+        }
+    }
+}
+/// Tuple struct: arguments are named by position
+#[fieldwork(new)]
+struct Point(i32, #[field = "y"] i32, #[field(default = 1)] i32);
+impl Point {
+    ///Constructs a new `Point`
+    pub fn new(field_0: i32, y: i32) -> Self {
+        Self(field_0, y, 1)
+    }
+}
+/// Struct: every field defaulted
+#[fieldwork(new)]
+pub struct Counters {
+    #[field(default)]
+    hits: u64,
+    #[field(default = 10)]
+    limit: u64,
+}
+impl Counters {
+    ///Constructs a new `Counters`
+    pub fn new() -> Self {
+        Self {
+            hits: Default::default(),
+            limit: 10,
+        }
+    }
+}
@@ -0,0 +1,40 @@
+/// Struct: defaulted fields are filled in, the rest become arguments
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(new, get)]
+struct Server {
+    host: String,
+    port: u16,
+    #[field(default = 30)]
+    timeout: u64,
+    #[field(default)]
+    handlers: Vec<String>,
+}
+
+/// Struct: `into` applies to `new` like any other method, and `argument` renames carry over
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(new(into, vis = "pub(crate)"))]
+struct User {
+    #[field(argument = display_name)]
+    name: String,
+    #[field(new(into = false))]
+    age: u8,
+    #[field(new = false)]
+    tags: Vec<String>,
+    #[field(option_set_some)]
+    email: Option<String>,
+}
+
+/// Tuple struct: arguments are named by position
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(new)]
+struct Point(i32, #[field = "y"] i32, #[field(default = 1)] i32);
+
+/// Struct: every field defaulted
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(new)]
+pub struct Counters {
+    #[field(default)]
+    hits: u64,
+    #[field(default = 10)]
+    limit: u64,
+}
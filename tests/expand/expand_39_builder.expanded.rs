@@ -0,0 +1,389 @@
+/// Struct: non-`Option` fields without a default are required by `build`
+#[fieldwork(builder, get)]
+struct Server {
+    /// the hostname
+    host: String,
+    /// the port to listen on
+    port: u16,
+    #[field(default = 30)]
+    timeout: u64,
+    /// an optional name for logs
+    name: Option<String>,
+}
+impl Server {
+    ///Starts building a new `Server`
+    pub fn builder() -> ServerBuilder {
+        Default::default()
+    }
+    ///Borrows the hostname
+    pub fn host(&self) -> &str {
+        &*self.host
+    }
+    ///Returns a copy of the port to listen on
+    pub fn port(&self) -> u16 {
+        self.port
+    }
+    pub fn timeout(&self) -> u64 {
+        self.timeout
+    }
+    ///Borrows an optional name for logs
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+}
+///A builder for [`Server`], started with `Server::builder()`
+pub struct ServerBuilder {
+    host: Option<String>,
+    port: Option<u16>,
+    timeout: Option<u64>,
+    name: Option<String>,
+}
+impl Default for ServerBuilder {
+    fn default() -> Self {
+        Self {
+            host: None,
+            port: None,
+            timeout: None,
+            name: None,
+        }
+    }
+}
+impl ServerBuilder {
+    ///Sets the hostname, returning the builder for chaining
+    #[must_use]
+    pub fn with_host(mut self, host: String) -> Self {
+        self.host = Some(host);
+        self
+    }
+    ///Sets the port to listen on, returning the builder for chaining
+    #[must_use]
+    pub fn with_port(mut self, port: u16) -> Self {
+        self.port = Some(port);
+        self
+    }
+    #[must_use]
+    pub fn with_timeout(mut self, timeout: u64) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+    ///Sets an optional name for logs, returning the builder for chaining
+    #[must_use]
+    pub fn with_name(mut self, name: Option<String>) -> Self {
+        self.name = name;
+        self
+    }
+    ///Builds the `Server`, or returns an error naming the required fields that were not set
+    pub fn build(self) -> Result<Server, ServerBuilderError> {
+        let mut missing = Vec::new();
+        if self.host.is_none() {
+            missing.push("host");
+        }
+        if self.port.is_none() {
+            missing.push("port");
+        }
+        if let (Some(host), Some(port)) = (self.host, self.port) {
+            Ok(Server {
+                host,
+                port,
+                timeout: self.timeout.unwrap_or_else(|| 30),
+                name: self.name,
+            })
+        } else {
+            Err(ServerBuilderError { missing })
+        }
+    }
+}
+///The error returned by [`ServerBuilder::build`]
+pub struct ServerBuilderError {
+    missing: Vec<&'static str>,
+}
+#[automatically_derived]
+impl ::core::fmt::Debug for ServerBuilderError {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_struct_field1_finish(
+            f,
+            "ServerBuilderError",
+            "missing",
+            &&self.missing,
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for ServerBuilderError {
+    #[inline]
+    fn clone(&self) -> ServerBuilderError {
+        ServerBuilderError {
+            missing: ::core::clone::Clone::clone(&self.missing),
+        }
+    }
+}
+#[automatically_derived]
+impl ::core::marker::StructuralPartialEq for ServerBuilderError {}
+#[automatically_derived]
+impl ::core::cmp::PartialEq for ServerBuilderError {
+    #[inline]
+    fn eq(&self, other: &ServerBuilderError) -> bool {
+        self.missing == other.missing
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::Eq for ServerBuilderError {
+    #[inline]
+    #[doc(hidden)]
+    #[coverage(off)]
+    fn assert_fields_are_eq(&self) {
+        let _: ::core::cmp::AssertParamIsEq<Vec<&'static str>>;
+    }
+}
+impl ServerBuilderError {
+    /// The names of the required fields that were not set
+    pub fn missing_fields(&self) -> &[&'static str] {
+        &self.missing
+    }
+}
+impl std::fmt::Display for ServerBuilderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("missing required fields for `Server`: ")?;
+        f.write_str(&self.missing.join(", "))
+    }
+}
+impl std::error::Error for ServerBuilderError {}
+/// Struct: `into` and `option_set_some` apply to builder setters like any other setter
+#[fieldwork(builder(into, vis = "pub(crate)"), option_set_some)]
+struct User {
+    #[field(argument = display_name)]
+    name: String,
+    #[field(builder(rename = age_in_years, into = false))]
+    age: u8,
+    #[field(builder = false)]
+    tags: Vec<String>,
+    email: Option<String>,
+}
+impl User {
+    ///Starts building a new `User`
+    pub(crate) fn builder() -> UserBuilder {
+        Default::default()
+    }
+}
+///A builder for [`User`], started with `User::builder()`
+pub(crate) struct UserBuilder {
+    name: Option<String>,
+    age: Option<u8>,
+    email: Option<String>,
+}
+impl Default for UserBuilder {
+    fn default() -> Self {
+        Self {
+            name: None,
+            age: None,
+            email: None,
+        }
+    }
+}
+impl UserBuilder {
+    #[must_use]
+    pub(crate) fn with_name(mut self, display_name: impl Into<String>) -> Self {
+        self.name = Some(display_name.into());
+        self
+    }
+    #[must_use]
+    pub(crate) fn age_in_years(mut self, age: u8) -> Self {
+        self.age = Some(age);
+        self
+    }
+    #[must_use]
+    pub(crate) fn with_email(mut self, email: impl Into<String>) -> Self {
+        self.email = Some(email.into());
+        self
+    }
+    ///Builds the `User`, or returns an error naming the required fields that were not set
+    pub(crate) fn build(self) -> Result<User, UserBuilderError> {
+        let mut missing = Vec::new();
+        if self.name.is_none() {
+            missing.push("name");
+        }
+        if self.age.is_none() {
+            missing.push("age");
+        }
+        if let (Some(name), Some(age)) = (self.name, self.age) {
+            Ok(User {
+                name,
+                age,
+                tags: Default::default(),
+                email: self.email,
+            })
+        } else {
+            Err(UserBuilderError { missing })
+        }
+    }
+}
+///The error returned by [`UserBuilder::build`]
+pub(crate) struct UserBuilderError {
+    missing: Vec<&'static str>,
+}
+#[automatically_derived]
+impl ::core::fmt::Debug for UserBuilderError {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_struct_field1_finish(
+            f,
+            "UserBuilderError",
+            "missing",
+            &&self.missing,
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for UserBuilderError {
+    #[inline]
+    fn clone(&self) -> UserBuilderError {
+        UserBuilderError {
+            missing: ::core::clone::Clone::clone(&self.missing),
+        }
+    }
+}
+#[automatically_derived]
+impl ::core::marker::StructuralPartialEq for UserBuilderError {}
+#[automatically_derived]
+impl ::core::cmp::PartialEq for UserBuilderError {
+    #[inline]
+    fn eq(&self, other: &UserBuilderError) -> bool {
+        self.missing == other.missing
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::Eq for UserBuilderError {
+    #[inline]
+    #[doc(hidden)]
+    #[coverage(off)]
+    fn assert_fields_are_eq(&self) {
+        let _: ::core::cmp::AssertParamIsEq<Vec<&'static str>>;
+    }
+}
+impl UserBuilderError {
+    /// The names of the required fields that were not set
+    pub(crate) fn missing_fields(&self) -> &[&'static str] {
+        &self.missing
+    }
+}
+impl std::fmt::Display for UserBuilderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("missing required fields for `User`: ")?;
+        f.write_str(&self.missing.join(", "))
+    }
+}
+impl std::error::Error for UserBuilderError {}
+/// Struct: every field optional or defaulted, so `build` never fails
+#[fieldwork(builder)]
+pub struct Counters<T: Clone> {
+    #[field(default)]
+    hits: u64,
+    #[field(default = Some(10))]
+    limit: Option<u64>,
+    label: Option<T>,
+}
+impl<T: Clone> Counters<T> {
+    ///Starts building a new `Counters`
+    pub fn builder() -> CountersBuilder<T> {
+        Default::default()
+    }
+}
+///A builder for [`Counters`], started with `Counters::builder()`
+pub struct CountersBuilder<T: Clone> {
+    hits: Option<u64>,
+    limit: Option<u64>,
+    label: Option<T>,
+    _marker: std::marker::PhantomData<fn() -> Counters<T>>,
+}
+impl<T: Clone> Default for CountersBuilder<T> {
+    fn default() -> Self {
+        Self {
+            hits: None,
+            limit: None,
+            label: None,
+            _marker: std::marker::PhantomData,
+        }
+    }
+}
+impl<T: Clone> CountersBuilder<T> {
+    #[must_use]
+    pub fn with_hits(mut self, hits: u64) -> Self {
+        self.hits = Some(hits);
+        self
+    }
+    #[must_use]
+    pub fn with_limit(mut self, limit: Option<u64>) -> Self {
+        self.limit = limit;
+        self
+    }
+    #[must_use]
+    pub fn with_label(mut self, label: Option<T>) -> Self {
+        self.label = label;
+        self
+    }
+    ///Builds the `Counters`, or returns an error naming the required fields that were not set
+    pub fn build(self) -> Result<Counters<T>, CountersBuilderError> {
+        Ok(Counters {
+            hits: self.hits.unwrap_or_default(),
+            limit: self.limit.or_else(|| Some(10)),
+            label: self.label,
+        })
+    }
+}
+///The error returned by [`CountersBuilder::build`]
+pub struct CountersBuilderError {
+    missing: Vec<&'static str>,
+}
+#[automatically_derived]
+impl ::core::fmt::Debug for CountersBuilderError {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_struct_field1_finish(
+            f,
+            "CountersBuilderError",
+            "missing",
+            &&self.missing,
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for CountersBuilderError {
+    #[inline]
+    fn clone(&self) -> CountersBuilderError {
+        CountersBuilderError {
+            missing: ::core::clone::Clone::clone(&self.missing),
+        }
+    }
+}
+#[automatically_derived]
+impl ::core::marker::StructuralPartialEq for CountersBuilderError {}
+#[automatically_derived]
+impl ::core::cmp::PartialEq for CountersBuilderError {
+    #[inline]
+    fn eq(&self, other: &CountersBuilderError) -> bool {
+        self.missing == other.missing
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::Eq for CountersBuilderError {
+    #[inline]
+    #[doc(hidden)]
+    #[coverage(off)]
+    fn assert_fields_are_eq(&self) {
+        let _: ::core::cmp::AssertParamIsEq<Vec<&'static str>>;
+    }
+}
+impl CountersBuilderError {
+    /// The names of the required fields that were not set
+    pub fn missing_fields(&self) -> &[&'static str] {
+        &self.missing
+    }
+}
+impl std::fmt::Display for CountersBuilderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("missing required fields for `Counters`: ")?;
+        f.write_str(&self.missing.join(", "))
+    }
+}
+impl std::error::Error for CountersBuilderError {}
@@ -0,0 +1,37 @@
+/// Struct: non-`Option` fields without a default are required by `build`
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(builder, get)]
+struct Server {
+    /// the hostname
+    host: String,
+    /// the port to listen on
+    port: u16,
+    #[field(default = 30)]
+    timeout: u64,
+    /// an optional name for logs
+    name: Option<String>,
+}
+
+/// Struct: `into` and `option_set_some` apply to builder setters like any other setter
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(builder(into, vis = "pub(crate)"), option_set_some)]
+struct User {
+    #[field(argument = display_name)]
+    name: String,
+    #[field(builder(rename = age_in_years, into = false))]
+    age: u8,
+    #[field(builder = false)]
+    tags: Vec<String>,
+    email: Option<String>,
+}
+
+/// Struct: every field optional or defaulted, so `build` never fails
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(builder)]
+pub struct Counters<T: Clone> {
+    #[field(default)]
+    hits: u64,
+    #[field(default = Some(10))]
+    limit: Option<u64>,
+    label: Option<T>,
+}
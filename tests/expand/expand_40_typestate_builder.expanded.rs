@@ -0,0 +1,230 @@
+/// Struct: required fields are tracked in type parameters, so `build` can't be called early
+#[fieldwork(builder(typestate))]
+struct Server {
+    /// the hostname
+    host: String,
+    /// the port to listen on
+    #[field(deprecate = listen_port)]
+    port: u16,
+    #[field(default = 30)]
+    timeout: u64,
+    /// an optional name for logs
+    #[field(
+        builder(deprecate(was = name, note = "use `with_label`"), rename = with_label)
+    )]
+    name: Option<String>,
+}
+impl Server {
+    ///Starts building a new `Server`
+    pub fn builder() -> ServerBuilder {
+        Default::default()
+    }
+}
+/**A builder for [`Server`], started with `Server::builder()`
+
+Each `State` type parameter tracks whether a required field has been set, and is `()` until it is. `build` is only available once all of them have been.*/
+pub struct ServerBuilder<HostState = (), PortState = ()> {
+    host: HostState,
+    port: PortState,
+    timeout: Option<u64>,
+    name: Option<String>,
+}
+impl Default for ServerBuilder<(), ()> {
+    fn default() -> Self {
+        Self {
+            host: (),
+            port: (),
+            timeout: None,
+            name: None,
+        }
+    }
+}
+impl<HostState, PortState> ServerBuilder<HostState, PortState> {
+    ///Sets the hostname, returning the builder for chaining
+    #[must_use]
+    pub fn with_host(self, host: String) -> ServerBuilder<String, PortState> {
+        ServerBuilder {
+            host,
+            port: self.port,
+            timeout: self.timeout,
+            name: self.name,
+        }
+    }
+    ///Sets the port to listen on, returning the builder for chaining
+    #[must_use]
+    pub fn with_port(self, port: u16) -> ServerBuilder<HostState, u16> {
+        ServerBuilder {
+            port,
+            host: self.host,
+            timeout: self.timeout,
+            name: self.name,
+        }
+    }
+    ///Sets the port to listen on, returning the builder for chaining
+    #[deprecated(note = "use `with_port` instead")]
+    #[must_use]
+    pub fn with_listen_port(self, listen_port: u16) -> ServerBuilder<HostState, u16> {
+        ServerBuilder {
+            port: listen_port,
+            host: self.host,
+            timeout: self.timeout,
+            name: self.name,
+        }
+    }
+    #[must_use]
+    pub fn with_timeout(mut self, timeout: u64) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+    ///Sets an optional name for logs, returning the builder for chaining
+    #[must_use]
+    pub fn with_label(mut self, name: Option<String>) -> Self {
+        self.name = name;
+        self
+    }
+    ///Sets an optional name for logs, returning the builder for chaining
+    #[deprecated(note = "use `with_label`")]
+    #[must_use]
+    pub fn name(mut self, name: Option<String>) -> Self {
+        self.name = name;
+        self
+    }
+}
+impl ServerBuilder<String, u16> {
+    ///Builds the `Server`
+    #[must_use]
+    pub fn build(self) -> Server {
+        Server {
+            host: self.host,
+            port: self.port,
+            timeout: self.timeout.unwrap_or_else(|| 30),
+            name: self.name,
+        }
+    }
+}
+/// Struct: generics and `into` carry over, and skipped fields get their default
+#[fieldwork(builder(typestate, into, vis = "pub(crate)"))]
+struct Request<'a, T: Clone> {
+    path: &'a str,
+    #[field(argument = content)]
+    body: T,
+    #[field(builder = false)]
+    attempts: u8,
+    max_retries: Option<u8>,
+}
+impl<'a, T: Clone> Request<'a, T> {
+    ///Starts building a new `Request`
+    pub(crate) fn builder() -> RequestBuilder<'a, T> {
+        Default::default()
+    }
+}
+/**A builder for [`Request`], started with `Request::builder()`
+
+Each `State` type parameter tracks whether a required field has been set, and is `()` until it is. `build` is only available once all of them have been.*/
+pub(crate) struct RequestBuilder<'a, T: Clone, PathState = (), BodyState = ()> {
+    path: PathState,
+    body: BodyState,
+    max_retries: Option<u8>,
+    _marker: std::marker::PhantomData<fn() -> Request<'a, T>>,
+}
+impl<'a, T: Clone> Default for RequestBuilder<'a, T, (), ()> {
+    fn default() -> Self {
+        Self {
+            path: (),
+            body: (),
+            max_retries: None,
+            _marker: std::marker::PhantomData,
+        }
+    }
+}
+impl<'a, T: Clone, PathState, BodyState> RequestBuilder<'a, T, PathState, BodyState> {
+    #[must_use]
+    pub(crate) fn with_path(
+        self,
+        path: impl Into<&'a str>,
+    ) -> RequestBuilder<'a, T, &'a str, BodyState> {
+        RequestBuilder {
+            path: path.into(),
+            body: self.body,
+            max_retries: self.max_retries,
+            _marker: self._marker,
+        }
+    }
+    #[must_use]
+    pub(crate) fn with_body(
+        self,
+        content: impl Into<T>,
+    ) -> RequestBuilder<'a, T, PathState, T> {
+        RequestBuilder {
+            body: content.into(),
+            path: self.path,
+            max_retries: self.max_retries,
+            _marker: self._marker,
+        }
+    }
+    #[must_use]
+    pub(crate) fn with_max_retries(
+        mut self,
+        max_retries: Option<impl Into<u8>>,
+    ) -> Self {
+        self.max_retries = max_retries.map(Into::into);
+        self
+    }
+}
+impl<'a, T: Clone> RequestBuilder<'a, T, &'a str, T> {
+    ///Builds the `Request`
+    #[must_use]
+    pub(crate) fn build(self) -> Request<'a, T> {
+        Request {
+            path: self.path,
+            body: self.body,
+            attempts: Default::default(),
+            max_retries: self.max_retries,
+        }
+    }
+}
+/// Struct: without required fields, `build` is available immediately
+#[fieldwork(builder(typestate))]
+pub struct Counters {
+    #[field(default)]
+    hits: u64,
+    limit: Option<u64>,
+}
+impl Counters {
+    ///Starts building a new `Counters`
+    pub fn builder() -> CountersBuilder {
+        Default::default()
+    }
+}
+///A builder for [`Counters`], started with `Counters::builder()`
+pub struct CountersBuilder {
+    hits: Option<u64>,
+    limit: Option<u64>,
+}
+impl Default for CountersBuilder {
+    fn default() -> Self {
+        Self { hits: None, limit: None }
+    }
+}
+impl CountersBuilder {
+    #[must_use]
+    pub fn with_hits(mut self, hits: u64) -> Self {
+        self.hits = Some(hits);
+        self
+    }
+    #[must_use]
+    pub fn with_limit(mut self, limit: Option<u64>) -> Self {
+        self.limit = limit;
+        self
+    }
+}
+impl CountersBuilder {
+    ///Builds the `Counters`
+    #[must_use]
+    pub fn build(self) -> Counters {
+        Counters {
+            hits: self.hits.unwrap_or_default(),
+            limit: self.limit,
+        }
+    }
+}
@@ -0,0 +1,36 @@
+/// Struct: required fields are tracked in type parameters, so `build` can't be called early
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(builder(typestate))]
+struct Server {
+    /// the hostname
+    host: String,
+    /// the port to listen on
+    #[field(deprecate = listen_port)]
+    port: u16,
+    #[field(default = 30)]
+    timeout: u64,
+    /// an optional name for logs
+    #[field(builder(deprecate(was = name, note = "use `with_label`"), rename = with_label))]
+    name: Option<String>,
+}
+
+/// Struct: generics and `into` carry over, and skipped fields get their default
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(builder(typestate, into, vis = "pub(crate)"))]
+struct Request<'a, T: Clone> {
+    path: &'a str,
+    #[field(argument = content)]
+    body: T,
+    #[field(builder = false)]
+    attempts: u8,
+    max_retries: Option<u8>,
+}
+
+/// Struct: without required fields, `build` is available immediately
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(builder(typestate))]
+pub struct Counters {
+    #[field(default)]
+    hits: u64,
+    limit: Option<u64>,
+}
@@ -0,0 +1,226 @@
+/// Enum: named, tuple, and unit variants
+#[fieldwork(variants)]
+enum Event {
+    Click { x: i32, y: i32, button: u8 },
+    KeyPress(char),
+    Resize(u32, u32),
+    Close,
+}
+impl Event {
+    ///Returns `true` if this is `Self::Click`
+    pub fn is_click(&self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Click { .. } => true,
+            _ => false,
+        }
+    }
+    ///Borrows the fields of `Self::Click`, if this is one
+    pub fn as_click(&self) -> Option<(&i32, &i32, &u8)> {
+        match self {
+            Self::Click { x, y, button } => Some((x, y, button)),
+            _ => None,
+        }
+    }
+    ///Mutably borrows the fields of `Self::Click`, if this is one
+    pub fn as_click_mut(&mut self) -> Option<(&mut i32, &mut i32, &mut u8)> {
+        match self {
+            Self::Click { x, y, button } => Some((x, y, button)),
+            _ => None,
+        }
+    }
+    ///Consumes self, returning the fields of `Self::Click`, or `Err(self)` if this is another variant
+    pub fn into_click(self) -> Result<(i32, i32, u8), Self> {
+        match self {
+            Self::Click { x, y, button } => Ok((x, y, button)),
+            other => Err(other),
+        }
+    }
+    ///Returns `true` if this is `Self::KeyPress`
+    pub fn is_key_press(&self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::KeyPress(..) => true,
+            _ => false,
+        }
+    }
+    ///Borrows the fields of `Self::KeyPress`, if this is one
+    pub fn as_key_press(&self) -> Option<&char> {
+        match self {
+            Self::KeyPress(field_0) => Some(field_0),
+            _ => None,
+        }
+    }
+    ///Mutably borrows the fields of `Self::KeyPress`, if this is one
+    pub fn as_key_press_mut(&mut self) -> Option<&mut char> {
+        match self {
+            Self::KeyPress(field_0) => Some(field_0),
+            _ => None,
+        }
+    }
+    ///Consumes self, returning the fields of `Self::KeyPress`, or `Err(self)` if this is another variant
+    pub fn into_key_press(self) -> Result<char, Self> {
+        match self {
+            Self::KeyPress(field_0) => Ok(field_0),
+            other => Err(other),
+        }
+    }
+    ///Returns `true` if this is `Self::Resize`
+    pub fn is_resize(&self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Resize(..) => true,
+            _ => false,
+        }
+    }
+    ///Borrows the fields of `Self::Resize`, if this is one
+    pub fn as_resize(&self) -> Option<(&u32, &u32)> {
+        match self {
+            Self::Resize(field_0, field_1) => Some((field_0, field_1)),
+            _ => None,
+        }
+    }
+    ///Mutably borrows the fields of `Self::Resize`, if this is one
+    pub fn as_resize_mut(&mut self) -> Option<(&mut u32, &mut u32)> {
+        match self {
+            Self::Resize(field_0, field_1) => Some((field_0, field_1)),
+            _ => None,
+        }
+    }
+    ///Consumes self, returning the fields of `Self::Resize`, or `Err(self)` if this is another variant
+    pub fn into_resize(self) -> Result<(u32, u32), Self> {
+        match self {
+            Self::Resize(field_0, field_1) => Ok((field_0, field_1)),
+            other => Err(other),
+        }
+    }
+    ///Returns `true` if this is `Self::Close`
+    pub fn is_close(&self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Close => true,
+            _ => false,
+        }
+    }
+}
+/// Enum: per-variant rename and `variants = false`, alongside field methods
+#[fieldwork(variants, get, vis = "pub(crate)")]
+enum Response<T> {
+    #[variant(rename = ok)]
+    Success { id: u64, body: T },
+    HTTPError { id: u64, status: u16 },
+    #[variant(variants = false)]
+    Pending { id: u64 },
+}
+impl<T> Response<T> {
+    ///Returns `true` if this is `Self::Success`
+    pub(crate) fn is_ok(&self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Success { .. } => true,
+            _ => false,
+        }
+    }
+    ///Borrows the fields of `Self::Success`, if this is one
+    pub(crate) fn as_ok(&self) -> Option<(&u64, &T)> {
+        match self {
+            Self::Success { id, body } => Some((id, body)),
+            _ => None,
+        }
+    }
+    ///Mutably borrows the fields of `Self::Success`, if this is one
+    pub(crate) fn as_ok_mut(&mut self) -> Option<(&mut u64, &mut T)> {
+        match self {
+            Self::Success { id, body } => Some((id, body)),
+            _ => None,
+        }
+    }
+    ///Consumes self, returning the fields of `Self::Success`, or `Err(self)` if this is another variant
+    pub(crate) fn into_ok(self) -> Result<(u64, T), Self> {
+        match self {
+            Self::Success { id, body } => Ok((id, body)),
+            other => Err(other),
+        }
+    }
+    ///Returns `true` if this is `Self::HTTPError`
+    pub(crate) fn is_http_error(&self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::HTTPError { .. } => true,
+            _ => false,
+        }
+    }
+    ///Borrows the fields of `Self::HTTPError`, if this is one
+    pub(crate) fn as_http_error(&self) -> Option<(&u64, &u16)> {
+        match self {
+            Self::HTTPError { id, status } => Some((id, status)),
+            _ => None,
+        }
+    }
+    ///Mutably borrows the fields of `Self::HTTPError`, if this is one
+    pub(crate) fn as_http_error_mut(&mut self) -> Option<(&mut u64, &mut u16)> {
+        match self {
+            Self::HTTPError { id, status } => Some((id, status)),
+            _ => None,
+        }
+    }
+    ///Consumes self, returning the fields of `Self::HTTPError`, or `Err(self)` if this is another variant
+    pub(crate) fn into_http_error(self) -> Result<(u64, u16), Self> {
+        match self {
+            Self::HTTPError { id, status } => Ok((id, status)),
+            other => Err(other),
+        }
+    }
+    pub(crate) fn body(&self) -> Option<&T> {
+        match self {
+            Self::Success { body, .. } => Some(body),
+            _ => None,
+        }
+    }
+    pub(crate) fn id(&self) -> u64 {
+        match self {
+            Self::Success { id, .. }
+            | Self::HTTPError { id, .. }
+            | Self::Pending { id, .. } => *id,
+        }
+    }
+    pub(crate) fn status(&self) -> Option<u16> {
+        match self {
+            Self::HTTPError { status, .. } => Some(*status),
+            _ => None,
+        }
+    }
+}
+/// Enum: a single variant needs no fallback arms
+#[fieldwork(variants)]
+enum Wrapper {
+    Only(String),
+}
+impl Wrapper {
+    ///Returns `true` if this is `Self::Only`
+    pub fn is_only(&self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Only(..) => true,
+            _ => false,
+        }
+    }
+    ///Borrows the fields of `Self::Only`, if this is one
+    pub fn as_only(&self) -> Option<&String> {
+        match self {
+            Self::Only(field_0) => Some(field_0),
+        }
+    }
+    ///Mutably borrows the fields of `Self::Only`, if this is one
+    pub fn as_only_mut(&mut self) -> Option<&mut String> {
+        match self {
+            Self::Only(field_0) => Some(field_0),
+        }
+    }
+    ///Consumes self, returning the fields of `Self::Only`, or `Err(self)` if this is another variant
+    pub fn into_only(self) -> Result<String, Self> {
+        match self {
+            Self::Only(field_0) => Ok(field_0),
+        }
+    }
+}
@@ -0,0 +1,35 @@
+/// Enum: named, tuple, and unit variants
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(variants)]
+enum Event {
+    Click { x: i32, y: i32, button: u8 },
+    KeyPress(char),
+    Resize(u32, u32),
+    Close,
+}
+
+/// Enum: per-variant rename and `variants = false`, alongside field methods
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(variants, get, vis = "pub(crate)")]
+enum Response<T> {
+    #[variant(rename = ok)]
+    Success {
+        id: u64,
+        body: T,
+    },
+    HTTPError {
+        id: u64,
+        status: u16,
+    },
+    #[variant(variants = false)]
+    Pending {
+        id: u64,
+    },
+}
+
+/// Enum: a single variant needs no fallback arms
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(variants)]
+enum Wrapper {
+    Only(String),
+}
@@ -0,0 +1,98 @@
+/// Enum: a legacy variant excluded from setters
+#[fieldwork(get, set)]
+enum Account {
+    Active { id: u64, name: String },
+    Suspended { id: u64, name: String },
+    #[variant(set = false)]
+    Legacy { id: u64, name: String },
+}
+impl Account {
+    pub fn id(&self) -> u64 {
+        match self {
+            Self::Active { id, .. }
+            | Self::Suspended { id, .. }
+            | Self::Legacy { id, .. } => *id,
+        }
+    }
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Active { name, .. }
+            | Self::Suspended { name, .. }
+            | Self::Legacy { name, .. } => &**name,
+        }
+    }
+}
+/// Enum: variant-level copy, deref, and skip apply to every field of the variant
+#[fieldwork(get, set)]
+enum Document {
+    #[variant(get(copy = false))]
+    Draft { title: String, revision: u32 },
+    #[variant(deref = "[u8]", set(skip))]
+    Binary { bytes: Vec<u8> },
+    #[variant(skip)]
+    Archived { reason: String },
+}
+impl Document {
+    pub fn bytes(&self) -> Option<&[u8]> {
+        match self {
+            Self::Binary { bytes, .. } => Some(&**bytes),
+            _ => None,
+        }
+    }
+    pub fn revision(&self) -> Option<&u32> {
+        match self {
+            Self::Draft { revision, .. } => Some(revision),
+            _ => None,
+        }
+    }
+    pub fn title(&self) -> Option<&str> {
+        match self {
+            Self::Draft { title, .. } => Some(&**title),
+            _ => None,
+        }
+    }
+}
+/// Enum: a field shared across variants takes the first variant's vis and into
+#[fieldwork(get, set)]
+enum Label {
+    #[variant(vis = "pub(crate)", into)]
+    Short { text: String },
+    Long { text: String },
+}
+impl Label {
+    pub(crate) fn text(&self) -> &str {
+        match self {
+            Self::Short { text, .. } | Self::Long { text, .. } => &**text,
+        }
+    }
+    pub(crate) fn set_text(&mut self, text: impl Into<String>) -> &mut Self {
+        match self {
+            Self::Short { text: text_binding, .. } => {
+                *text_binding = text.into();
+            }
+            Self::Long { text: text_binding, .. } => {
+                *text_binding = text.into();
+            }
+        }
+        self
+    }
+}
+/// Enum: per-method opt-in from a variant
+enum Packet {
+    #[variant(get)]
+    Data { seq: u32, payload: Vec<u8> },
+    Ack { seq: u32 },
+}
+impl Packet {
+    pub fn payload(&self) -> Option<&[u8]> {
+        match self {
+            Self::Data { payload, .. } => Some(&**payload),
+            _ => None,
+        }
+    }
+    pub fn seq(&self) -> u32 {
+        match self {
+            Self::Data { seq, .. } | Self::Ack { seq, .. } => *seq,
+        }
+    }
+}
@@ -0,0 +1,56 @@
+/// Enum: a legacy variant excluded from setters
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(get, set)]
+enum Account {
+    Active {
+        id: u64,
+        name: String,
+    },
+    Suspended {
+        id: u64,
+        name: String,
+    },
+    #[variant(set = false)]
+    Legacy {
+        id: u64,
+        name: String,
+    },
+}
+
+/// Enum: variant-level copy, deref, and skip apply to every field of the variant
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(get, set)]
+enum Document {
+    #[variant(get(copy = false))]
+    Draft { title: String, revision: u32 },
+    #[variant(deref = "[u8]", set(skip))]
+    Binary { bytes: Vec<u8> },
+    #[variant(skip)]
+    Archived { reason: String },
+}
+
+/// Enum: a field shared across variants takes the first variant's vis and into
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(get, set)]
+enum Label {
+    #[variant(vis = "pub(crate)", into)]
+    Short {
+        text: String,
+    },
+    Long {
+        text: String,
+    },
+}
+
+/// Enum: per-method opt-in from a variant
+#[derive(fieldwork::Fieldwork)]
+enum Packet {
+    #[variant(get)]
+    Data {
+        seq: u32,
+        payload: Vec<u8>,
+    },
+    Ack {
+        seq: u32,
+    },
+}
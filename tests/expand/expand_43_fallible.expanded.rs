@@ -0,0 +1,162 @@
+/// Enum: fallible set, with, into_field, and take for partial-coverage fields
+#[fieldwork(get, set, with, into_field, take, fallible)]
+enum Event {
+    Click { x: i32, y: i32, button: u8, label: Option<String> },
+    Move { x: i32, y: i32 },
+}
+impl Event {
+    pub fn button(&self) -> Option<u8> {
+        match self {
+            Self::Click { button, .. } => Some(*button),
+            _ => None,
+        }
+    }
+    pub fn set_button(&mut self, button: u8) -> Result<&mut Self, u8> {
+        match self {
+            Self::Click { button: button_binding, .. } => {
+                *button_binding = button;
+            }
+            _ => return Err(button),
+        }
+        Ok(self)
+    }
+    pub fn with_button(mut self, button: u8) -> Result<Self, (Self, u8)> {
+        match &mut self {
+            Self::Click { button: button_binding, .. } => {
+                *button_binding = button;
+            }
+            _ => return Err((self, button)),
+        }
+        Ok(self)
+    }
+    pub fn try_into_button(self) -> Result<u8, Self> {
+        match self {
+            Self::Click { button, .. } => Ok(button),
+            other => Err(other),
+        }
+    }
+    pub fn label(&self) -> Option<&str> {
+        match self {
+            Self::Click { label, .. } => label.as_deref(),
+            _ => None,
+        }
+    }
+    pub fn set_label(
+        &mut self,
+        label: Option<String>,
+    ) -> Result<&mut Self, Option<String>> {
+        match self {
+            Self::Click { label: label_binding, .. } => {
+                *label_binding = label;
+            }
+            _ => return Err(label),
+        }
+        Ok(self)
+    }
+    pub fn with_label(
+        mut self,
+        label: Option<String>,
+    ) -> Result<Self, (Self, Option<String>)> {
+        match &mut self {
+            Self::Click { label: label_binding, .. } => {
+                *label_binding = label;
+            }
+            _ => return Err((self, label)),
+        }
+        Ok(self)
+    }
+    pub fn take_label(&mut self) -> Option<String> {
+        match self {
+            Self::Click { label, .. } => label.take(),
+            _ => None,
+        }
+    }
+    pub fn try_into_label(self) -> Result<Option<String>, Self> {
+        match self {
+            Self::Click { label, .. } => Ok(label),
+            other => Err(other),
+        }
+    }
+    pub fn x(&self) -> i32 {
+        match self {
+            Self::Click { x, .. } | Self::Move { x, .. } => *x,
+        }
+    }
+    pub fn set_x(&mut self, x: i32) -> &mut Self {
+        match self {
+            Self::Click { x: x_binding, .. } => {
+                *x_binding = x;
+            }
+            Self::Move { x: x_binding, .. } => {
+                *x_binding = x;
+            }
+        }
+        self
+    }
+    #[must_use]
+    pub fn with_x(mut self, x: i32) -> Self {
+        match &mut self {
+            Self::Click { x: x_binding, .. } => {
+                *x_binding = x;
+            }
+            Self::Move { x: x_binding, .. } => {
+                *x_binding = x;
+            }
+        }
+        self
+    }
+    pub fn y(&self) -> i32 {
+        match self {
+            Self::Click { y, .. } | Self::Move { y, .. } => *y,
+        }
+    }
+    pub fn set_y(&mut self, y: i32) -> &mut Self {
+        match self {
+            Self::Click { y: y_binding, .. } => {
+                *y_binding = y;
+            }
+            Self::Move { y: y_binding, .. } => {
+                *y_binding = y;
+            }
+        }
+        self
+    }
+    #[must_use]
+    pub fn with_y(mut self, y: i32) -> Self {
+        match &mut self {
+            Self::Click { y: y_binding, .. } => {
+                *y_binding = y;
+            }
+            Self::Move { y: y_binding, .. } => {
+                *y_binding = y;
+            }
+        }
+        self
+    }
+}
+/// Enum: fallible per field, with into and chain = false
+#[fieldwork(set(chain = false), with)]
+enum Shape {
+    Circle { #[field(fallible, into)] name: String, radius: f64 },
+    Square { side: f64 },
+}
+impl Shape {
+    pub fn set_name(&mut self, name: impl Into<String>) -> Result<(), String> {
+        match self {
+            Self::Circle { name: name_binding, .. } => {
+                *name_binding = name.into();
+            }
+            _ => return Err(name.into()),
+        }
+        Ok(())
+    }
+    pub fn with_name(mut self, name: impl Into<String>) -> Result<Self, (Self, String)> {
+        match &mut self {
+            Self::Circle { name: name_binding, .. } => {
+                *name_binding = name.into();
+            }
+            _ => return Err((self, name.into())),
+        }
+        Ok(self)
+    }
+}
@@ -0,0 +1,29 @@
+/// Enum: fallible set, with, into_field, and take for partial-coverage fields
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(get, set, with, into_field, take, fallible)]
+enum Event {
+    Click {
+        x: i32,
+        y: i32,
+        button: u8,
+        label: Option<String>,
+    },
+    Move {
+        x: i32,
+        y: i32,
+    },
+}
+
+/// Enum: fallible per field, with into and chain = false
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(set(chain = false), with)]
+enum Shape {
+    Circle {
+        #[field(fallible, into)]
+        name: String,
+        radius: f64,
+    },
+    Square {
+        side: f64,
+    },
+}
@@ -0,0 +1,59 @@
+use std::fmt::Display;
+/// Enum: differing types unified by deref, with partial coverage
+#[fieldwork(get, set)]
+enum Message {
+    V1 { #[field(get(deref = "str"))] name: String, tag: Option<String> },
+    V2 { name: Box<str>, #[field(deref = "str")] tag: Option<std::sync::Arc<str>> },
+    V3 { name: std::rc::Rc<str>, #[field(get(deref = "[u8]"))] payload: Vec<u8> },
+    V4 { payload: [u8; 4] },
+}
+impl Message {
+    pub fn name(&self) -> Option<&str> {
+        match self {
+            Self::V1 { name, .. } => Some(name),
+            Self::V2 { name, .. } => Some(name),
+            Self::V3 { name, .. } => Some(name),
+            _ => None,
+        }
+    }
+    pub fn payload(&self) -> Option<&[u8]> {
+        match self {
+            Self::V3 { payload, .. } => Some(payload),
+            Self::V4 { payload, .. } => Some(payload),
+            _ => None,
+        }
+    }
+    pub fn tag(&self) -> Option<&str> {
+        match self {
+            Self::V1 { tag, .. } => tag.as_deref(),
+            Self::V2 { tag, .. } => tag.as_deref(),
+            _ => None,
+        }
+    }
+}
+/// Enum: differing types unified by `as`, including on a struct
+#[fieldwork(get)]
+enum Reading {
+    Count { #[field(get(as = "&dyn Display"))] value: u64 },
+    Label { value: String },
+    Missing,
+}
+impl Reading {
+    pub fn value(&self) -> Option<&dyn Display> {
+        match self {
+            Self::Count { value, .. } => Some(value),
+            Self::Label { value, .. } => Some(value),
+            _ => None,
+        }
+    }
+}
+#[fieldwork(get)]
+struct Labeled {
+    #[field(get(as = "&dyn Display"))]
+    label: String,
+}
+impl Labeled {
+    pub fn label(&self) -> &dyn Display {
+        &self.label
+    }
+}
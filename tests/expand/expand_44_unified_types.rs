@@ -0,0 +1,46 @@
+use std::fmt::Display;
+
+/// Enum: differing types unified by deref, with partial coverage
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(get, set)]
+enum Message {
+    V1 {
+        #[field(get(deref = "str"))]
+        name: String,
+        tag: Option<String>,
+    },
+    V2 {
+        name: Box<str>,
+        #[field(deref = "str")]
+        tag: Option<std::sync::Arc<str>>,
+    },
+    V3 {
+        name: std::rc::Rc<str>,
+        #[field(get(deref = "[u8]"))]
+        payload: Vec<u8>,
+    },
+    V4 {
+        payload: [u8; 4],
+    },
+}
+
+/// Enum: differing types unified by `as`, including on a struct
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(get)]
+enum Reading {
+    Count {
+        #[field(get(as = "&dyn Display"))]
+        value: u64,
+    },
+    Label {
+        value: String,
+    },
+    Missing,
+}
+
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(get)]
+struct Labeled {
+    #[field(get(as = "&dyn Display"))]
+    label: String,
+}
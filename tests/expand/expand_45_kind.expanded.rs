@@ -0,0 +1,220 @@
+/// Enum: kind enum with default name and derives
+#[fieldwork(kind)]
+enum Event {
+    Click { x: i32, y: i32 },
+    KeyPress(char),
+    Close,
+}
+impl Event {
+    ///Returns the [`EventKind`] of this `Event`
+    pub fn kind(&self) -> EventKind {
+        match *self {
+            Self::Click { .. } => EventKind::Click,
+            Self::KeyPress(..) => EventKind::KeyPress,
+            Self::Close => EventKind::Close,
+        }
+    }
+    ///Returns the name of this `Event`'s variant
+    pub fn variant_name(&self) -> &'static str {
+        match *self {
+            Self::Click { .. } => "Click",
+            Self::KeyPress(..) => "KeyPress",
+            Self::Close => "Close",
+        }
+    }
+}
+///The variants of [`Event`], without their fields
+pub enum EventKind {
+    ///[`Event::Click`]
+    Click,
+    ///[`Event::KeyPress`]
+    KeyPress,
+    ///[`Event::Close`]
+    Close,
+}
+#[automatically_derived]
+#[doc(hidden)]
+unsafe impl ::core::clone::TrivialClone for EventKind {}
+#[automatically_derived]
+impl ::core::clone::Clone for EventKind {
+    #[inline]
+    fn clone(&self) -> EventKind {
+        *self
+    }
+}
+#[automatically_derived]
+impl ::core::marker::Copy for EventKind {}
+#[automatically_derived]
+impl ::core::marker::StructuralPartialEq for EventKind {}
+#[automatically_derived]
+impl ::core::cmp::PartialEq for EventKind {
+    #[inline]
+    fn eq(&self, other: &EventKind) -> bool {
+        let __self_discr = ::core::intrinsics::discriminant_value(self);
+        let __arg1_discr = ::core::intrinsics::discriminant_value(other);
+        __self_discr == __arg1_discr
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::Eq for EventKind {
+    #[inline]
+    #[doc(hidden)]
+    #[coverage(off)]
+    fn assert_fields_are_eq(&self) {}
+}
+#[automatically_derived]
+impl ::core::hash::Hash for EventKind {
+    #[inline]
+    fn hash<__H: ::core::hash::Hasher>(&self, state: &mut __H) {
+        let __self_discr = ::core::intrinsics::discriminant_value(self);
+        ::core::hash::Hash::hash(&__self_discr, state)
+    }
+}
+#[automatically_derived]
+impl ::core::fmt::Debug for EventKind {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::write_str(
+            f,
+            match self {
+                EventKind::Click => "Click",
+                EventKind::KeyPress => "KeyPress",
+                EventKind::Close => "Close",
+            },
+        )
+    }
+}
+/// Enum: kind enum with explicit name, derives, and vis, alongside generics
+#[fieldwork(kind(name = ResponseStatus, derive(Clone, Copy, Debug)), vis = "pub(crate)")]
+enum Response<T> {
+    Success(T),
+    Failure { code: u16 },
+}
+impl<T> Response<T> {
+    ///Returns the [`ResponseStatus`] of this `Response`
+    pub(crate) fn kind(&self) -> ResponseStatus {
+        match *self {
+            Self::Success(..) => ResponseStatus::Success,
+            Self::Failure { .. } => ResponseStatus::Failure,
+        }
+    }
+    ///Returns the name of this `Response`'s variant
+    pub(crate) fn variant_name(&self) -> &'static str {
+        match *self {
+            Self::Success(..) => "Success",
+            Self::Failure { .. } => "Failure",
+        }
+    }
+}
+///The variants of [`Response`], without their fields
+pub(crate) enum ResponseStatus {
+    ///[`Response::Success`]
+    Success,
+    ///[`Response::Failure`]
+    Failure,
+}
+#[automatically_derived]
+#[doc(hidden)]
+unsafe impl ::core::clone::TrivialClone for ResponseStatus {}
+#[automatically_derived]
+impl ::core::clone::Clone for ResponseStatus {
+    #[inline]
+    fn clone(&self) -> ResponseStatus {
+        *self
+    }
+}
+#[automatically_derived]
+impl ::core::marker::Copy for ResponseStatus {}
+#[automatically_derived]
+impl ::core::fmt::Debug for ResponseStatus {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::write_str(
+            f,
+            match self {
+                ResponseStatus::Success => "Success",
+                ResponseStatus::Failure => "Failure",
+            },
+        )
+    }
+}
+/// Enum: kind name from a string
+#[fieldwork(kind = "Flavor")]
+enum IceCream {
+    Vanilla,
+    Chocolate,
+}
+impl IceCream {
+    ///Returns the [`Flavor`] of this `IceCream`
+    pub fn kind(&self) -> Flavor {
+        match *self {
+            Self::Vanilla => Flavor::Vanilla,
+            Self::Chocolate => Flavor::Chocolate,
+        }
+    }
+    ///Returns the name of this `IceCream`'s variant
+    pub fn variant_name(&self) -> &'static str {
+        match *self {
+            Self::Vanilla => "Vanilla",
+            Self::Chocolate => "Chocolate",
+        }
+    }
+}
+///The variants of [`IceCream`], without their fields
+pub enum Flavor {
+    ///[`IceCream::Vanilla`]
+    Vanilla,
+    ///[`IceCream::Chocolate`]
+    Chocolate,
+}
+#[automatically_derived]
+#[doc(hidden)]
+unsafe impl ::core::clone::TrivialClone for Flavor {}
+#[automatically_derived]
+impl ::core::clone::Clone for Flavor {
+    #[inline]
+    fn clone(&self) -> Flavor {
+        *self
+    }
+}
+#[automatically_derived]
+impl ::core::marker::Copy for Flavor {}
+#[automatically_derived]
+impl ::core::marker::StructuralPartialEq for Flavor {}
+#[automatically_derived]
+impl ::core::cmp::PartialEq for Flavor {
+    #[inline]
+    fn eq(&self, other: &Flavor) -> bool {
+        let __self_discr = ::core::intrinsics::discriminant_value(self);
+        let __arg1_discr = ::core::intrinsics::discriminant_value(other);
+        __self_discr == __arg1_discr
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::Eq for Flavor {
+    #[inline]
+    #[doc(hidden)]
+    #[coverage(off)]
+    fn assert_fields_are_eq(&self) {}
+}
+#[automatically_derived]
+impl ::core::hash::Hash for Flavor {
+    #[inline]
+    fn hash<__H: ::core::hash::Hasher>(&self, state: &mut __H) {
+        let __self_discr = ::core::intrinsics::discriminant_value(self);
+        ::core::hash::Hash::hash(&__self_discr, state)
+    }
+}
+#[automatically_derived]
+impl ::core::fmt::Debug for Flavor {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::write_str(
+            f,
+            match self {
+                Flavor::Vanilla => "Vanilla",
+                Flavor::Chocolate => "Chocolate",
+            },
+        )
+    }
+}
@@ -0,0 +1,24 @@
+/// Enum: kind enum with default name and derives
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(kind)]
+enum Event {
+    Click { x: i32, y: i32 },
+    KeyPress(char),
+    Close,
+}
+
+/// Enum: kind enum with explicit name, derives, and vis, alongside generics
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(kind(name = ResponseStatus, derive(Clone, Copy, Debug)), vis = "pub(crate)")]
+enum Response<T> {
+    Success(T),
+    Failure { code: u16 },
+}
+
+/// Enum: kind name from a string
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(kind = "Flavor")]
+enum IceCream {
+    Vanilla,
+    Chocolate,
+}
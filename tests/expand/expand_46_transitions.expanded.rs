@@ -0,0 +1,85 @@
+/// Enum: transitions carry over shared fields and take the rest as arguments
+#[fieldwork(
+    transition(from = Pending, to = Active),
+    transition(from = Active, to = Finished),
+    transition(from = Active, to = Pending, name = reset)
+)]
+enum Job {
+    Pending { id: u64, name: String },
+    Active { id: u64, name: String, started_at: u64 },
+    Finished { id: u64, exit_code: i32 },
+}
+impl Job {
+    ///Moves `Self::Pending` to `Self::Active`, carrying over `id` and `name`, or returns `Err(self)` if this is another variant
+    pub fn into_active(self, started_at: u64) -> Result<Self, Self> {
+        match self {
+            Self::Pending { id, name, .. } => {
+                Ok(Self::Active {
+                    id,
+                    name,
+                    started_at,
+                })
+            }
+            other => Err(other),
+        }
+    }
+    ///Moves `Self::Active` to `Self::Finished`, carrying over `id`, or returns `Err(self)` if this is another variant
+    pub fn into_finished(self, exit_code: i32) -> Result<Self, Self> {
+        match self {
+            Self::Active { id, .. } => Ok(Self::Finished { id, exit_code }),
+            other => Err(other),
+        }
+    }
+    ///Moves `Self::Active` to `Self::Pending`, carrying over `id` and `name`, or returns `Err(self)` if this is another variant
+    pub fn reset(self) -> Result<Self, Self> {
+        match self {
+            Self::Active { id, name, .. } => Ok(Self::Pending { id, name }),
+            other => Err(other),
+        }
+    }
+}
+/// Enum: transitions between tuple, renamed, and unit variants
+#[fieldwork(
+    vis = "pub(crate)",
+    transition(from = Connecting, to = Connected),
+    transition(from = Connected, to = Closed),
+    transition(from = Closed, to = Connecting)
+)]
+enum Connection<T> {
+    Connecting(#[field = "address"] String, u8),
+    #[variant(rename = open)]
+    Connected { #[field = "address"] peer: String, stream: T },
+    Closed,
+}
+impl<T> Connection<T> {
+    ///Moves `Self::Connecting` to `Self::Connected`, carrying over `address`, or returns `Err(self)` if this is another variant
+    pub(crate) fn into_open(self, stream: T) -> Result<Self, Self> {
+        match self {
+            Self::Connecting(address, ..) => {
+                Ok(Self::Connected {
+                    peer: address,
+                    stream,
+                })
+            }
+            other => Err(other),
+        }
+    }
+    ///Moves `Self::Connected` to `Self::Closed`, or returns `Err(self)` if this is another variant
+    pub(crate) fn into_closed(self) -> Result<Self, Self> {
+        match self {
+            Self::Connected { .. } => Ok(Self::Closed),
+            other => Err(other),
+        }
+    }
+    ///Moves `Self::Closed` to `Self::Connecting`, or returns `Err(self)` if this is another variant
+    pub(crate) fn into_connecting(
+        self,
+        address: String,
+        field_1: u8,
+    ) -> Result<Self, Self> {
+        match self {
+            Self::Closed => Ok(Self::Connecting(address, field_1)),
+            other => Err(other),
+        }
+    }
+}
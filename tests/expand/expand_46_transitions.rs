@@ -0,0 +1,41 @@
+/// Enum: transitions carry over shared fields and take the rest as arguments
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(
+    transition(from = Pending, to = Active),
+    transition(from = Active, to = Finished),
+    transition(from = Active, to = Pending, name = reset)
+)]
+enum Job {
+    Pending {
+        id: u64,
+        name: String,
+    },
+    Active {
+        id: u64,
+        name: String,
+        started_at: u64,
+    },
+    Finished {
+        id: u64,
+        exit_code: i32,
+    },
+}
+
+/// Enum: transitions between tuple, renamed, and unit variants
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(
+    vis = "pub(crate)",
+    transition(from = Connecting, to = Connected),
+    transition(from = Connected, to = Closed),
+    transition(from = Closed, to = Connecting)
+)]
+enum Connection<T> {
+    Connecting(#[field = "address"] String, u8),
+    #[variant(rename = open)]
+    Connected {
+        #[field = "address"]
+        peer: String,
+        stream: T,
+    },
+    Closed,
+}
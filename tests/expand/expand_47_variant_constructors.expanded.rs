@@ -0,0 +1,54 @@
+/// Enum: a constructor for each variant, with defaults, into, and skips
+#[fieldwork(new(into))]
+enum Event {
+    Click { x: i32, y: i32, #[field(default = 1)] button: u8 },
+    KeyPress(#[field(new(into = false))] char),
+    Message(#[field = "text"] String, #[field(default)] Vec<String>),
+    Close,
+    #[variant(skip)]
+    Unknown,
+}
+impl Event {
+    ///Constructs a new `Event::Click`
+    pub fn new_click(x: impl Into<i32>, y: impl Into<i32>) -> Self {
+        Self::Click {
+            x: x.into(),
+            y: y.into(),
+            button: 1,
+        }
+    }
+    ///Constructs a new `Event::KeyPress`
+    pub fn new_key_press(field_0: char) -> Self {
+        Self::KeyPress(field_0)
+    }
+    ///Constructs a new `Event::Message`
+    pub fn new_message(text: impl Into<String>) -> Self {
+        Self::Message(text.into(), Default::default())
+    }
+    ///Constructs a new `Event::Close`
+    pub fn new_close() -> Self {
+        Self::Close
+    }
+}
+/// Enum: constructors named by template, with variant-level renames, vis, and opt-outs
+#[fieldwork(new(template = "{}"), vis = "pub(crate)")]
+enum Shape<T> {
+    #[variant(rename = circle, new(into))]
+    Round { radius: T },
+    #[variant(vis = "pub")]
+    Square { side: T },
+    #[variant(new = false)]
+    Empty,
+}
+impl<T> Shape<T> {
+    ///Constructs a new `Shape::Round`
+    pub(crate) fn circle(radius: impl Into<T>) -> Self {
+        Self::Round {
+            radius: radius.into(),
+        }
+    }
+    ///Constructs a new `Shape::Square`
+    pub fn square(side: T) -> Self {
+        Self::Square { side }
+    }
+}
@@ -0,0 +1,28 @@
+/// Enum: a constructor for each variant, with defaults, into, and skips
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(new(into))]
+enum Event {
+    Click {
+        x: i32,
+        y: i32,
+        #[field(default = 1)]
+        button: u8,
+    },
+    KeyPress(#[field(new(into = false))] char),
+    Message(#[field = "text"] String, #[field(default)] Vec<String>),
+    Close,
+    #[variant(skip)]
+    Unknown,
+}
+
+/// Enum: constructors named by template, with variant-level renames, vis, and opt-outs
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(new(template = "{}"), vis = "pub(crate)")]
+enum Shape<T> {
+    #[variant(rename = circle, new(into))]
+    Round { radius: T },
+    #[variant(vis = "pub")]
+    Square { side: T },
+    #[variant(new = false)]
+    Empty,
+}
@@ -0,0 +1,53 @@
+/// Enum: tuple-variant fields named by position across every variant
+#[fieldwork(get, set, positional(0 = id, 1 = payload))]
+enum Message {
+    Request(u64, Vec<u8>),
+    Response(u64, Vec<u8>, #[field = "status"] u16),
+    Ack(u64),
+    Ping { id: u64 },
+    Close,
+}
+impl Message {
+    pub fn id(&self) -> Option<u64> {
+        match self {
+            Self::Request(id, ..) => Some(*id),
+            Self::Response(id, ..) => Some(*id),
+            Self::Ack(id, ..) => Some(*id),
+            Self::Ping { id, .. } => Some(*id),
+            _ => None,
+        }
+    }
+    pub fn payload(&self) -> Option<&[u8]> {
+        match self {
+            Self::Request(_, payload, ..) => Some(&**payload),
+            Self::Response(_, payload, ..) => Some(&**payload),
+            _ => None,
+        }
+    }
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            Self::Response(_, _, status, ..) => Some(*status),
+            _ => None,
+        }
+    }
+}
+/// Enum: a per-field name takes precedence over the positional one
+#[fieldwork(get, positional(0 = "key"))]
+enum Entry {
+    Occupied(String, u32),
+    Vacant(#[field = "name"] String),
+}
+impl Entry {
+    pub fn key(&self) -> Option<&str> {
+        match self {
+            Self::Occupied(key, ..) => Some(&**key),
+            _ => None,
+        }
+    }
+    pub fn name(&self) -> Option<&str> {
+        match self {
+            Self::Vacant(name, ..) => Some(&**name),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,18 @@
+/// Enum: tuple-variant fields named by position across every variant
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(get, set, positional(0 = id, 1 = payload))]
+enum Message {
+    Request(u64, Vec<u8>),
+    Response(u64, Vec<u8>, #[field = "status"] u16),
+    Ack(u64),
+    Ping { id: u64 },
+    Close,
+}
+
+/// Enum: a per-field name takes precedence over the positional one
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(get, positional(0 = "key"))]
+enum Entry {
+    Occupied(String, u32),
+    Vacant(#[field = "name"] String),
+}
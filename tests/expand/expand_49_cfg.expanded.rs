@@ -0,0 +1,163 @@
+/// Struct: fields removed by `#[cfg]` get no methods, and others keep theirs
+#[fieldwork(get, set, new)]
+struct Config {
+    name: String,
+    port: u16,
+}
+impl Config {
+    ///Constructs a new `Config`
+    pub fn new(name: String, port: u16) -> Self {
+        Self { name, port }
+    }
+    pub fn name(&self) -> &str {
+        &*self.name
+    }
+    pub fn set_name(&mut self, name: String) -> &mut Self {
+        self.name = name;
+        self
+    }
+    pub fn port(&self) -> u16 {
+        self.port
+    }
+    pub fn set_port(&mut self, port: u16) -> &mut Self {
+        self.port = port;
+        self
+    }
+}
+/// Enum: a variant removed by `#[cfg]` leaves a single-variant enum
+#[fieldwork(get, set, variants)]
+enum Backend {
+    Memory { capacity: usize },
+}
+impl Backend {
+    ///Returns `true` if this is `Self::Memory`
+    pub fn is_memory(&self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Memory { .. } => true,
+            _ => false,
+        }
+    }
+    ///Borrows the fields of `Self::Memory`, if this is one
+    pub fn as_memory(&self) -> Option<&usize> {
+        match self {
+            Self::Memory { capacity } => Some(capacity),
+        }
+    }
+    ///Mutably borrows the fields of `Self::Memory`, if this is one
+    pub fn as_memory_mut(&mut self) -> Option<&mut usize> {
+        match self {
+            Self::Memory { capacity } => Some(capacity),
+        }
+    }
+    ///Consumes self, returning the fields of `Self::Memory`, or `Err(self)` if this is another variant
+    pub fn into_memory(self) -> Result<usize, Self> {
+        match self {
+            Self::Memory { capacity } => Ok(capacity),
+        }
+    }
+    pub fn capacity(&self) -> usize {
+        match self {
+            Self::Memory { capacity, .. } => *capacity,
+        }
+    }
+    pub fn set_capacity(&mut self, capacity: usize) -> &mut Self {
+        match self {
+            Self::Memory { capacity: capacity_binding, .. } => {
+                *capacity_binding = capacity;
+            }
+        }
+        self
+    }
+}
+/// Enum: coverage reflects the variants and fields that remain
+#[fieldwork(get, kind)]
+enum Event {
+    Click { x: i32 },
+    Move { x: i32, y: i32 },
+}
+impl Event {
+    ///Returns the [`EventKind`] of this `Event`
+    pub fn kind(&self) -> EventKind {
+        match *self {
+            Self::Click { .. } => EventKind::Click,
+            Self::Move { .. } => EventKind::Move,
+        }
+    }
+    ///Returns the name of this `Event`'s variant
+    pub fn variant_name(&self) -> &'static str {
+        match *self {
+            Self::Click { .. } => "Click",
+            Self::Move { .. } => "Move",
+        }
+    }
+    pub fn x(&self) -> i32 {
+        match self {
+            Self::Click { x, .. } | Self::Move { x, .. } => *x,
+        }
+    }
+    pub fn y(&self) -> Option<i32> {
+        match self {
+            Self::Move { y, .. } => Some(*y),
+            _ => None,
+        }
+    }
+}
+///The variants of [`Event`], without their fields
+pub enum EventKind {
+    ///[`Event::Click`]
+    Click,
+    ///[`Event::Move`]
+    Move,
+}
+#[automatically_derived]
+#[doc(hidden)]
+unsafe impl ::core::clone::TrivialClone for EventKind {}
+#[automatically_derived]
+impl ::core::clone::Clone for EventKind {
+    #[inline]
+    fn clone(&self) -> EventKind {
+        *self
+    }
+}
+#[automatically_derived]
+impl ::core::marker::Copy for EventKind {}
+#[automatically_derived]
+impl ::core::marker::StructuralPartialEq for EventKind {}
+#[automatically_derived]
+impl ::core::cmp::PartialEq for EventKind {
+    #[inline]
+    fn eq(&self, other: &EventKind) -> bool {
+        let __self_discr = ::core::intrinsics::discriminant_value(self);
+        let __arg1_discr = ::core::intrinsics::discriminant_value(other);
+        __self_discr == __arg1_discr
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::Eq for EventKind {
+    #[inline]
+    #[doc(hidden)]
+    #[coverage(off)]
+    fn assert_fields_are_eq(&self) {}
+}
+#[automatically_derived]
+impl ::core::hash::Hash for EventKind {
+    #[inline]
+    fn hash<__H: ::core::hash::Hasher>(&self, state: &mut __H) {
+        let __self_discr = ::core::intrinsics::discriminant_value(self);
+        ::core::hash::Hash::hash(&__self_discr, state)
+    }
+}
+#[automatically_derived]
+impl ::core::fmt::Debug for EventKind {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::write_str(
+            f,
+            match self {
+                EventKind::Click => "Click",
+                EventKind::Move => "Move",
+            },
+        )
+    }
+}
@@ -0,0 +1,40 @@
+/// Struct: fields removed by `#[cfg]` get no methods, and others keep theirs
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(get, set, new)]
+struct Config {
+    name: String,
+    #[cfg(any())]
+    secret: String,
+    port: u16,
+}
+
+/// Enum: a variant removed by `#[cfg]` leaves a single-variant enum
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(get, set, variants)]
+enum Backend {
+    Memory {
+        capacity: usize,
+    },
+    #[cfg(any())]
+    Disk {
+        capacity: usize,
+        path: String,
+    },
+}
+
+/// Enum: coverage reflects the variants and fields that remain
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(get, kind)]
+enum Event {
+    Click {
+        x: i32,
+        #[cfg(any())]
+        y: i32,
+    },
+    Move {
+        x: i32,
+        y: i32,
+    },
+    #[cfg(any())]
+    Close,
+}
@@ -0,0 +1,107 @@
+/// Struct: configuration split across several attributes is merged
+#[fieldwork(get, set)]
+#[fieldwork(with, vis = "pub(crate)")]
+struct User {
+    /// the user's name
+    #[field(rename = username)]
+    #[field(set(into), with = false)]
+    name: String,
+    #[field(get(copy = false))]
+    #[field(set = false)]
+    id: u64,
+}
+impl User {
+    ///Borrows the user's name
+    pub(crate) fn username(&self) -> &str {
+        &*self.name
+    }
+    ///Sets the user's name, returning `&mut Self` for chaining
+    pub(crate) fn set_username(&mut self, username: impl Into<String>) -> &mut Self {
+        self.name = username.into();
+        self
+    }
+    pub(crate) fn id(&self) -> &u64 {
+        &self.id
+    }
+    #[must_use]
+    pub(crate) fn with_id(mut self, id: u64) -> Self {
+        self.id = id;
+        self
+    }
+}
+/// Enum: variant and field attributes merge the same way
+#[fieldwork(get)]
+#[fieldwork(variants)]
+enum Shape {
+    #[variant = "round"]
+    #[variant(vis = "pub(crate)")]
+    Circle { radius: f64 },
+    Square { #[field = "radius"] #[field(get(copy = false))] half_side: f64 },
+}
+impl Shape {
+    ///Returns `true` if this is `Self::Circle`
+    pub(crate) fn is_round(&self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Circle { .. } => true,
+            _ => false,
+        }
+    }
+    ///Borrows the fields of `Self::Circle`, if this is one
+    pub(crate) fn as_round(&self) -> Option<&f64> {
+        match self {
+            Self::Circle { radius } => Some(radius),
+            _ => None,
+        }
+    }
+    ///Mutably borrows the fields of `Self::Circle`, if this is one
+    pub(crate) fn as_round_mut(&mut self) -> Option<&mut f64> {
+        match self {
+            Self::Circle { radius } => Some(radius),
+            _ => None,
+        }
+    }
+    ///Consumes self, returning the fields of `Self::Circle`, or `Err(self)` if this is another variant
+    pub(crate) fn into_round(self) -> Result<f64, Self> {
+        match self {
+            Self::Circle { radius } => Ok(radius),
+            other => Err(other),
+        }
+    }
+    ///Returns `true` if this is `Self::Square`
+    pub fn is_square(&self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Square { .. } => true,
+            _ => false,
+        }
+    }
+    ///Borrows the fields of `Self::Square`, if this is one
+    pub fn as_square(&self) -> Option<&f64> {
+        match self {
+            Self::Square { half_side } => Some(half_side),
+            _ => None,
+        }
+    }
+    ///Mutably borrows the fields of `Self::Square`, if this is one
+    pub fn as_square_mut(&mut self) -> Option<&mut f64> {
+        match self {
+            Self::Square { half_side } => Some(half_side),
+            _ => None,
+        }
+    }
+    ///Consumes self, returning the fields of `Self::Square`, or `Err(self)` if this is another variant
+    pub fn into_square(self) -> Result<f64, Self> {
+        match self {
+            Self::Square { half_side } => Ok(half_side),
+            other => Err(other),
+        }
+    }
+    pub(crate) fn radius(&self) -> &f64 {
+        match self {
+            Self::Circle { radius, .. } | Self::Square { half_side: radius, .. } => {
+                radius
+            }
+        }
+    }
+}
@@ -0,0 +1,30 @@
+/// Struct: configuration split across several attributes is merged
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(get, set)]
+#[fieldwork(with, vis = "pub(crate)")]
+struct User {
+    /// the user's name
+    #[field(rename = username)]
+    #[field(set(into), with = false)]
+    name: String,
+
+    #[field(get(copy = false))]
+    #[cfg_attr(all(), field(set = false))]
+    #[cfg_attr(any(), field(skip))]
+    id: u64,
+}
+
+/// Enum: variant and field attributes merge the same way
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(get)]
+#[fieldwork(variants)]
+enum Shape {
+    #[variant = "round"]
+    #[variant(vis = "pub(crate)")]
+    Circle { radius: f64 },
+    Square {
+        #[field = "radius"]
+        #[field(get(copy = false))]
+        half_side: f64,
+    },
+}
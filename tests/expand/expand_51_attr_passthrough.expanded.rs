@@ -0,0 +1,159 @@
+/// Struct: attributes passed through to generated methods at every level
+#[fieldwork(set, new, attr(allow(clippy::needless_pass_by_value)))]
+#[fieldwork(with(attr(must_use = "with consumes the user")))]
+#[fieldwork(get(attr(inline, must_use)))]
+struct User {
+    /// the user's name
+    #[field(set(attr(doc(hidden))))]
+    name: String,
+    /// the user's id
+    #[field(attr(track_caller), get(attr(inline)))]
+    id: u64,
+}
+impl User {
+    ///Constructs a new `User`
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn new(name: String, id: u64) -> Self {
+        Self { name, id }
+    }
+    ///Borrows the user's name
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &*self.name
+    }
+    ///Sets the user's name, returning `&mut Self` for chaining
+    #[allow(clippy::needless_pass_by_value)]
+    #[doc(hidden)]
+    pub fn set_name(&mut self, name: String) -> &mut Self {
+        self.name = name;
+        self
+    }
+    ///Owned chainable setter for the user's name, returning `Self`
+    #[allow(clippy::needless_pass_by_value)]
+    #[must_use = "with consumes the user"]
+    pub fn with_name(mut self, name: String) -> Self {
+        self.name = name;
+        self
+    }
+    ///Returns a copy of the user's id
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+    ///Sets the user's id, returning `&mut Self` for chaining
+    #[allow(clippy::needless_pass_by_value)]
+    #[track_caller]
+    pub fn set_id(&mut self, id: u64) -> &mut Self {
+        self.id = id;
+        self
+    }
+    ///Owned chainable setter for the user's id, returning `Self`
+    #[allow(clippy::needless_pass_by_value)]
+    #[must_use = "with consumes the user"]
+    #[track_caller]
+    pub fn with_id(mut self, id: u64) -> Self {
+        self.id = id;
+        self
+    }
+}
+/// Enum: variants pass attributes through too
+#[fieldwork(get, variants)]
+#[fieldwork(attr(inline))]
+enum Shape {
+    #[variant(attr(doc(hidden)))]
+    Circle { radius: f64 },
+    Square { #[field(get(attr(must_use)))] side: f64 },
+}
+impl Shape {
+    ///Returns `true` if this is `Self::Circle`
+    #[inline]
+    #[doc(hidden)]
+    pub fn is_circle(&self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Circle { .. } => true,
+            _ => false,
+        }
+    }
+    ///Borrows the fields of `Self::Circle`, if this is one
+    #[inline]
+    #[doc(hidden)]
+    pub fn as_circle(&self) -> Option<&f64> {
+        match self {
+            Self::Circle { radius } => Some(radius),
+            _ => None,
+        }
+    }
+    ///Mutably borrows the fields of `Self::Circle`, if this is one
+    #[inline]
+    #[doc(hidden)]
+    pub fn as_circle_mut(&mut self) -> Option<&mut f64> {
+        match self {
+            Self::Circle { radius } => Some(radius),
+            _ => None,
+        }
+    }
+    ///Consumes self, returning the fields of `Self::Circle`, or `Err(self)` if this is another variant
+    #[inline]
+    #[doc(hidden)]
+    pub fn into_circle(self) -> Result<f64, Self> {
+        match self {
+            Self::Circle { radius } => Ok(radius),
+            other => Err(other),
+        }
+    }
+    ///Returns `true` if this is `Self::Square`
+    #[inline]
+    pub fn is_square(&self) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match self {
+            Self::Square { .. } => true,
+            _ => false,
+        }
+    }
+    ///Borrows the fields of `Self::Square`, if this is one
+    #[inline]
+    pub fn as_square(&self) -> Option<&f64> {
+        match self {
+            Self::Square { side } => Some(side),
+            _ => None,
+        }
+    }
+    ///Mutably borrows the fields of `Self::Square`, if this is one
+    #[inline]
+    pub fn as_square_mut(&mut self) -> Option<&mut f64> {
+        match self {
+            Self::Square { side } => Some(side),
+            _ => None,
+        }
+    }
+    ///Consumes self, returning the fields of `Self::Square`, or `Err(self)` if this is another variant
+    #[inline]
+    pub fn into_square(self) -> Result<f64, Self> {
+        match self {
+            Self::Square { side } => Ok(side),
+            other => Err(other),
+        }
+    }
+    #[inline]
+    #[doc(hidden)]
+    pub fn radius(&self) -> Option<f64> {
+        match self {
+            Self::Circle { radius, .. } => Some(*radius),
+            _ => None,
+        }
+    }
+    #[inline]
+    #[must_use]
+    pub fn side(&self) -> Option<f64> {
+        match self {
+            Self::Square { side, .. } => Some(*side),
+            _ => None,
+        }
+    }
+}
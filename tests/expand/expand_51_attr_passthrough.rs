@@ -0,0 +1,27 @@
+/// Struct: attributes passed through to generated methods at every level
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(set, new, attr(allow(clippy::needless_pass_by_value)))]
+#[fieldwork(with(attr(must_use = "with consumes the user")))]
+#[fieldwork(get(attr(inline, must_use)))]
+struct User {
+    /// the user's name
+    #[field(set(attr(doc(hidden))))]
+    name: String,
+
+    /// the user's id
+    #[field(attr(track_caller), get(attr(inline)))]
+    id: u64,
+}
+
+/// Enum: variants pass attributes through too
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(get, variants)]
+#[fieldwork(attr(inline))]
+enum Shape {
+    #[variant(attr(doc(hidden)))]
+    Circle { radius: f64 },
+    Square {
+        #[field(get(attr(must_use)))]
+        side: f64,
+    },
+}
@@ -0,0 +1,72 @@
+/// Struct: const getters for copies and plain borrows
+#[fieldwork(get(const), option_predicates)]
+struct Config {
+    /// the port
+    port: u16,
+    /// the timeout in seconds
+    timeout: Option<u64>,
+    /// the name
+    #[field(deref = false)]
+    name: String,
+    /// the fallback, which can't be const
+    #[field(get(const = false))]
+    fallback: Option<String>,
+}
+impl Config {
+    ///Returns a copy of the port
+    pub const fn port(&self) -> u16 {
+        self.port
+    }
+    ///Returns a copy of the timeout in seconds
+    pub const fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+    /**Returns `true` if `timeout` is `Some`
+
+the timeout in seconds*/
+    pub const fn has_timeout(&self) -> bool {
+        self.timeout.is_some()
+    }
+    ///Borrows the name
+    pub const fn name(&self) -> &String {
+        &self.name
+    }
+    ///Borrows the fallback, which can't be const
+    pub fn fallback(&self) -> Option<&str> {
+        self.fallback.as_deref()
+    }
+    /**Returns `true` if `fallback` is `Some`
+
+the fallback, which can't be const*/
+    pub fn has_fallback(&self) -> bool {
+        self.fallback.is_some()
+    }
+}
+const fn port_and_timeout(config: &Config) -> (u16, bool) {
+    (config.port(), config.has_timeout())
+}
+/// Enum: const getters match on self
+#[fieldwork(get)]
+enum Shape {
+    Circle { #[field(get(const))] radius: f64, #[field(get(const))] origin: (f64, f64) },
+    Square { side: f64, origin: (f64, f64) },
+}
+impl Shape {
+    pub const fn origin(&self) -> &(f64, f64) {
+        match self {
+            Self::Circle { origin, .. } | Self::Square { origin, .. } => origin,
+        }
+    }
+    pub const fn radius(&self) -> Option<f64> {
+        match self {
+            Self::Circle { radius, .. } => Some(*radius),
+            _ => None,
+        }
+    }
+    pub fn side(&self) -> Option<f64> {
+        match self {
+            Self::Square { side, .. } => Some(*side),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,38 @@
+/// Struct: const getters for copies and plain borrows
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(get(const), option_predicates)]
+struct Config {
+    /// the port
+    port: u16,
+
+    /// the timeout in seconds
+    timeout: Option<u64>,
+
+    /// the name
+    #[field(deref = false)]
+    name: String,
+
+    /// the fallback, which can't be const
+    #[field(get(const = false))]
+    fallback: Option<String>,
+}
+
+const fn port_and_timeout(config: &Config) -> (u16, bool) {
+    (config.port(), config.has_timeout())
+}
+
+/// Enum: const getters match on self
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(get)]
+enum Shape {
+    Circle {
+        #[field(get(const))]
+        radius: f64,
+        #[field(get(const))]
+        origin: (f64, f64),
+    },
+    Square {
+        side: f64,
+        origin: (f64, f64),
+    },
+}
@@ -0,0 +1,79 @@
+/// Struct: methods with their own bounds go in impl blocks of their own
+#[fieldwork(get, set, with(where = "T: Default"))]
+struct Container<T> {
+    /// the value
+    #[field(get(clone, where = "T: Clone"))]
+    value: T,
+    /// the count
+    count: usize,
+}
+impl<T> Container<T> {
+    ///Sets the value, returning `&mut Self` for chaining
+    pub fn set_value(&mut self, value: T) -> &mut Self {
+        self.value = value;
+        self
+    }
+    ///Returns a copy of the count
+    pub fn count(&self) -> usize {
+        self.count
+    }
+    ///Sets the count, returning `&mut Self` for chaining
+    pub fn set_count(&mut self, count: usize) -> &mut Self {
+        self.count = count;
+        self
+    }
+}
+impl<T> Container<T>
+where
+    T: Clone,
+{
+    ///Returns a clone of the value
+    pub fn value(&self) -> T {
+        self.value.clone()
+    }
+}
+impl<T> Container<T>
+where
+    T: Default,
+{
+    ///Owned chainable setter for the value, returning `Self`
+    #[must_use]
+    pub fn with_value(mut self, value: T) -> Self {
+        self.value = value;
+        self
+    }
+    ///Owned chainable setter for the count, returning `Self`
+    #[must_use]
+    pub fn with_count(mut self, count: usize) -> Self {
+        self.count = count;
+        self
+    }
+}
+/// Enum: variant-level bounds apply to its fields' methods and constructor
+#[fieldwork(get, new)]
+enum Slot<T> {
+    #[variant(where = "T: Copy")]
+    Full { #[field(get(copy))] value: T },
+    Empty,
+}
+impl<T> Slot<T> {
+    ///Constructs a new `Slot::Empty`
+    pub fn new_empty() -> Self {
+        Self::Empty
+    }
+}
+impl<T> Slot<T>
+where
+    T: Copy,
+{
+    ///Constructs a new `Slot::Full`
+    pub fn new_full(value: T) -> Self {
+        Self::Full { value }
+    }
+    pub fn value(&self) -> Option<T> {
+        match self {
+            Self::Full { value, .. } => Some(*value),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,23 @@
+/// Struct: methods with their own bounds go in impl blocks of their own
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(get, set, with(where = "T: Default"))]
+struct Container<T> {
+    /// the value
+    #[field(get(clone, where = "T: Clone"))]
+    value: T,
+
+    /// the count
+    count: usize,
+}
+
+/// Enum: variant-level bounds apply to its fields' methods and constructor
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(get, new)]
+enum Slot<T> {
+    #[variant(where = "T: Copy")]
+    Full {
+        #[field(get(copy))]
+        value: T,
+    },
+    Empty,
+}
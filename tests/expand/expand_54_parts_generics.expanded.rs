@@ -0,0 +1,79 @@
+/// Struct: a generic only used by a skipped field is left off the parts struct
+#[fieldwork(into_parts, from_parts, parts)]
+struct Handle<T> {
+    /// the raw id
+    id: u64,
+    #[field(skip)]
+    _kind: std::marker::PhantomData<T>,
+}
+impl<T> Handle<T> {
+    /// Consumes self, returning its fields
+    #[must_use]
+    pub fn into_parts(self) -> HandleParts {
+        HandleParts { id: self.id }
+    }
+    /// Constructs self from its fields
+    pub fn from_parts(parts: HandleParts) -> Self {
+        Self {
+            id: parts.id,
+            _kind: Default::default(),
+        }
+    }
+}
+///The fields of `Handle`, as moved by `into_parts` and `from_parts`
+pub struct HandleParts {
+    ///the raw id
+    pub id: u64,
+}
+/// Struct: bounds that mention a dropped generic are dropped with it
+#[fieldwork(into_parts, from_parts, parts)]
+struct Keyed<K: Clone, V: From<K>>
+where
+    K: Default,
+    V: std::fmt::Debug,
+{
+    value: V,
+    #[field(skip)]
+    _key: std::marker::PhantomData<K>,
+}
+impl<K: Clone, V: From<K>> Keyed<K, V>
+where
+    K: Default,
+    V: std::fmt::Debug,
+{
+    /// Consumes self, returning its fields
+    #[must_use]
+    pub fn into_parts(self) -> KeyedParts<V> {
+        KeyedParts { value: self.value }
+    }
+    /// Constructs self from its fields
+    pub fn from_parts(parts: KeyedParts<V>) -> Self {
+        Self {
+            value: parts.value,
+            _key: Default::default(),
+        }
+    }
+}
+///The fields of `Keyed`, as moved by `into_parts` and `from_parts`
+pub struct KeyedParts<V>
+where
+    V: std::fmt::Debug,
+{
+    pub value: V,
+}
+/// Tuple struct: the same applies to lifetimes
+#[fieldwork(into_parts, from_parts, parts)]
+struct Tagged<'a, T>(String, #[field(skip)] std::marker::PhantomData<&'a T>);
+impl<'a, T> Tagged<'a, T> {
+    /// Consumes self, returning its fields
+    #[must_use]
+    pub fn into_parts(self) -> TaggedParts {
+        TaggedParts(self.0)
+    }
+    /// Constructs self from its fields
+    pub fn from_parts(parts: TaggedParts) -> Self {
+        Self(parts.0, Default::default())
+    }
+}
+///The fields of `Tagged`, as moved by `into_parts` and `from_parts`
+pub struct TaggedParts(pub String);
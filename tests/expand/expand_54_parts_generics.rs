@@ -0,0 +1,27 @@
+/// Struct: a generic only used by a skipped field is left off the parts struct
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(into_parts, from_parts, parts)]
+struct Handle<T> {
+    /// the raw id
+    id: u64,
+    #[field(skip)]
+    _kind: std::marker::PhantomData<T>,
+}
+
+/// Struct: bounds that mention a dropped generic are dropped with it
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(into_parts, from_parts, parts)]
+struct Keyed<K: Clone, V: From<K>>
+where
+    K: Default,
+    V: std::fmt::Debug,
+{
+    value: V,
+    #[field(skip)]
+    _key: std::marker::PhantomData<K>,
+}
+
+/// Tuple struct: the same applies to lifetimes
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(into_parts, from_parts, parts)]
+struct Tagged<'a, T>(String, #[field(skip)] std::marker::PhantomData<&'a T>);
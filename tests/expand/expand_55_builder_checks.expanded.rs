@@ -0,0 +1,181 @@
+fn nonzero(port: &u16) -> Result<(), String> {
+    if *port == 0 { Err("port must be nonzero".into()) } else { Ok(()) }
+}
+/// Struct: a validated builder setter, a generic only used by a skipped field, and bounds
+#[fieldwork(builder(where = "T: Default"))]
+struct Listener<T> {
+    #[field(validate = nonzero, error = String)]
+    port: u16,
+    #[field(builder(where = "T: Clone"))]
+    label: Option<String>,
+    #[field(skip)]
+    _protocol: std::marker::PhantomData<T>,
+}
+impl<T> Listener<T> {}
+impl<T> Listener<T>
+where
+    T: Default,
+{
+    ///Starts building a new `Listener`
+    pub fn builder() -> ListenerBuilder<T> {
+        Default::default()
+    }
+}
+///A builder for [`Listener`], started with `Listener::builder()`
+pub struct ListenerBuilder<T> {
+    port: Option<u16>,
+    label: Option<String>,
+    _marker: std::marker::PhantomData<fn() -> Listener<T>>,
+}
+impl<T> Default for ListenerBuilder<T>
+where
+    T: Default,
+{
+    fn default() -> Self {
+        Self {
+            port: None,
+            label: None,
+            _marker: std::marker::PhantomData,
+        }
+    }
+}
+impl<T> ListenerBuilder<T>
+where
+    T: Default,
+{
+    pub fn try_with_port(mut self, port: u16) -> Result<Self, String> {
+        nonzero(&port)?;
+        self.port = Some(port);
+        Ok(self)
+    }
+    #[must_use]
+    pub fn with_label(mut self, label: Option<String>) -> Self
+    where
+        T: Clone,
+    {
+        self.label = label;
+        self
+    }
+    ///Builds the `Listener`, or returns an error naming the required fields that were not set
+    pub fn build(self) -> Result<Listener<T>, ListenerBuilderError> {
+        let mut missing = Vec::new();
+        if self.port.is_none() {
+            missing.push("port");
+        }
+        if let (Some(port),) = (self.port,) {
+            Ok(Listener {
+                port,
+                label: self.label,
+                _protocol: Default::default(),
+            })
+        } else {
+            Err(ListenerBuilderError { missing })
+        }
+    }
+}
+///The error returned by [`ListenerBuilder::build`]
+pub struct ListenerBuilderError {
+    missing: Vec<&'static str>,
+}
+#[automatically_derived]
+impl ::core::fmt::Debug for ListenerBuilderError {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_struct_field1_finish(
+            f,
+            "ListenerBuilderError",
+            "missing",
+            &&self.missing,
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::clone::Clone for ListenerBuilderError {
+    #[inline]
+    fn clone(&self) -> ListenerBuilderError {
+        ListenerBuilderError {
+            missing: ::core::clone::Clone::clone(&self.missing),
+        }
+    }
+}
+#[automatically_derived]
+impl ::core::marker::StructuralPartialEq for ListenerBuilderError {}
+#[automatically_derived]
+impl ::core::cmp::PartialEq for ListenerBuilderError {
+    #[inline]
+    fn eq(&self, other: &ListenerBuilderError) -> bool {
+        self.missing == other.missing
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::Eq for ListenerBuilderError {
+    #[inline]
+    #[doc(hidden)]
+    #[coverage(off)]
+    fn assert_fields_are_eq(&self) {
+        let _: ::core::cmp::AssertParamIsEq<Vec<&'static str>>;
+    }
+}
+impl ListenerBuilderError {
+    /// The names of the required fields that were not set
+    pub fn missing_fields(&self) -> &[&'static str] {
+        &self.missing
+    }
+}
+impl std::fmt::Display for ListenerBuilderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("missing required fields for `Listener`: ")?;
+        f.write_str(&self.missing.join(", "))
+    }
+}
+impl std::error::Error for ListenerBuilderError {}
+/// Struct: typestate builders validate required fields and fields with defaults
+#[fieldwork(builder(typestate))]
+struct Endpoint {
+    #[field(validate = nonzero, error = String)]
+    port: u16,
+    #[field(default = 8080, builder(validate = nonzero, error = String))]
+    fallback: u16,
+}
+impl Endpoint {
+    ///Starts building a new `Endpoint`
+    pub fn builder() -> EndpointBuilder {
+        Default::default()
+    }
+}
+/**A builder for [`Endpoint`], started with `Endpoint::builder()`
+
+Each `State` type parameter tracks whether a required field has been set, and is `()` until it is. `build` is only available once all of them have been.*/
+pub struct EndpointBuilder<PortState = ()> {
+    port: PortState,
+    fallback: Option<u16>,
+}
+impl Default for EndpointBuilder<()> {
+    fn default() -> Self {
+        Self { port: (), fallback: None }
+    }
+}
+impl<PortState> EndpointBuilder<PortState> {
+    pub fn try_with_port(self, port: u16) -> Result<EndpointBuilder<u16>, String> {
+        nonzero(&port)?;
+        Ok(EndpointBuilder {
+            port,
+            fallback: self.fallback,
+        })
+    }
+    pub fn try_with_fallback(mut self, fallback: u16) -> Result<Self, String> {
+        nonzero(&fallback)?;
+        self.fallback = Some(fallback);
+        Ok(self)
+    }
+}
+impl EndpointBuilder<u16> {
+    ///Builds the `Endpoint`
+    #[must_use]
+    pub fn build(self) -> Endpoint {
+        Endpoint {
+            port: self.port,
+            fallback: self.fallback.unwrap_or_else(|| 8080),
+        }
+    }
+}
@@ -0,0 +1,29 @@
+fn nonzero(port: &u16) -> Result<(), String> {
+    if *port == 0 {
+        Err("port must be nonzero".into())
+    } else {
+        Ok(())
+    }
+}
+
+/// Struct: a validated builder setter, a generic only used by a skipped field, and bounds
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(builder(where = "T: Default"))]
+struct Listener<T> {
+    #[field(validate = nonzero, error = String)]
+    port: u16,
+    #[field(builder(where = "T: Clone"))]
+    label: Option<String>,
+    #[field(skip)]
+    _protocol: std::marker::PhantomData<T>,
+}
+
+/// Struct: typestate builders validate required fields and fields with defaults
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(builder(typestate))]
+struct Endpoint {
+    #[field(validate = nonzero, error = String)]
+    port: u16,
+    #[field(default = 8080, builder(validate = nonzero, error = String))]
+    fallback: u16,
+}
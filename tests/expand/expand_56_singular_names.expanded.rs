@@ -0,0 +1,66 @@
+/// Struct: singular names for `-es` and `-ies` plurals
+#[fieldwork(push, with_item)]
+struct Mailbox {
+    addresses: Vec<String>,
+    categories: Vec<String>,
+    taxes: Vec<u32>,
+    batches: Vec<u32>,
+    messages: Vec<String>,
+    status: Vec<u8>,
+    boxes: Vec<u32>,
+}
+impl Mailbox {
+    pub fn push_address(&mut self, address: String) -> &mut Self {
+        self.addresses.push(address);
+        self
+    }
+    #[must_use]
+    pub fn with_address(mut self, address: String) -> Self {
+        self.addresses.push(address);
+        self
+    }
+    pub fn push_category(&mut self, category: String) -> &mut Self {
+        self.categories.push(category);
+        self
+    }
+    #[must_use]
+    pub fn with_category(mut self, category: String) -> Self {
+        self.categories.push(category);
+        self
+    }
+    pub fn push_tax(&mut self, tax: u32) -> &mut Self {
+        self.taxes.push(tax);
+        self
+    }
+    #[must_use]
+    pub fn with_tax(mut self, tax: u32) -> Self {
+        self.taxes.push(tax);
+        self
+    }
+    pub fn push_batch(&mut self, batch: u32) -> &mut Self {
+        self.batches.push(batch);
+        self
+    }
+    #[must_use]
+    pub fn with_batch(mut self, batch: u32) -> Self {
+        self.batches.push(batch);
+        self
+    }
+    pub fn push_message(&mut self, message: String) -> &mut Self {
+        self.messages.push(message);
+        self
+    }
+    #[must_use]
+    pub fn with_message(mut self, message: String) -> Self {
+        self.messages.push(message);
+        self
+    }
+    pub fn push_status(&mut self, status: u8) -> &mut Self {
+        self.status.push(status);
+        self
+    }
+    pub fn push_boxes(&mut self, boxes: u32) -> &mut Self {
+        self.boxes.push(boxes);
+        self
+    }
+}
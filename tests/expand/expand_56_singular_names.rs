@@ -0,0 +1,14 @@
+/// Struct: singular names for `-es` and `-ies` plurals
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(push, with_item)]
+struct Mailbox {
+    addresses: Vec<String>,
+    categories: Vec<String>,
+    taxes: Vec<u32>,
+    batches: Vec<u32>,
+    messages: Vec<String>,
+    // no derivable singular, so `with_item` is skipped
+    status: Vec<u8>,
+    // the singular is a keyword, so `with_item` is skipped
+    boxes: Vec<u32>,
+}
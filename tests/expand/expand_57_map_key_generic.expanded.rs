@@ -0,0 +1,68 @@
+use std::collections::HashMap;
+/// Struct: the map key generic doesn't shadow an item generic named `Q`
+#[fieldwork(get_item, get_item_mut, remove_item)]
+struct Inventory<Q: std::hash::Hash + Eq> {
+    items: HashMap<Q, u8>,
+}
+impl<Q: std::hash::Hash + Eq> Inventory<Q> {
+    pub fn item<Q_>(&self, key: &Q_) -> Option<&u8>
+    where
+        Q: std::borrow::Borrow<Q_>,
+        Q_: std::hash::Hash + Eq + ?Sized,
+    {
+        self.items.get(key)
+    }
+    pub fn item_mut<Q_>(&mut self, key: &Q_) -> Option<&mut u8>
+    where
+        Q: std::borrow::Borrow<Q_>,
+        Q_: std::hash::Hash + Eq + ?Sized,
+    {
+        self.items.get_mut(key)
+    }
+    pub fn remove_item<Q_>(&mut self, key: &Q_) -> Option<u8>
+    where
+        Q: std::borrow::Borrow<Q_>,
+        Q_: std::hash::Hash + Eq + ?Sized,
+    {
+        self.items.remove(key)
+    }
+}
+/// Enum: the same applies across variants
+#[fieldwork(get_item, get_item_mut, remove_item)]
+enum Catalog<Q: std::hash::Hash + Eq, Q_> {
+    Stocked { items: HashMap<Q, Q_> },
+    Archived { items: HashMap<Q, Q_> },
+}
+impl<Q: std::hash::Hash + Eq, Q_> Catalog<Q, Q_> {
+    pub fn item<Q__>(&self, key: &Q__) -> Option<&Q_>
+    where
+        Q: std::borrow::Borrow<Q__>,
+        Q__: std::hash::Hash + Eq + ?Sized,
+    {
+        match self {
+            Self::Stocked { items, .. } | Self::Archived { items, .. } => items.get(key),
+        }
+    }
+    pub fn item_mut<Q__>(&mut self, key: &Q__) -> Option<&mut Q_>
+    where
+        Q: std::borrow::Borrow<Q__>,
+        Q__: std::hash::Hash + Eq + ?Sized,
+    {
+        match self {
+            Self::Stocked { items, .. } | Self::Archived { items, .. } => {
+                items.get_mut(key)
+            }
+        }
+    }
+    pub fn remove_item<Q__>(&mut self, key: &Q__) -> Option<Q_>
+    where
+        Q: std::borrow::Borrow<Q__>,
+        Q__: std::hash::Hash + Eq + ?Sized,
+    {
+        match self {
+            Self::Stocked { items, .. } | Self::Archived { items, .. } => {
+                items.remove(key)
+            }
+        }
+    }
+}
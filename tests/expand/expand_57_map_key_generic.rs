@@ -0,0 +1,16 @@
+use std::collections::HashMap;
+
+/// Struct: the map key generic doesn't shadow an item generic named `Q`
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(get_item, get_item_mut, remove_item)]
+struct Inventory<Q: std::hash::Hash + Eq> {
+    items: HashMap<Q, u8>,
+}
+
+/// Enum: the same applies across variants
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(get_item, get_item_mut, remove_item)]
+enum Catalog<Q: std::hash::Hash + Eq, Q_> {
+    Stocked { items: HashMap<Q, Q_> },
+    Archived { items: HashMap<Q, Q_> },
+}
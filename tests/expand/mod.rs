@@ -26,3 +26,32 @@ mod expand_24_into_field;
 mod expand_25_enum_basic;
 mod expand_26_enum_field_config;
 mod expand_27_where_bounds;
+mod expand_29_replace;
+mod expand_30_update_and_map;
+mod expand_31_validate;
+mod expand_32_collections;
+mod expand_33_maps;
+mod expand_34_option_predicates;
+mod expand_35_flags;
+mod expand_36_clone;
+mod expand_37_parts;
+mod expand_38_new;
+mod expand_39_builder;
+mod expand_40_typestate_builder;
+mod expand_41_variants;
+mod expand_42_variant_config;
+mod expand_43_fallible;
+mod expand_44_unified_types;
+mod expand_45_kind;
+mod expand_46_transitions;
+mod expand_47_variant_constructors;
+mod expand_48_positional;
+mod expand_49_cfg;
+mod expand_50_multiple_attributes;
+mod expand_51_attr_passthrough;
+mod expand_52_const_getters;
+mod expand_53_method_bounds;
+mod expand_54_parts_generics;
+mod expand_55_builder_checks;
+mod expand_56_singular_names;
+mod expand_57_map_key_generic;
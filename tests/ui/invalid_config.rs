@@ -188,4 +188,93 @@ union FF {
     y: f32,
 }
 
+// validate without a matching error type
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(set)]
+struct GG {
+    #[field(validate = check)]
+    field: (),
+}
+
+// typestate is only for builders
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(with(typestate))]
+struct HH {
+    field: (),
+}
+
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(variants)]
+struct II {
+    field: (),
+}
+
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(kind)]
+struct JJ {
+    field: (),
+}
+
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(transition(from = A, to = B))]
+struct KK {
+    field: (),
+}
+
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(positional(0 = id))]
+struct LL {
+    field: (),
+}
+
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(get, vis = "pub(crate)")]
+#[fieldwork(set, vis = "pub")]
+struct MM {
+    field: (),
+}
+
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(get)]
+struct NN {
+    #[field(get(copy))]
+    #[field(get = false)]
+    field: (),
+}
+
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(get(attr(1)))]
+struct OO {
+    field: (),
+}
+
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(get(const))]
+struct PP {
+    name: String,
+    #[field(get(clone))]
+    tags: Vec<String>,
+}
+
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(get, where = "T: Clone")]
+struct QQ<T> {
+    field: T,
+}
+
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(get)]
+struct RR {
+    #[field = "renamed"]
+    #[field(name = other)]
+    field: (),
+}
+
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(get, option)]
+#[fieldwork(option_borrow_inner = false)]
+struct SS {
+    field: Option<()>,
+}
+
 fn main() {}
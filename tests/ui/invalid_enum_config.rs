@@ -23,6 +23,125 @@ enum MultipleAnnotations {
     },
 }
 
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(into_parts)]
+enum Parts {
+    A { field: String },
+}
+
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(builder)]
+enum Builder {
+    A { field: String },
+}
+
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(variants)]
+enum Variants {
+    #[variant(rename = a, unknown)]
+    A { field: String },
+}
+
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(get)]
+enum VariantMethod {
+    #[variant(get(rename = a))]
+    A { field: String },
+}
+
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(get)]
+enum UnifiedOption {
+    A {
+        #[field(get(deref = "str"))]
+        name: String,
+    },
+    B {
+        name: Option<String>,
+    },
+}
+
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(get)]
+enum UnifiedSet {
+    A {
+        #[field(get(deref = "str"), set)]
+        name: String,
+    },
+    B {
+        name: Box<str>,
+    },
+}
+
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(kind(name = StatusKind, derives(Debug)))]
+enum Kind {
+    A { field: String },
+}
+
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(transition(from = Pending, to = Running))]
+enum UnknownTransition {
+    Pending { id: u64 },
+    Active { id: u64 },
+}
+
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(transition(from = Pending))]
+enum IncompleteTransition {
+    Pending { id: u64 },
+    Active { id: u64 },
+}
+
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(transition(from = Pending, to = Active))]
+enum MismatchedTransition {
+    Pending { id: u64 },
+    Active { id: String },
+}
+
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(variants, transition(from = Pending, to = Active))]
+enum ShadowedTransition {
+    Pending { id: u64 },
+    Active { id: u64 },
+}
+
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(get, positional(0 = id, 0 = other))]
+enum DuplicatePosition {
+    A(u64),
+}
+
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(variants)]
+enum DuplicateVariantKey {
+    #[variant = "first"]
+    #[variant(rename = second)]
+    A(u64),
+}
+
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(get, kind)]
+enum ShadowedKind {
+    A { kind: u8 },
+    B { kind: u8 },
+}
+
+fn check_id(_: &u64) -> Result<(), String> {
+    Ok(())
+}
+
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(set, fallible)]
+enum FallibleValidate {
+    A {
+        #[field(validate = check_id, error = String)]
+        id: u64,
+    },
+    B,
+}
+
 /// Rename splits into two groups; each group has exactly one annotation → fine.
 /// This should compile successfully — no error expected here, but trybuild
 /// requires all items to fail, so we comment this case out and test it elsewhere.
@@ -0,0 +1,11 @@
+#[derive(fieldwork::Fieldwork)]
+#[fieldwork(builder(typestate))]
+struct Server {
+    host: String,
+    port: u16,
+    name: Option<String>,
+}
+
+fn main() {
+    let _ = Server::builder().with_host("localhost".into()).build();
+}